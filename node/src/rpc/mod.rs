@@ -122,6 +122,7 @@ where
 		PalletServicesConstraints,
 		AccountId,
 		AssetId,
+		Balance,
 	>,
 	C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
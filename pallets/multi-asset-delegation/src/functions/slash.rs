@@ -0,0 +1,108 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Webb Technologies Inc.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::{types::*, Pallet};
+use frame_support::traits::{
+	fungibles::Mutate,
+	tokens::{Fortitude, Precision, Preservation},
+	ReservableCurrency,
+};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+	/// Slashes an operator and its delegators.
+	///
+	/// # Arguments
+	///
+	/// * `operator` - The account ID of the operator.
+	/// * `own` - The amount to slash from the operator's self-stake.
+	/// * `others` - The delegators with the asset and the amount to slash from their stake
+	///   bonded to the operator in that asset.
+	///
+	/// The slashed funds are burned. Returns the total amount that got slashed.
+	pub fn process_slash(
+		operator: &T::AccountId,
+		own: BalanceOf<T>,
+		others: &[(T::AccountId, T::AssetId, BalanceOf<T>)],
+	) -> BalanceOf<T> {
+		let Some(mut metadata) = Operators::<T>::get(operator) else {
+			return Zero::zero();
+		};
+
+		// Slash the operator's self-stake, which is reserved.
+		let own = own.min(metadata.stake);
+		let (_burned, not_slashed) = T::Currency::slash_reserved(operator, own);
+		let own_slashed = own.saturating_sub(not_slashed);
+		metadata.stake = metadata.stake.saturating_sub(own_slashed);
+		if !own_slashed.is_zero() {
+			Self::deposit_event(Event::OperatorSlashed {
+				who: operator.clone(),
+				amount: own_slashed,
+			});
+		}
+
+		let mut total_slashed = own_slashed;
+		for (delegator, asset_id, amount) in others {
+			let asset_id = *asset_id;
+			let mut remaining = *amount;
+			let mut delegator_slashed = BalanceOf::<T>::zero();
+			for bond in metadata
+				.delegations
+				.iter_mut()
+				.filter(|bond| &bond.delegator == delegator && bond.asset_id == asset_id)
+			{
+				if remaining.is_zero() {
+					break;
+				}
+				let slash = remaining.min(bond.amount);
+				// The delegated assets are held by the pallet account.
+				let burned = T::Fungibles::burn_from(
+					asset_id,
+					&Self::pallet_account(),
+					slash,
+					Preservation::Expendable,
+					Precision::BestEffort,
+					Fortitude::Force,
+				)
+				.unwrap_or_default();
+				bond.amount = bond.amount.saturating_sub(burned);
+				remaining = remaining.saturating_sub(burned);
+				delegator_slashed = delegator_slashed.saturating_add(burned);
+
+				Delegators::<T>::mutate(delegator, |maybe_metadata| {
+					if let Some(delegation) = maybe_metadata.as_mut().and_then(|metadata| {
+						metadata.delegations.iter_mut().find(|delegation| {
+							&delegation.operator == operator && delegation.asset_id == asset_id
+						})
+					}) {
+						delegation.amount = delegation.amount.saturating_sub(burned);
+					}
+				});
+			}
+
+			if !delegator_slashed.is_zero() {
+				Self::deposit_event(Event::DelegatorSlashed {
+					who: delegator.clone(),
+					amount: delegator_slashed,
+				});
+			}
+			total_slashed = total_slashed.saturating_add(delegator_slashed);
+		}
+
+		Operators::<T>::insert(operator, metadata);
+		total_slashed
+	}
+}
@@ -242,6 +242,10 @@ pub mod pallet {
 		OperatorWentOffline { who: T::AccountId },
 		/// An operator has gone online.
 		OperatorWentOnline { who: T::AccountId },
+		/// An operator has been slashed.
+		OperatorSlashed { who: T::AccountId, amount: BalanceOf<T> },
+		/// A delegator has been slashed.
+		DelegatorSlashed { who: T::AccountId, amount: BalanceOf<T> },
 		/// A deposit has been made.
 		Deposited { who: T::AccountId, amount: BalanceOf<T>, asset_id: T::AssetId },
 		/// An withdraw has been scheduled.
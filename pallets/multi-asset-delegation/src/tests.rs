@@ -20,5 +20,6 @@ pub mod delegate;
 pub mod deposit;
 pub mod operator;
 pub mod session_manager;
+pub mod slash;
 
 use crate::tests::deposit::{create_and_mint_tokens, mint_tokens};
@@ -0,0 +1,97 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Webb Technologies Inc.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use frame_support::{assert_ok, traits::ReservableCurrency};
+use tangle_primitives::traits::MultiAssetDelegationInfo;
+
+#[test]
+fn slash_operator_should_work() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		let who = 1;
+		let operator = 2;
+		let asset_id = VDOT;
+		let amount = 100;
+
+		assert_ok!(MultiAssetDelegation::join_operators(RuntimeOrigin::signed(operator), 10_000));
+		create_and_mint_tokens(VDOT, who, amount);
+		assert_ok!(MultiAssetDelegation::deposit(RuntimeOrigin::signed(who), asset_id, amount,));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(who),
+			operator,
+			asset_id,
+			amount,
+		));
+
+		// Act
+		let slashed = <MultiAssetDelegation as MultiAssetDelegationInfo<_, _>>::slash_operator(
+			&operator,
+			1_000,
+			&[(who, asset_id, 40)],
+		);
+
+		// Assert
+		assert_eq!(slashed, 1_040);
+		assert_eq!(Balances::reserved_balance(&operator), 9_000);
+		assert_eq!(Assets::balance(asset_id, &MultiAssetDelegation::pallet_account()), 60);
+
+		let operator_metadata = MultiAssetDelegation::operator_info(operator).unwrap();
+		assert_eq!(operator_metadata.stake, 9_000);
+		assert_eq!(operator_metadata.delegations[0].amount, 60);
+
+		let metadata = MultiAssetDelegation::delegators(who).unwrap();
+		assert_eq!(metadata.delegations[0].amount, 60);
+	});
+}
+
+#[test]
+fn slash_operator_should_only_slash_the_bonds_in_the_slashed_asset() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		let who = 1;
+		let operator = 2;
+		let asset_id = VDOT;
+		let other_asset_id = VDOT + 1;
+		let amount = 100;
+
+		assert_ok!(MultiAssetDelegation::join_operators(RuntimeOrigin::signed(operator), 10_000));
+		create_and_mint_tokens(VDOT, who, amount);
+		assert_ok!(MultiAssetDelegation::deposit(RuntimeOrigin::signed(who), asset_id, amount,));
+		assert_ok!(MultiAssetDelegation::delegate(
+			RuntimeOrigin::signed(who),
+			operator,
+			asset_id,
+			amount,
+		));
+
+		// Act
+		let slashed = <MultiAssetDelegation as MultiAssetDelegationInfo<_, _>>::slash_operator(
+			&operator,
+			0,
+			&[(who, other_asset_id, 40)],
+		);
+
+		// Assert
+		assert_eq!(slashed, 0);
+		assert_eq!(Assets::balance(asset_id, &MultiAssetDelegation::pallet_account()), 100);
+
+		let operator_metadata = MultiAssetDelegation::operator_info(operator).unwrap();
+		assert_eq!(operator_metadata.delegations[0].amount, 100);
+
+		let metadata = MultiAssetDelegation::delegators(who).unwrap();
+		assert_eq!(metadata.delegations[0].amount, 100);
+	});
+}
@@ -63,4 +63,12 @@ impl<T: crate::Config> MultiAssetDelegationInfo<T::AccountId, BalanceOf<T>> for
 				.collect()
 		})
 	}
+
+	fn slash_operator(
+		operator: &T::AccountId,
+		own: BalanceOf<T>,
+		others: &[(T::AccountId, T::AssetId, BalanceOf<T>)],
+	) -> BalanceOf<T> {
+		Self::process_slash(operator, own, others)
+	}
 }
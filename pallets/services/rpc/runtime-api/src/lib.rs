@@ -21,17 +21,23 @@
 use parity_scale_codec::Codec;
 use sp_runtime::{traits::MaybeDisplay, Serialize};
 use sp_std::vec::Vec;
-use tangle_primitives::services::{Constraints, RpcServicesWithBlueprint};
+use tangle_primitives::services::{
+	Constraints, Field, JobCall, JobCallResult, OperatorPreferences, RpcServicesWithBlueprint,
+	Service, ServiceBlueprint, ServiceEscrow, ServiceRequest, TypeCheckError, UnappliedSlash,
+};
 
 pub type BlockNumberOf<Block> =
 	<<Block as sp_runtime::traits::HeaderProvider>::HeaderT as sp_runtime::traits::Header>::Number;
 
 sp_api::decl_runtime_apis! {
-	pub trait ServicesApi<C, AccountId, AssetId>
+	/// The version 2 adds the escrow, revision, paginated and type check queries.
+	#[api_version(2)]
+	pub trait ServicesApi<C, AccountId, AssetId, Balance>
 	where
 		C: Constraints,
 		AccountId: Codec + MaybeDisplay + Serialize,
 		AssetId: Codec + MaybeDisplay + Serialize,
+		Balance: Codec + MaybeDisplay + Serialize,
 	{
 		/// Query all the services that this operator is providing along with their blueprints.
 		///
@@ -45,5 +51,185 @@ sp_api::decl_runtime_apis! {
 			Vec<RpcServicesWithBlueprint<C, AccountId, BlockNumberOf<Block>, AssetId>>,
 			sp_runtime::DispatchError,
 		>;
+
+		/// Query the value escrowed by a pending service request.
+		///
+		/// ## Arguments
+		/// - `request_id`: The service request id.
+		/// ## Return
+		/// - `Balance`: The escrowed value, refunded to the owner if the request is rejected or expires.
+		#[api_version(2)]
+		fn query_service_request_escrow(
+			request_id: u64,
+		) -> Result<Balance, sp_runtime::DispatchError>;
+
+		/// Query the value escrowed by an operator on its registration for a service blueprint.
+		///
+		/// ## Arguments
+		/// - `blueprint_id`: The service blueprint id.
+		/// - `operator`: The operator account id.
+		/// ## Return
+		/// - `Balance`: The escrowed value, refunded to the operator when it unregisters.
+		#[api_version(2)]
+		fn query_registration_escrow(
+			blueprint_id: u64,
+			operator: AccountId,
+		) -> Result<Balance, sp_runtime::DispatchError>;
+
+		/// Query the escrow of a service instance.
+		///
+		/// ## Arguments
+		/// - `service_id`: The service id.
+		/// ## Return
+		/// - [`ServiceEscrow`]: The escrowed value and the part of it paid out to the operators.
+		#[api_version(2)]
+		fn query_service_escrow(
+			service_id: u64,
+		) -> Result<ServiceEscrow<Balance>, sp_runtime::DispatchError>;
+
+		/// Query all the revisions of a service blueprint.
+		///
+		/// ## Arguments
+		/// - `blueprint_id`: The service blueprint id.
+		/// ## Return
+		/// - [`ServiceBlueprint`]: The revisions of the blueprint, ordered from the oldest to the latest.
+		#[api_version(2)]
+		fn query_blueprint_revisions(
+			blueprint_id: u64,
+		) -> Result<Vec<(u32, ServiceBlueprint<C>)>, sp_runtime::DispatchError>;
+
+		/// Query a page of the service blueprints, ordered by id.
+		///
+		/// ## Arguments
+		/// - `owner`: Only query the blueprints owned by this account, if any.
+		/// - `offset`: The number of blueprints to skip.
+		/// - `limit`: The maximum number of blueprints to return, capped to 100.
+		/// ## Return
+		/// - [`ServiceBlueprint`]: The blueprints with their id and owner.
+		#[api_version(2)]
+		fn query_blueprints(
+			owner: Option<AccountId>,
+			offset: u32,
+			limit: u32,
+		) -> Result<Vec<(u64, AccountId, ServiceBlueprint<C>)>, sp_runtime::DispatchError>;
+
+		/// Query a page of the operators registered on a service blueprint.
+		///
+		/// ## Arguments
+		/// - `blueprint_id`: The service blueprint id.
+		/// - `offset`: The number of operators to skip.
+		/// - `limit`: The maximum number of operators to return, capped to 100.
+		/// ## Return
+		/// - [`OperatorPreferences`]: The operators with their preferences.
+		#[api_version(2)]
+		fn query_blueprint_operators(
+			blueprint_id: u64,
+			offset: u32,
+			limit: u32,
+		) -> Result<Vec<(AccountId, OperatorPreferences)>, sp_runtime::DispatchError>;
+
+		/// Query a page of the services owned by an account, ordered by id.
+		///
+		/// ## Arguments
+		/// - `owner`: The owner account id.
+		/// - `offset`: The number of services to skip.
+		/// - `limit`: The maximum number of services to return, capped to 100.
+		/// ## Return
+		/// - [`Service`]: The services of the owner.
+		#[api_version(2)]
+		fn query_services_by_owner(
+			owner: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Result<
+			Vec<Service<C, AccountId, BlockNumberOf<Block>, AssetId>>,
+			sp_runtime::DispatchError,
+		>;
+
+		/// Query a page of the pending service requests waiting for the approval of an operator,
+		/// ordered by id.
+		///
+		/// ## Arguments
+		/// - `operator`: The operator account id.
+		/// - `offset`: The number of requests to skip.
+		/// - `limit`: The maximum number of requests to return, capped to 100.
+		/// ## Return
+		/// - [`ServiceRequest`]: The pending requests with their id.
+		///
+		/// Every pending request is read to find the ones of the operator, whatever the page.
+		#[api_version(2)]
+		fn query_pending_requests_by_operator(
+			operator: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Result<
+			Vec<(u64, ServiceRequest<C, AccountId, BlockNumberOf<Block>, AssetId>)>,
+			sp_runtime::DispatchError,
+		>;
+
+		/// Query a page of the job calls of a service, ordered by id.
+		///
+		/// ## Arguments
+		/// - `service_id`: The service id.
+		/// - `offset`: The number of job calls to skip.
+		/// - `limit`: The maximum number of job calls to return, capped to 100.
+		/// ## Return
+		/// - [`JobCall`]: The job calls with their id, and their final result if they got one.
+		#[api_version(2)]
+		fn query_job_calls(
+			service_id: u64,
+			offset: u32,
+			limit: u32,
+		) -> Result<
+			Vec<(u64, JobCall<C, AccountId>, Option<JobCallResult<C, AccountId>>)>,
+			sp_runtime::DispatchError,
+		>;
+
+		/// Query a page of the unapplied slashes of an operator, ordered by era and index.
+		///
+		/// ## Arguments
+		/// - `operator`: The operator account id.
+		/// - `offset`: The number of slashes to skip.
+		/// - `limit`: The maximum number of slashes to return, capped to 100.
+		/// ## Return
+		/// - [`UnappliedSlash`]: The unapplied slashes with their era and index.
+		///
+		/// Every unapplied slash is read to find the ones of the operator, whatever the page.
+		#[api_version(2)]
+		fn query_unapplied_slashes(
+			operator: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Result<Vec<(u32, u32, UnappliedSlash<AccountId, Balance, AssetId>)>, sp_runtime::DispatchError>;
+
+		/// Type check the arguments of a job call without calling it.
+		///
+		/// ## Arguments
+		/// - `service_id`: The service id.
+		/// - `job`: The job index.
+		/// - `args`: The arguments of the job call.
+		/// ## Return
+		/// - [`TypeCheckError`]: The full type check error the call would fail with, if any.
+		#[api_version(2)]
+		fn type_check_job_call(
+			service_id: u64,
+			job: u8,
+			args: Vec<Field<C, AccountId>>,
+		) -> Result<Option<TypeCheckError>, sp_runtime::DispatchError>;
+
+		/// Type check the result of a job call without submitting it.
+		///
+		/// ## Arguments
+		/// - `service_id`: The service id.
+		/// - `call_id`: The job call id.
+		/// - `result`: The result of the job call.
+		/// ## Return
+		/// - [`TypeCheckError`]: The full type check error the submission would fail with, if any.
+		#[api_version(2)]
+		fn type_check_job_result(
+			service_id: u64,
+			call_id: u64,
+			result: Vec<Field<C, AccountId>>,
+		) -> Result<Option<TypeCheckError>, sp_runtime::DispatchError>;
 	}
 }
@@ -29,19 +29,23 @@ use sp_runtime::{
 	DispatchError, Serialize,
 };
 use std::sync::Arc;
-use tangle_primitives::services::{Constraints, RpcServicesWithBlueprint};
+use tangle_primitives::services::{
+	Constraints, Field, JobCall, JobCallResult, OperatorPreferences, RpcServicesWithBlueprint,
+	Service, ServiceBlueprint, ServiceEscrow, ServiceRequest, TypeCheckError, UnappliedSlash,
+};
 
 type BlockNumberOf<Block> =
 	<<Block as sp_runtime::traits::HeaderProvider>::HeaderT as sp_runtime::traits::Header>::Number;
 
 /// ServicesClient RPC methods.
 #[rpc(client, server)]
-pub trait ServicesApi<BlockHash, X, AccountId, BlockNumber, AssetId>
+pub trait ServicesApi<BlockHash, X, AccountId, BlockNumber, AssetId, Balance>
 where
 	X: Constraints,
 	AccountId: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
 	BlockNumber: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
 	AssetId: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
+	Balance: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
 {
 	#[method(name = "services_queryServicesWithBlueprintsByOperator")]
 	fn query_services_with_blueprints_by_operator(
@@ -49,6 +53,107 @@ where
 		operator: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<Vec<RpcServicesWithBlueprint<X, AccountId, BlockNumber, AssetId>>>;
+
+	#[method(name = "services_queryServiceRequestEscrow")]
+	fn query_service_request_escrow(
+		&self,
+		request_id: u64,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	#[method(name = "services_queryRegistrationEscrow")]
+	fn query_registration_escrow(
+		&self,
+		blueprint_id: u64,
+		operator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	#[method(name = "services_queryServiceEscrow")]
+	fn query_service_escrow(
+		&self,
+		service_id: u64,
+		at: Option<BlockHash>,
+	) -> RpcResult<ServiceEscrow<Balance>>;
+
+	#[method(name = "services_queryBlueprintRevisions")]
+	fn query_blueprint_revisions(
+		&self,
+		blueprint_id: u64,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u32, ServiceBlueprint<X>)>>;
+
+	#[method(name = "services_queryBlueprints")]
+	fn query_blueprints(
+		&self,
+		owner: Option<AccountId>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, AccountId, ServiceBlueprint<X>)>>;
+
+	#[method(name = "services_queryBlueprintOperators")]
+	fn query_blueprint_operators(
+		&self,
+		blueprint_id: u64,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, OperatorPreferences)>>;
+
+	#[method(name = "services_queryServicesByOwner")]
+	fn query_services_by_owner(
+		&self,
+		owner: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Service<X, AccountId, BlockNumber, AssetId>>>;
+
+	#[method(name = "services_queryPendingRequestsByOperator")]
+	fn query_pending_requests_by_operator(
+		&self,
+		operator: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, ServiceRequest<X, AccountId, BlockNumber, AssetId>)>>;
+
+	#[method(name = "services_queryJobCalls")]
+	fn query_job_calls(
+		&self,
+		service_id: u64,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, JobCall<X, AccountId>, Option<JobCallResult<X, AccountId>>)>>;
+
+	#[method(name = "services_queryUnappliedSlashes")]
+	fn query_unapplied_slashes(
+		&self,
+		operator: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u32, u32, UnappliedSlash<AccountId, Balance, AssetId>)>>;
+
+	#[method(name = "services_typeCheckJobCall")]
+	fn type_check_job_call(
+		&self,
+		service_id: u64,
+		job: u8,
+		args: Vec<Field<X, AccountId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TypeCheckError>>;
+
+	#[method(name = "services_typeCheckJobResult")]
+	fn type_check_job_result(
+		&self,
+		service_id: u64,
+		call_id: u64,
+		result: Vec<Field<X, AccountId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TypeCheckError>>;
 }
 
 /// A struct that implements the `ServicesApi`.
@@ -64,16 +169,17 @@ impl<C, M, P> ServicesClient<C, M, P> {
 	}
 }
 
-impl<C, X, Block, AccountId, AssetId>
-	ServicesApiServer<<Block as BlockT>::Hash, X, AccountId, BlockNumberOf<Block>, AssetId>
+impl<C, X, Block, AccountId, AssetId, Balance>
+	ServicesApiServer<<Block as BlockT>::Hash, X, AccountId, BlockNumberOf<Block>, AssetId, Balance>
 	for ServicesClient<C, Block, AccountId>
 where
 	Block: BlockT,
 	AccountId: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
 	AssetId: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
+	Balance: Codec + MaybeDisplay + core::fmt::Debug + Send + Sync + 'static + Serialize,
 	X: Constraints,
 	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + Send + Sync + 'static,
-	C::Api: ServicesRuntimeApi<Block, X, AccountId, AssetId>,
+	C::Api: ServicesRuntimeApi<Block, X, AccountId, AssetId, Balance>,
 {
 	fn query_services_with_blueprints_by_operator(
 		&self,
@@ -89,6 +195,203 @@ where
 			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
 		}
 	}
+
+	fn query_service_request_escrow(
+		&self,
+		request_id: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_service_request_escrow(at, request_id) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_registration_escrow(
+		&self,
+		blueprint_id: u64,
+		operator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_registration_escrow(at, blueprint_id, operator) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_service_escrow(
+		&self,
+		service_id: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ServiceEscrow<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_service_escrow(at, service_id) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_blueprint_revisions(
+		&self,
+		blueprint_id: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u32, ServiceBlueprint<X>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_blueprint_revisions(at, blueprint_id) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_blueprints(
+		&self,
+		owner: Option<AccountId>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u64, AccountId, ServiceBlueprint<X>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_blueprints(at, owner, offset, limit) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_blueprint_operators(
+		&self,
+		blueprint_id: u64,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, OperatorPreferences)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_blueprint_operators(at, blueprint_id, offset, limit) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_services_by_owner(
+		&self,
+		owner: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Service<X, AccountId, BlockNumberOf<Block>, AssetId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_services_by_owner(at, owner, offset, limit) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_pending_requests_by_operator(
+		&self,
+		operator: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u64, ServiceRequest<X, AccountId, BlockNumberOf<Block>, AssetId>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_pending_requests_by_operator(at, operator, offset, limit) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_job_calls(
+		&self,
+		service_id: u64,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u64, JobCall<X, AccountId>, Option<JobCallResult<X, AccountId>>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_job_calls(at, service_id, offset, limit) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn query_unapplied_slashes(
+		&self,
+		operator: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u32, u32, UnappliedSlash<AccountId, Balance, AssetId>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.query_unapplied_slashes(at, operator, offset, limit) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn type_check_job_call(
+		&self,
+		service_id: u64,
+		job: u8,
+		args: Vec<Field<X, AccountId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<TypeCheckError>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.type_check_job_call(at, service_id, job, args) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
+
+	fn type_check_job_result(
+		&self,
+		service_id: u64,
+		call_id: u64,
+		result: Vec<Field<X, AccountId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<TypeCheckError>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		match api.type_check_job_result(at, service_id, call_id, result) {
+			Ok(Ok(res)) => Ok(res),
+			Ok(Err(e)) => Err(custom_error_into_rpc_err(Error::CustomDispatchError(e))),
+			Err(e) => Err(custom_error_into_rpc_err(Error::RuntimeError(e))),
+		}
+	}
 }
 
 /// Error type of this RPC api.
@@ -1,14 +1,30 @@
 use crate::{Call, Config, Pallet};
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, Get},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use parity_scale_codec::Decode;
 use sp_core::{ecdsa, H160};
-use sp_runtime::KeyTypeId;
-use sp_std::vec;
-use tangle_primitives::services::*;
+use sp_runtime::{traits::Saturating, KeyTypeId, Percent};
+use sp_std::{vec, vec::Vec};
+use tangle_primitives::{
+	services::*,
+	verifier::{
+		arkworks::{groth16_bn254_verification_case, ArkworksVerifierGroth16Bn254},
+		InstanceVerifier,
+	},
+	MultiAssetDelegationInfo,
+};
 
 const CGGMP21_BLUEPRINT: H160 = H160([0x21; 20]);
 
+/// The length of a compressed Groth16 verifying key without public inputs, each of which adds
+/// another 32 bytes.
+const VERIFYING_KEY_BASE_LENGTH: u32 = 264;
+
 fn zero_key() -> ecdsa::Public {
 	ecdsa::Public::from([0; 33])
 }
@@ -17,6 +33,15 @@ fn mock_account_id<T: Config>(id: u8) -> T::AccountId {
 	T::AccountId::decode(&mut &[id; 32][..]).unwrap()
 }
 
+fn funded_account<T: Config>(id: u8) -> T::AccountId {
+	let account = mock_account_id::<T>(id);
+	let balance = T::Currency::minimum_balance()
+		.max(1u32.into())
+		.saturating_mul(1_000_000_000u32.into());
+	T::Currency::make_free_balance_be(&account, balance);
+	account
+}
+
 fn operator_preferences<T: Config>() -> OperatorPreferences {
 	OperatorPreferences { key: zero_key(), price_targets: Default::default() }
 }
@@ -30,12 +55,18 @@ fn cggmp21_blueprint<T: Config>() -> ServiceBlueprint<T::Constraints> {
 				params: vec![FieldType::Uint8].try_into().unwrap(),
 				result: vec![FieldType::Bytes].try_into().unwrap(),
 				verifier: JobResultVerifier::Evm(CGGMP21_BLUEPRINT),
+				result_policy: JobResultPolicy::First,
+				response_window: None,
+				fee: None,
 			},
 			JobDefinition {
 				metadata: JobMetadata { name: "sign".try_into().unwrap(), ..Default::default() },
 				params: vec![FieldType::Uint64, FieldType::Bytes].try_into().unwrap(),
 				result: vec![FieldType::Bytes].try_into().unwrap(),
 				verifier: JobResultVerifier::Evm(CGGMP21_BLUEPRINT),
+				result_policy: JobResultPolicy::First,
+				response_window: None,
+				fee: None,
 			},
 		]
 		.try_into()
@@ -44,266 +75,566 @@ fn cggmp21_blueprint<T: Config>() -> ServiceBlueprint<T::Constraints> {
 		registration_params: vec![].try_into().unwrap(),
 		request_hook: ServiceRequestHook::Evm(CGGMP21_BLUEPRINT),
 		request_params: vec![].try_into().unwrap(),
+		manager: BlueprintManager::Evm(CGGMP21_BLUEPRINT),
 		gadget: Default::default(),
+		exit_notice_period: 0,
+		heartbeat_interval: 0,
+		hook_gas_limit: 0,
+	}
+}
+
+/// Creates the CGGMP21 blueprint, owned by `owner`.
+fn create_blueprint<T: Config>(owner: &T::AccountId) -> u64 {
+	let blueprint_id = Pallet::<T>::next_blueprint_id();
+	assert_ok!(Pallet::<T>::create_blueprint(
+		RawOrigin::Signed(owner.clone()).into(),
+		cggmp21_blueprint::<T>()
+	));
+	blueprint_id
+}
+
+/// Creates the CGGMP21 blueprint with a heartbeat interval of 20 blocks, owned by `owner`.
+fn heartbeat_blueprint<T: Config>(owner: &T::AccountId) -> u64 {
+	let blueprint_id = Pallet::<T>::next_blueprint_id();
+	let mut blueprint = cggmp21_blueprint::<T>();
+	blueprint.heartbeat_interval = 20;
+	assert_ok!(Pallet::<T>::create_blueprint(RawOrigin::Signed(owner.clone()).into(), blueprint));
+	blueprint_id
+}
+
+/// Registers the account `id` as an operator of the blueprint.
+fn register_operator<T: Config>(blueprint_id: u64, id: u8) -> T::AccountId {
+	let operator = funded_account::<T>(id);
+	assert_ok!(Pallet::<T>::register(
+		RawOrigin::Signed(operator.clone()).into(),
+		blueprint_id,
+		operator_preferences::<T>(),
+		Default::default(),
+		0u32.into()
+	));
+	operator
+}
+
+/// Requests a service of the blueprint from the given operators.
+fn request_service<T: Config>(
+	owner: &T::AccountId,
+	blueprint_id: u64,
+	operators: &[T::AccountId],
+) -> u64 {
+	let request_id = Pallet::<T>::next_service_request_id();
+	assert_ok!(Pallet::<T>::request(
+		RawOrigin::Signed(owner.clone()).into(),
+		blueprint_id,
+		vec![mock_account_id::<T>(1u8)],
+		operators.to_vec(),
+		Default::default(),
+		vec![0u32.into()],
+		100u32.into(),
+		100u32.into(),
+		Default::default()
+	));
+	request_id
+}
+
+/// Requests a service of the blueprint from the given operators, and approves it with all of
+/// them so that the service gets initiated.
+fn new_service<T: Config>(
+	owner: &T::AccountId,
+	blueprint_id: u64,
+	operators: &[T::AccountId],
+) -> u64 {
+	let service_id = Pallet::<T>::next_instance_id();
+	let request_id = request_service::<T>(owner, blueprint_id, operators);
+	for operator in operators {
+		assert_ok!(Pallet::<T>::approve(
+			RawOrigin::Signed(operator.clone()).into(),
+			request_id,
+			Percent::from_percent(25)
+		));
 	}
+	service_id
 }
 
 benchmarks! {
 
 	create_blueprint {
-		let alice = mock_account_id::<T>(1u8);
+		let alice = funded_account::<T>(1u8);
 		let blueprint = cggmp21_blueprint::<T>();
 	}: _(RawOrigin::Signed(alice.clone()), blueprint)
 
 	pre_register {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-
-	}: _(RawOrigin::Signed(bob.clone()), 0)
-
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = funded_account::<T>(2u8);
+	}: _(RawOrigin::Signed(bob.clone()), blueprint_id)
 
 	register {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = funded_account::<T>(2u8);
 		let operator_preference = operator_preferences::<T>();
-
-	}: _(RawOrigin::Signed(bob.clone()), 0, operator_preference, Default::default())
-
+	}: _(RawOrigin::Signed(bob.clone()), blueprint_id, operator_preference, Default::default(), 100u32.into())
 
 	unregister {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let operator_preference = operator_preferences::<T>();
-
-		let _= Pallet::<T>::register(RawOrigin::Signed(bob.clone()).into(), 0, operator_preference, Default::default());
-
-	}: _(RawOrigin::Signed(bob.clone()), 0)
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = funded_account::<T>(2u8);
+		assert_ok!(Pallet::<T>::register(
+			RawOrigin::Signed(bob.clone()).into(),
+			blueprint_id,
+			operator_preferences::<T>(),
+			Default::default(),
+			100u32.into()
+		));
+	}: _(RawOrigin::Signed(bob.clone()), blueprint_id)
 
 	update_price_targets {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let operator_preference = operator_preferences::<T>();
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
 		let price_targets = Default::default();
-
-		let _= Pallet::<T>::register(RawOrigin::Signed(bob.clone()).into(), 0, operator_preference, Default::default());
-
-	}: _(RawOrigin::Signed(bob.clone()), 0, price_targets)
-
+	}: _(RawOrigin::Signed(bob.clone()), blueprint_id, price_targets)
 
 	request {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let operator_preference = operator_preferences::<T>();
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(bob.clone()).into(), 0, operator_preference, Default::default());
-
-		let charlie: T::AccountId =  mock_account_id::<T>(3u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(charlie.clone()).into(), 0, operator_preference, Default::default());
-
-		let dave: T::AccountId =  mock_account_id::<T>(4u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(dave.clone()).into(), 0, operator_preference, Default::default());
-
-		let eve: T::AccountId =  mock_account_id::<T>(5u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(eve.clone()).into(), 0, operator_preference, Default::default());
-
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
 	}: _(
 			RawOrigin::Signed(eve.clone()),
-			0,
+			blueprint_id,
 			vec![alice.clone()],
 			vec![bob.clone(), charlie.clone(), dave.clone()],
+			Default::default(),
+			vec![0u32.into()],
+			100u32.into(),
 			100u32.into(),
 			Default::default()
 		)
 
+	// The last approval, which initiates the service.
 	approve {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-
-		let operator_preference = operator_preferences::<T>();
-		let _= Pallet::<T>::register(
-			RawOrigin::Signed(bob.clone()).into(),
-			0,
-			operator_preference,
-			Default::default()
-		);
-
-		let charlie: T::AccountId =  mock_account_id::<T>(3u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(charlie.clone()).into(), 0, operator_preference, Default::default());
-
-		let dave: T::AccountId =  mock_account_id::<T>(4u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(dave.clone()).into(), 0, operator_preference, Default::default());
-
-		let eve: T::AccountId =  mock_account_id::<T>(5u8);
-		let _= Pallet::<T>::request(
-			RawOrigin::Signed(eve.clone()).into(),
-			0,
-			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
-			100u32.into(),
-			Default::default()
-		);
-
-	}: _(RawOrigin::Signed(charlie.clone()), 0, Percent::from_percent(25))
-
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
+		let request_id = request_service::<T>(&eve, blueprint_id, &[bob.clone(), charlie.clone(), dave.clone()]);
+		for operator in [&bob, &dave] {
+			assert_ok!(Pallet::<T>::approve(
+				RawOrigin::Signed(operator.clone()).into(),
+				request_id,
+				Percent::from_percent(25)
+			));
+		}
+	}: _(RawOrigin::Signed(charlie.clone()), request_id, Percent::from_percent(25))
 
 	reject {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let operator_preference = operator_preferences::<T>();
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let _= Pallet::<T>::register(
-			RawOrigin::Signed(bob.clone()).into(),
-			0,
-			operator_preference,
-			Default::default()
-		);
-
-		let charlie: T::AccountId =  mock_account_id::<T>(3u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(charlie.clone()).into(), 0, operator_preference, Default::default());
-
-		let dave: T::AccountId =  mock_account_id::<T>(4u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(dave.clone()).into(), 0, operator_preference, Default::default());
-
-		let eve: T::AccountId =  mock_account_id::<T>(5u8);
-		let _= Pallet::<T>::request(
-			RawOrigin::Signed(eve.clone()).into(),
-			0,
-			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
-			100u32.into(),
-			Default::default()
-		);
-
-	}: _(RawOrigin::Signed(charlie.clone()), 0)
-
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
+		let request_id = request_service::<T>(&eve, blueprint_id, &[bob.clone(), charlie.clone(), dave.clone()]);
+	}: _(RawOrigin::Signed(charlie.clone()), request_id)
 
 	terminate {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob, charlie, dave]);
+	}: _(RawOrigin::Signed(eve.clone()), service_id)
+
+	payout {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob, charlie, dave]);
+		// half of the lifetime of the service elapsed.
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + 50u32.into());
+	}: _(RawOrigin::Signed(alice.clone()), service_id)
+
+	update_blueprint {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
 		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let operator_preference = operator_preferences::<T>();
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(bob.clone()).into(), 0, operator_preference, Default::default());
-
-		let charlie: T::AccountId =  mock_account_id::<T>(3u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(charlie.clone()).into(), 0, operator_preference, Default::default());
-
-		let dave: T::AccountId =  mock_account_id::<T>(4u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(dave.clone()).into(), 0, operator_preference, Default::default());
-
-		let eve: T::AccountId =  mock_account_id::<T>(5u8);
-		let _= Pallet::<T>::request(
+	}: _(RawOrigin::Signed(alice.clone()), blueprint_id, blueprint)
+
+	update_operator_revision {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		assert_ok!(Pallet::<T>::update_blueprint(
+			RawOrigin::Signed(alice.clone()).into(),
+			blueprint_id,
+			cggmp21_blueprint::<T>()
+		));
+	}: _(RawOrigin::Signed(bob.clone()), blueprint_id)
+
+	transfer_blueprint_ownership {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = mock_account_id::<T>(2u8);
+	}: _(RawOrigin::Signed(alice.clone()), blueprint_id, bob)
+
+	deprecate_blueprint {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+	}: _(RawOrigin::Signed(alice.clone()), blueprint_id)
+
+	set_verifying_key {
+		let k in VERIFYING_KEY_BASE_LENGTH .. T::MaxVerifyingKeyLength::get();
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let (key, ..) =
+			groth16_bn254_verification_case((k - VERIFYING_KEY_BASE_LENGTH) / 32).unwrap();
+	}: _(RawOrigin::Signed(alice.clone()), blueprint_id, 0, key)
+
+	// The last approval, which applies a change of `n` joining operators.
+	approve_operator_change {
+		let n in 1 .. 32;
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob]);
+		let joining = (0..n as u8)
+			.map(|i| register_operator::<T>(blueprint_id, 100u8 + i))
+			.collect::<Vec<_>>();
+		assert_ok!(Pallet::<T>::propose_operator_change(
 			RawOrigin::Signed(eve.clone()).into(),
-			0,
-			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
-			100u32.into(),
-			Default::default()
-		);
-
-	}: _(RawOrigin::Signed(eve.clone()),0)
-
+			service_id,
+			joining.clone(),
+			vec![]
+		));
+		let (last, others) = joining.split_last().unwrap();
+		for operator in others {
+			assert_ok!(Pallet::<T>::approve_operator_change(
+				RawOrigin::Signed(operator.clone()).into(),
+				service_id,
+				Percent::from_percent(10),
+				n
+			));
+		}
+	}: _(RawOrigin::Signed(last.clone()), service_id, Percent::from_percent(10), n)
+
+	reject_operator_change {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob]);
+		assert_ok!(Pallet::<T>::propose_operator_change(
+			RawOrigin::Signed(eve.clone()).into(),
+			service_id,
+			vec![charlie.clone()],
+			vec![]
+		));
+	}: _(RawOrigin::Signed(charlie.clone()), service_id)
+
+	set_job_caller_policy {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob]);
+		let max_callers = <T::Constraints as Constraints>::MaxPermittedCallers::get();
+		let callers = (0..max_callers)
+			.map(|i| mock_account_id::<T>(100u8.saturating_add(i as u8)))
+			.collect::<Vec<_>>();
+		let policy = JobCallerPolicy::Accounts(callers.try_into().unwrap());
+	}: _(RawOrigin::Signed(eve.clone()), service_id, 0, policy)
+
+	add_permitted_caller {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob]);
+		let charlie = mock_account_id::<T>(3u8);
+	}: _(RawOrigin::Signed(eve.clone()), service_id, charlie)
+
+	remove_permitted_caller {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob]);
+		let charlie = mock_account_id::<T>(3u8);
+		assert_ok!(Pallet::<T>::add_permitted_caller(
+			RawOrigin::Signed(eve.clone()).into(),
+			service_id,
+			charlie.clone()
+		));
+	}: _(RawOrigin::Signed(eve.clone()), service_id, charlie)
+
+	slash {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+		let service = Pallet::<T>::services(service_id).unwrap();
+		let (slashing_origin, _) = Pallet::<T>::query_slashing_origin(&service).unwrap();
+		let slashing_origin = slashing_origin.unwrap();
+	}: _(RawOrigin::Signed(slashing_origin), bob, service_id, Percent::from_percent(50))
+
+	dispute {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+		let service = Pallet::<T>::services(service_id).unwrap();
+		let (slashing_origin, _) = Pallet::<T>::query_slashing_origin(&service).unwrap();
+		assert_ok!(Pallet::<T>::slash(
+			RawOrigin::Signed(slashing_origin.unwrap()).into(),
+			bob,
+			service_id,
+			Percent::from_percent(50)
+		));
+		let (dispute_origin, _) = Pallet::<T>::query_dispute_origin(&service).unwrap();
+		let era = T::OperatorDelegationManager::get_current_round();
+	}: _(RawOrigin::Signed(dispute_origin.unwrap()), era, 0)
+
+	cancel_request {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let request_id = request_service::<T>(&eve, blueprint_id, &[bob]);
+	}: _(RawOrigin::Signed(eve.clone()), request_id)
+
+	leave_service {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone(), charlie]);
+		// the operator leaves right away, without a notice period.
+	}: _(RawOrigin::Signed(bob.clone()), service_id)
+
+	propose_operator_change {
+		let n in 1 .. 32;
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let leaving = (0..n as u8)
+			.map(|i| register_operator::<T>(blueprint_id, 100u8 + i))
+			.collect::<Vec<_>>();
+		let eve = funded_account::<T>(5u8);
+		let operators = leaving.iter().cloned().chain([bob]).collect::<Vec<_>>();
+		let service_id = new_service::<T>(&eve, blueprint_id, &operators);
+		// the change gets applied right away, as no operator is joining.
+	}: _(RawOrigin::Signed(eve.clone()), service_id, vec![], leaving)
 
 	call {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-
-		let operator_preference = operator_preferences::<T>();
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(bob.clone()).into(), 0, operator_preference, Default::default());
-
-		let charlie: T::AccountId =  mock_account_id::<T>(3u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(charlie.clone()).into(), 0, operator_preference, Default::default());
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob, charlie, dave]);
+	}: _(RawOrigin::Signed(eve.clone()), service_id, 0, vec![Field::Uint8(2)])
 
-		let dave: T::AccountId =  mock_account_id::<T>(4u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(dave.clone()).into(), 0, operator_preference, Default::default());
-
-		let eve: T::AccountId =  mock_account_id::<T>(5u8);
-		let _= Pallet::<T>::request(
+	submit_result {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let charlie = register_operator::<T>(blueprint_id, 3u8);
+		let dave = register_operator::<T>(blueprint_id, 4u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone(), charlie, dave]);
+		let keygen_job_call_id = Pallet::<T>::next_job_call_id();
+		assert_ok!(Pallet::<T>::call(
 			RawOrigin::Signed(eve.clone()).into(),
+			service_id,
 			0,
-			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
-			100u32.into(),
-			Default::default()
-		);
+			vec![Field::Uint8(2)]
+		));
 
+		let key_type = KeyTypeId(*b"mdkg");
+		let dkg = sp_io::crypto::ecdsa_generate(key_type, None);
 	}: _(
-			RawOrigin::Signed(eve.clone()),
-			0,
-			0,
-			vec![Field::Uint8(2)].try_into().unwrap()
+			RawOrigin::Signed(bob.clone()),
+			service_id,
+			keygen_job_call_id,
+			vec![Field::Bytes(dkg.0.to_vec().try_into().unwrap())]
 		)
 
+	call_batch {
+		let n in 1 .. T::MaxJobBatchSize::get();
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob]);
+
+		let calls = (0..n).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(eve.clone()), service_id, calls)
+
+	submit_results_batch {
+		let n in 1 .. T::MaxJobBatchSize::get();
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+
+		let first_call_id = Pallet::<T>::next_job_call_id();
+		let calls = (0..n).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		assert_ok!(Pallet::<T>::call_batch(RawOrigin::Signed(eve.clone()).into(), service_id, calls));
 
-	submit_result {
-		let alice: T::AccountId = mock_account_id::<T>(1u8);
-		let blueprint = cggmp21_blueprint::<T>();
-		let _= Pallet::<T>::create_blueprint(RawOrigin::Signed(alice.clone()).into(), blueprint);
-
-		let operator_preference = operator_preferences::<T>();
-
-		let bob: T::AccountId =  mock_account_id::<T>(2u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(bob.clone()).into(), 0, operator_preference, Default::default());
-
-		let charlie: T::AccountId =  mock_account_id::<T>(3u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(charlie.clone()).into(), 0, operator_preference, Default::default());
-
-		let dave: T::AccountId =  mock_account_id::<T>(4u8);
-		let _= Pallet::<T>::register(RawOrigin::Signed(dave.clone()).into(), 0, operator_preference, Default::default());
-
-		let eve: T::AccountId =  mock_account_id::<T>(5u8);
-		let _= Pallet::<T>::request(
-			RawOrigin::Signed(eve.clone()).into(),
+		let key_type = KeyTypeId(*b"mdkg");
+		let results = (0..u64::from(n))
+			.map(|i| {
+				let dkg = sp_io::crypto::ecdsa_generate(key_type, None);
+				(first_call_id + i, vec![Field::Bytes(dkg.0.to_vec().try_into().unwrap())])
+			})
+			.collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(bob.clone()), service_id, results)
+
+	// The public inputs of a job result are bounded by `MaxFieldsSize`, which is why the
+	// verification is measured on its own, with as many public inputs as the key allows.
+	verify_result_proof {
+		let k in VERIFYING_KEY_BASE_LENGTH .. T::MaxVerifyingKeyLength::get();
+		let (key, public_inputs, proof) =
+			groth16_bn254_verification_case((k - VERIFYING_KEY_BASE_LENGTH) / 32).unwrap();
+		crate::VerifyingKeys::<T>::insert(
 			0,
-			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
-			100u32.into(),
-			Default::default()
+			0,
+			BoundedVec::<_, T::MaxVerifyingKeyLength>::try_from(key).unwrap(),
 		);
+	}: {
+		let key = Pallet::<T>::verifying_keys(0, 0).unwrap();
+		let valid = ArkworksVerifierGroth16Bn254::verify(&public_inputs, &proof, &key);
+		assert!(matches!(valid, Ok(true)));
+	}
 
-		let _= Pallet::<T>::call(
+	verify_result_signature {
+		let key_type = KeyTypeId(*b"mdkg");
+		let group_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		crate::ServiceGroupKeys::<T>::insert(0, GroupKey::EcdsaSecp256k1(group_key));
+		let job_call = JobCall::<T::Constraints, T::AccountId> {
+			service_id: 0,
+			job: 1,
+			args: vec![Field::Uint64(0), Field::Bytes(b"hello".to_vec().try_into().unwrap())]
+				.try_into()
+				.unwrap(),
+			caller: mock_account_id::<T>(5u8),
+		};
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(key_type, &group_key, &job_call.signing_hash(1))
+				.unwrap();
+		let result = vec![Field::Bytes(signature.0.to_vec().try_into().unwrap())];
+	}: {
+		assert_ok!(Pallet::<T>::verify_group_signature(1, &job_call, 0, &result));
+	}
+
+	register_group_key {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+		let keygen_job_call_id = Pallet::<T>::next_job_call_id();
+		assert_ok!(Pallet::<T>::call(
 			RawOrigin::Signed(eve.clone()).into(),
+			service_id,
 			0,
-			0,
-			vec![Field::Uint8(2)].try_into().unwrap()
-		);
-
-		let keygen_job_call_id = 0;
+			vec![Field::Uint8(2)]
+		));
 		let key_type = KeyTypeId(*b"mdkg");
 		let dkg = sp_io::crypto::ecdsa_generate(key_type, None);
-
+		assert_ok!(Pallet::<T>::submit_result(
+			RawOrigin::Signed(bob).into(),
+			service_id,
+			keygen_job_call_id,
+			vec![Field::Bytes(dkg.0.to_vec().try_into().unwrap())]
+		));
 	}: _(
-			RawOrigin::Signed(bob.clone()),
-			0,
+			RawOrigin::Signed(eve.clone()),
+			service_id,
 			keygen_job_call_id,
-			vec![Field::Bytes(dkg.0.to_vec().try_into().unwrap())].try_into().unwrap()
+			0,
+			SignatureScheme::EcdsaSecp256k1
 		)
 
+	acknowledge_job_result {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = create_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+		let call_id = Pallet::<T>::next_job_call_id();
+		assert_ok!(Pallet::<T>::call(
+			RawOrigin::Signed(eve.clone()).into(),
+			service_id,
+			0,
+			vec![Field::Uint8(2)]
+		));
+		let key_type = KeyTypeId(*b"mdkg");
+		let dkg = sp_io::crypto::ecdsa_generate(key_type, None);
+		assert_ok!(Pallet::<T>::submit_result(
+			RawOrigin::Signed(bob).into(),
+			service_id,
+			call_id,
+			vec![Field::Bytes(dkg.0.to_vec().try_into().unwrap())]
+		));
+	}: _(RawOrigin::Signed(eve.clone()), service_id, call_id)
+
+	heartbeat {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = heartbeat_blueprint::<T>(&alice);
+		let bob = register_operator::<T>(blueprint_id, 2u8);
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+		// the operator becomes responsive again.
+		let now = frame_system::Pallet::<T>::block_number();
+		crate::UnresponsiveOperators::<T>::insert(service_id, &bob, now);
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(10u32.into()));
+	}: _(RawOrigin::Signed(bob.clone()), service_id)
+
+	submit_heartbeat {
+		let alice = funded_account::<T>(1u8);
+		let blueprint_id = heartbeat_blueprint::<T>(&alice);
+		let bob = funded_account::<T>(2u8);
+		let key_type = KeyTypeId(*b"mdkg");
+		let key = sp_io::crypto::ecdsa_generate(key_type, None);
+		assert_ok!(Pallet::<T>::register(
+			RawOrigin::Signed(bob.clone()).into(),
+			blueprint_id,
+			OperatorPreferences { key, price_targets: Default::default() },
+			Default::default(),
+			0u32.into()
+		));
+		let eve = funded_account::<T>(5u8);
+		let service_id = new_service::<T>(&eve, blueprint_id, &[bob.clone()]);
+		// the operator becomes responsive again.
+		let now = frame_system::Pallet::<T>::block_number();
+		crate::UnresponsiveOperators::<T>::insert(service_id, &bob, now);
+		let now = now.saturating_add(10u32.into());
+		frame_system::Pallet::<T>::set_block_number(now);
+
+		let heartbeat = OperatorHeartbeat { service_id, operator: bob, block_number: now };
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(key_type, &key, &heartbeat.signing_hash()).unwrap();
+	}: _(RawOrigin::None, heartbeat, signature)
+
 }
 
 // Define the module and associated types for the benchmarks
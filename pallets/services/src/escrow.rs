@@ -0,0 +1,196 @@
+use super::*;
+use crate::types::BalanceOf;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Saturating, Zero},
+	Percent, Rounding, SaturatedConversion,
+};
+use sp_std::vec::Vec;
+use tangle_primitives::services::{Service, ServiceEscrow};
+
+type ServiceOf<T> = Service<
+	<T as Config>::Constraints,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as Config>::AssetId,
+>;
+
+impl<T: Config> Pallet<T> {
+	/// Refunds the value escrowed by a pending service request back to its owner.
+	///
+	/// # Returns
+	/// * `BalanceOf<T>` - The refunded value.
+	pub(crate) fn refund_request_escrow(
+		request_id: u64,
+		owner: &T::AccountId,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let value = RequestEscrow::<T>::take(request_id);
+		if !value.is_zero() {
+			T::Currency::transfer(
+				&Self::account_id(),
+				owner,
+				value,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Ok(value)
+	}
+
+	/// Refunds the value escrowed on the registration of an operator for a blueprint back to
+	/// the operator.
+	///
+	/// # Returns
+	/// * `BalanceOf<T>` - The refunded value.
+	pub(crate) fn refund_registration_escrow(
+		blueprint_id: u64,
+		operator: &T::AccountId,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let value = RegistrationEscrow::<T>::take(blueprint_id, operator);
+		if !value.is_zero() {
+			T::Currency::transfer(
+				&Self::account_id(),
+				operator,
+				value,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Ok(value)
+	}
+
+	/// Moves the value escrowed by an approved service request to the escrow of the newly
+	/// initiated service.
+	pub(crate) fn open_service_escrow(request_id: u64, service_id: u64) {
+		let value = RequestEscrow::<T>::take(request_id);
+		ServiceEscrows::<T>::insert(service_id, ServiceEscrow { value, paid_out: Zero::zero() });
+	}
+
+	/// Pays out the part of the service escrow that vested until `now` to the operators of the
+	/// service, weighted by their restaking percentage.
+	///
+	/// The escrowed value vests linearly from the start block to the end block of the service.
+	///
+	/// # Returns
+	/// * `Vec<(T::AccountId, BalanceOf<T>)>` - The payout of each operator.
+	pub(crate) fn payout_service_escrow(
+		service: &ServiceOf<T>,
+		now: BlockNumberFor<T>,
+	) -> Result<Vec<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+		let mut escrow = Self::service_escrow(service.id)?;
+		let vested = Self::vested_service_value(service, escrow.value, now);
+		let amount = vested.saturating_sub(escrow.paid_out);
+		if amount.is_zero() {
+			return Ok(Vec::new());
+		}
+
+		let payouts = Self::operator_shares(&service.operators, amount);
+		for (operator, share) in &payouts {
+			T::Currency::transfer(
+				&Self::account_id(),
+				operator,
+				*share,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		escrow.paid_out.saturating_accrue(amount);
+		ServiceEscrows::<T>::insert(service.id, escrow);
+		Self::deposit_event(Event::ServicePaidOut {
+			service_id: service.id,
+			blueprint_id: service.blueprint,
+			payouts: payouts.clone(),
+		});
+		Ok(payouts)
+	}
+
+	/// Closes the escrow of a terminated service.
+	///
+	/// The value vested until `now` is paid out to the operators, and the rest is refunded to
+	/// the owner of the service.
+	///
+	/// # Returns
+	/// * `BalanceOf<T>` - The value refunded to the owner.
+	pub(crate) fn close_service_escrow(
+		service: &ServiceOf<T>,
+		now: BlockNumberFor<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if !ServiceEscrows::<T>::contains_key(service.id) {
+			return Ok(Zero::zero());
+		}
+		Self::payout_service_escrow(service, now)?;
+		let escrow = ServiceEscrows::<T>::take(service.id)?;
+		let remaining = escrow.value.saturating_sub(escrow.paid_out);
+		if !remaining.is_zero() {
+			T::Currency::transfer(
+				&Self::account_id(),
+				&service.owner,
+				remaining,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Ok(remaining)
+	}
+
+	/// The part of `value` that vested until `now` over the lifetime of the service.
+	fn vested_service_value(
+		service: &ServiceOf<T>,
+		value: BalanceOf<T>,
+		now: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		if now >= service.end_block || service.ttl.is_zero() {
+			return value;
+		}
+		let elapsed = now.saturating_sub(service.start_block).saturated_into::<u128>();
+		let lifetime = service.ttl.saturated_into::<u128>();
+		multiply_by_rational_with_rounding(
+			value.saturated_into::<u128>(),
+			elapsed,
+			lifetime,
+			Rounding::Down,
+		)
+		.map_or(value, |vested| vested.saturated_into())
+	}
+
+	/// Splits `amount` between the operators, weighted by their restaking percentage.
+	///
+	/// If none of the operators restaked anything, the amount is split evenly. The rounding
+	/// remainder goes to the first operator.
+	fn operator_shares(
+		operators: &[(T::AccountId, Percent)],
+		amount: BalanceOf<T>,
+	) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		let total_percent = operators
+			.iter()
+			.map(|(_, percent)| u128::from(percent.deconstruct()))
+			.sum::<u128>();
+		let weight_of = |percent: &Percent| match total_percent {
+			0 => 1,
+			_ => u128::from(percent.deconstruct()),
+		};
+		let total_weight = match total_percent {
+			0 => operators.len() as u128,
+			total => total,
+		};
+
+		let mut shares = operators
+			.iter()
+			.map(|(operator, percent)| {
+				let share = multiply_by_rational_with_rounding(
+					amount.saturated_into::<u128>(),
+					weight_of(percent),
+					total_weight,
+					Rounding::Down,
+				)
+				.unwrap_or_default();
+				(operator.clone(), share.saturated_into::<BalanceOf<T>>())
+			})
+			.collect::<Vec<_>>();
+
+		let distributed = shares
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |acc, (_, share)| acc.saturating_add(*share));
+		if let Some((_, first)) = shares.first_mut() {
+			first.saturating_accrue(amount.saturating_sub(distributed));
+		}
+		shares
+	}
+}
@@ -0,0 +1,382 @@
+use super::*;
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Index a pending service request, so that it gets expired once the `expiry` block is
+	/// reached.
+	pub(crate) fn schedule_service_request_expiry(request_id: u64, expiry: BlockNumberFor<T>) {
+		ServiceRequestExpiries::<T>::insert(expiry, request_id, ());
+		ServiceRequestExpiryCursor::<T>::mutate(|cursor| match cursor {
+			Some(next) if *next <= expiry => {},
+			_ => *cursor = Some(expiry),
+		});
+	}
+
+	/// Index a service instance, so that it gets terminated once its `end_block` is reached.
+	pub(crate) fn schedule_service_expiry(service_id: u64, end_block: BlockNumberFor<T>) {
+		ServiceExpiries::<T>::insert(end_block, service_id, ());
+		ServiceExpiryCursor::<T>::mutate(|cursor| match cursor {
+			Some(next) if *next <= end_block => {},
+			_ => *cursor = Some(end_block),
+		});
+	}
+
+	/// Index a job call, so that it times out once its response window ends at `deadline`.
+	pub(crate) fn schedule_job_call_deadline(
+		service_id: u64,
+		call_id: u64,
+		deadline: BlockNumberFor<T>,
+	) {
+		JobCallDeadlines::<T>::insert(deadline, (service_id, call_id), ());
+		JobCallResponseDeadlines::<T>::insert(service_id, call_id, deadline);
+		JobCallDeadlineCursor::<T>::mutate(|cursor| match cursor {
+			Some(next) if *next <= deadline => {},
+			_ => *cursor = Some(deadline),
+		});
+	}
+
+	/// Index an operator leaving a service, so that it gets removed from the service once the
+	/// `exit_block` is reached.
+	pub(crate) fn schedule_operator_exit(
+		service_id: u64,
+		operator: &T::AccountId,
+		exit_block: BlockNumberFor<T>,
+	) {
+		OperatorExits::<T>::insert(service_id, operator, exit_block);
+		OperatorExitSchedule::<T>::insert(exit_block, (service_id, operator.clone()), ());
+		OperatorExitCursor::<T>::mutate(|cursor| match cursor {
+			Some(next) if *next <= exit_block => {},
+			_ => *cursor = Some(exit_block),
+		});
+	}
+
+	/// Expires the pending service requests whose expiry block is at or before `now`.
+	///
+	/// Every expired request is removed and its escrowed value is refunded to the owner.
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn expire_service_requests(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		// Reading the request, its escrow and both balances, then writing them back.
+		let per_request = T::DbWeight::get().reads_writes(4, 5);
+		let (consumed, cursor) = Self::sweep_expiries(
+			now,
+			remaining_weight,
+			per_request,
+			ServiceRequestExpiryCursor::<T>::get(),
+			|block, limit| {
+				ServiceRequestExpiries::<T>::iter_key_prefix(block).take(limit).collect()
+			},
+			|block, request_id| {
+				ServiceRequestExpiries::<T>::remove(block, request_id);
+				Self::expire_service_request(request_id);
+				per_request
+			},
+		);
+		if let Some(cursor) = cursor {
+			ServiceRequestExpiryCursor::<T>::put(cursor);
+		}
+		consumed
+	}
+
+	/// Terminates the service instances whose lifetime ended at or before `now`.
+	///
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn expire_services(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// Reading and writing the service, its blueprint, its escrow, the owner's services and
+		// balance, the pallet's balance, and the profile and balance of each operator, then
+		// queueing the cleanup of its job data.
+		let max_operators = u64::from(T::MaxOperatorsPerService::get());
+		let per_service_storage = T::DbWeight::get().reads_writes(
+			max_operators.saturating_mul(2).saturating_add(6),
+			max_operators.saturating_mul(2).saturating_add(8),
+		);
+		// The termination hook of the blueprint manager, assuming the worst case gas usage.
+		let per_service = per_service_storage.saturating_add(Self::max_hook_weight(1));
+		let (consumed, cursor) = Self::sweep_expiries(
+			now,
+			remaining_weight,
+			per_service,
+			ServiceExpiryCursor::<T>::get(),
+			|block, limit| ServiceExpiries::<T>::iter_key_prefix(block).take(limit).collect(),
+			|block, service_id| {
+				ServiceExpiries::<T>::remove(block, service_id);
+				per_service_storage.saturating_add(Self::expire_service(service_id))
+			},
+		);
+		if let Some(cursor) = cursor {
+			ServiceExpiryCursor::<T>::put(cursor);
+		}
+		consumed
+	}
+
+	/// Times out the job calls whose response window ended at or before `now` without a final
+	/// result.
+	///
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn expire_job_calls(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// Reading the job call, its result, its submissions and the service, then writing the
+		// timed out call, its deadline and the missed calls of each operator.
+		let max_operators = u64::from(T::MaxOperatorsPerService::get());
+		let per_call = T::DbWeight::get().reads_writes(4, max_operators.saturating_add(2));
+		let (consumed, cursor) = Self::sweep_expiries(
+			now,
+			remaining_weight,
+			per_call,
+			JobCallDeadlineCursor::<T>::get(),
+			|block, limit| JobCallDeadlines::<T>::iter_key_prefix(block).take(limit).collect(),
+			|block, (service_id, call_id)| {
+				JobCallDeadlines::<T>::remove(block, (service_id, call_id));
+				JobCallResponseDeadlines::<T>::remove(service_id, call_id);
+				Self::time_out_job_call(service_id, call_id);
+				per_call
+			},
+		);
+		if let Some(cursor) = cursor {
+			JobCallDeadlineCursor::<T>::put(cursor);
+		}
+		consumed
+	}
+
+	/// Removes the operators whose notice period ended at or before `now` from the services they
+	/// are leaving.
+	///
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn process_operator_exits(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		// Reading and writing the exit, the service, its blueprint, its escrow, the operator's
+		// profile and preferences, and the balance of each operator paid out.
+		let max_operators = u64::from(T::MaxOperatorsPerService::get());
+		let per_exit_storage = T::DbWeight::get()
+			.reads_writes(max_operators.saturating_add(7), max_operators.saturating_add(7));
+		// The exit hook of the blueprint manager, assuming the worst case gas usage.
+		let per_exit = per_exit_storage.saturating_add(Self::max_hook_weight(1));
+		let (consumed, cursor) = Self::sweep_expiries(
+			now,
+			remaining_weight,
+			per_exit,
+			OperatorExitCursor::<T>::get(),
+			|block, limit| OperatorExitSchedule::<T>::iter_key_prefix(block).take(limit).collect(),
+			|block, (service_id, operator)| {
+				OperatorExitSchedule::<T>::remove(block, (service_id, operator.clone()));
+				per_exit_storage
+					.saturating_add(Self::remove_service_operator(service_id, &operator))
+			},
+		);
+		if let Some(cursor) = cursor {
+			OperatorExitCursor::<T>::put(cursor);
+		}
+		consumed
+	}
+
+	/// Walks the expiry buckets from `cursor` up to `now`, calling `expire` on every item
+	/// while there is enough weight left to process an item of `max_item_weight`.
+	///
+	/// `expired_in` returns at most `limit` items of the bucket at the given block, and
+	/// `expire` returns the weight it actually consumed.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	/// * `Option<BlockNumberFor<T>>` - The updated cursor, if there was one.
+	pub(crate) fn sweep_expiries<I>(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+		max_item_weight: Weight,
+		cursor: Option<BlockNumberFor<T>>,
+		expired_in: impl Fn(BlockNumberFor<T>, usize) -> Vec<I>,
+		mut expire: impl FnMut(BlockNumberFor<T>, I) -> Weight,
+	) -> (Weight, Option<BlockNumberFor<T>>) {
+		let db = T::DbWeight::get();
+		// Reading and updating the cursor.
+		let mut consumed = db.reads_writes(1, 1);
+		// Reading an expiry bucket.
+		let per_bucket = db.reads(1);
+
+		if remaining_weight.any_lt(consumed) {
+			return (Weight::zero(), cursor);
+		}
+
+		let Some(mut cursor) = cursor else {
+			return (db.reads(1), None);
+		};
+
+		while cursor <= now {
+			if remaining_weight.any_lt(consumed.saturating_add(per_bucket)) {
+				break;
+			}
+			consumed.saturating_accrue(per_bucket);
+
+			let budget = remaining_weight
+				.saturating_sub(consumed)
+				.checked_div_per_component(&max_item_weight)
+				.map_or(usize::MAX, |n| usize::try_from(n).unwrap_or(usize::MAX));
+			// Fetch one extra item, so that we know whether the bucket got fully processed.
+			let expired = expired_in(cursor, budget.saturating_add(1));
+			let exhausted = expired.len() <= budget;
+
+			for item in expired.into_iter().take(budget) {
+				consumed.saturating_accrue(expire(cursor, item));
+			}
+
+			if !exhausted {
+				break;
+			}
+			cursor = cursor.saturating_add(One::one());
+		}
+
+		(consumed, Some(cursor))
+	}
+
+	/// Removes an expired service request and refunds its escrowed value to the owner.
+	fn expire_service_request(request_id: u64) {
+		let Ok(request) = ServiceRequests::<T>::take(request_id) else {
+			// the request got approved in the meantime.
+			return;
+		};
+		let refunded =
+			Self::refund_request_escrow(request_id, &request.owner).unwrap_or_else(|e| {
+				log::error!(
+					target: "services",
+					"Failed to refund the owner of the expired request #{}: {:?}",
+					request_id,
+					e,
+				);
+				Zero::zero()
+			});
+
+		Self::deposit_event(Event::ServiceRequestExpired {
+			owner: request.owner,
+			request_id,
+			blueprint_id: request.blueprint,
+			refunded,
+		});
+	}
+
+	/// Terminates a service instance whose lifetime has elapsed.
+	///
+	/// Unlike a termination by the owner, the blueprint manager can not interrupt it.
+	///
+	/// The job data of the service is queued for cleanup, and the fees still escrowed by its
+	/// job calls are refunded along with it.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the termination hook.
+	fn expire_service(service_id: u64) -> Weight {
+		let Ok(service) = Instances::<T>::take(service_id) else {
+			// the service got terminated by its owner in the meantime.
+			return Weight::zero();
+		};
+		UserServices::<T>::mutate(&service.owner, |service_ids| service_ids.remove(&service_id));
+		OperatorChangeRequests::<T>::remove(service_id);
+		for (operator, _) in &service.operators {
+			OperatorsProfile::<T>::mutate_exists(operator, |profile| {
+				if let Some(profile) = profile {
+					profile.services.remove(&service_id);
+				}
+			});
+		}
+
+		let weight = match Self::service_blueprint(&service) {
+			Ok(blueprint) => {
+				match Self::on_service_termination_hook(&blueprint, service_id, &service.owner) {
+					Ok((_, weight)) => weight,
+					Err(e) => {
+						log::error!(
+							target: "services",
+							"Termination hook of the expired service #{} failed: {:?}",
+							service_id,
+							e,
+						);
+						e.post_info.actual_weight.unwrap_or_default()
+					},
+				}
+			},
+			Err(_) => Weight::zero(),
+		};
+
+		Self::queue_service_cleanup(service_id);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let refunded = Self::close_service_escrow(&service, now).unwrap_or_else(|e| {
+			log::error!(
+				target: "services",
+				"Failed to settle the escrow of the expired service #{}: {:?}",
+				service_id,
+				e,
+			);
+			Zero::zero()
+		});
+
+		Self::deposit_event(Event::ServiceTerminated {
+			owner: service.owner,
+			service_id,
+			blueprint_id: service.blueprint,
+			reason: ServiceTerminationReason::Expired,
+			refunded,
+		});
+		weight
+	}
+
+	/// Marks a job call as timed out if it has no final result, refunds its fee to the caller,
+	/// and records a missed call for every operator of the service that did not submit a
+	/// result.
+	fn time_out_job_call(service_id: u64, call_id: u64) {
+		if JobResults::<T>::contains_key(service_id, call_id) {
+			// the call got a final result in time.
+			return;
+		}
+		let (Ok(job_call), Ok(service)) =
+			(JobCalls::<T>::get(service_id, call_id), Instances::<T>::get(service_id))
+		else {
+			// the service got terminated in the meantime.
+			return;
+		};
+
+		let submissions = JobResultSubmissions::<T>::get(service_id, call_id);
+		let missed = service
+			.operators
+			.iter()
+			.map(|(operator, _)| operator)
+			.filter(|operator| !submissions.iter().any(|(submitter, _)| submitter == *operator))
+			.cloned()
+			.collect::<Vec<_>>();
+		for operator in &missed {
+			MissedJobCalls::<T>::mutate(service_id, operator, |missed| {
+				missed.saturating_inc();
+			});
+		}
+
+		Self::refund_job_call_fee(service_id, call_id);
+
+		// bounded by the operators of the service.
+		let bounded_missed = BoundedVec::truncate_from(missed.clone());
+		TimedOutJobCalls::<T>::insert(service_id, call_id, bounded_missed);
+		Self::schedule_job_call_pruning(service_id, call_id);
+		Self::deposit_event(Event::JobCallTimedOut {
+			service_id,
+			call_id,
+			job: job_call.job,
+			missed,
+		});
+	}
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::types::{BalanceOf, JobCallFee};
+use frame_support::traits::{fungibles::Mutate, tokens::Preservation};
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	SaturatedConversion,
+};
+use sp_std::vec::Vec;
+use tangle_primitives::services::{FeeAsset, Field, JobFee};
+
+impl<T: Config> Pallet<T> {
+	/// Escrows the fee of a job call, paid by its caller.
+	///
+	/// # Parameters
+	/// * `payer` - The account calling the job.
+	/// * `service_id` - The ID of the service.
+	/// * `call_id` - The ID of the job call.
+	/// * `fee` - The fee of the called job.
+	/// * `args` - The arguments of the job call.
+	pub(crate) fn charge_job_call_fee(
+		payer: &T::AccountId,
+		service_id: u64,
+		call_id: u64,
+		fee: &JobFee,
+		args: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<(), DispatchError> {
+		let asset = match fee.asset() {
+			FeeAsset::Native => None,
+			FeeAsset::Custom(id) => {
+				Some(T::AssetId::try_from(id).map_err(|_| Error::<T>::InvalidJobFeeAsset)?)
+			},
+		};
+		let amount: BalanceOf<T> = fee.amount(args.encoded_size()).saturated_into();
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		Self::transfer_fee(asset, payer, &Self::account_id(), amount, Preservation::Preserve)?;
+		JobCallFees::<T>::insert(
+			service_id,
+			call_id,
+			JobCallFee { payer: payer.clone(), asset, amount },
+		);
+		Ok(())
+	}
+
+	/// Pays out the fee escrowed by a job call evenly to the operators whose results got
+	/// accepted. The rounding remainder goes to the first operator.
+	///
+	/// The shares that fail to transfer stay escrowed, and get refunded to the caller once the
+	/// job call is pruned.
+	pub(crate) fn pay_job_call_fee(service_id: u64, call_id: u64, operators: &[T::AccountId]) {
+		let Some(mut fee) = JobCallFees::<T>::take(service_id, call_id) else {
+			return;
+		};
+		let count = BalanceOf::<T>::from(operators.len() as u32).max(One::one());
+		let share = fee.amount / count;
+		let mut payouts =
+			operators.iter().map(|operator| (operator.clone(), share)).collect::<Vec<_>>();
+		if let Some((_, first)) = payouts.first_mut() {
+			first.saturating_accrue(fee.amount.saturating_sub(share.saturating_mul(count)));
+		}
+
+		let mut unpaid = BalanceOf::<T>::zero();
+		payouts.retain(|(operator, amount)| {
+			match Self::transfer_fee(
+				fee.asset,
+				&Self::account_id(),
+				operator,
+				*amount,
+				Preservation::Expendable,
+			) {
+				Ok(()) => true,
+				Err(e) => {
+					log::error!(
+						target: "services",
+						"Failed to pay the fee of the job call #{} to {:?}: {:?}",
+						call_id,
+						operator,
+						e,
+					);
+					unpaid.saturating_accrue(*amount);
+					false
+				},
+			}
+		});
+
+		let asset = fee.asset;
+		if !unpaid.is_zero() {
+			fee.amount = unpaid;
+			JobCallFees::<T>::insert(service_id, call_id, fee);
+		}
+		if !payouts.is_empty() {
+			Self::deposit_event(Event::JobFeePaid { service_id, call_id, asset, payouts });
+		}
+	}
+
+	/// Refunds the fee escrowed by a failed job call back to its caller.
+	pub(crate) fn refund_job_call_fee(service_id: u64, call_id: u64) {
+		let Some(fee) = JobCallFees::<T>::take(service_id, call_id) else {
+			return;
+		};
+		if let Err(e) = Self::transfer_fee(
+			fee.asset,
+			&Self::account_id(),
+			&fee.payer,
+			fee.amount,
+			Preservation::Expendable,
+		) {
+			log::error!(
+				target: "services",
+				"Failed to refund the fee of the job call #{}: {:?}",
+				call_id,
+				e,
+			);
+			return;
+		}
+
+		Self::deposit_event(Event::JobFeeRefunded {
+			service_id,
+			call_id,
+			payer: fee.payer,
+			asset: fee.asset,
+			amount: fee.amount,
+		});
+	}
+
+	fn transfer_fee(
+		asset: Option<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		preservation: Preservation,
+	) -> DispatchResult {
+		match asset {
+			None => {
+				let existence = match preservation {
+					Preservation::Expendable => ExistenceRequirement::AllowDeath,
+					_ => ExistenceRequirement::KeepAlive,
+				};
+				T::Currency::transfer(from, to, amount, existence)
+			},
+			Some(asset) => {
+				T::Fungibles::transfer(asset, from, to, amount, preservation).map(|_| ())
+			},
+		}
+	}
+}
@@ -7,18 +7,97 @@ use alloc::{boxed::Box, string::String, vec, vec::Vec};
 use std::{boxed::Box, string::String, vec::Vec};
 
 use ethabi::Token;
-use frame_support::dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo};
+use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
+	storage::{with_transaction_opaque, TransactionOutcome},
+};
 use sp_core::{H160, U256};
 use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::collections::btree_map::BTreeMap;
 use tangle_primitives::services::{
-	BlueprintManager, Field, OperatorPreferences, Service, ServiceBlueprint,
+	BlueprintManager, BlueprintState, Field, FieldType, JobCall, JobCallerPolicy, JobDefinition,
+	JobResultVerifier, OperatorPreferences, Service, ServiceBlueprint,
 };
 
 use super::*;
-use crate::types::BalanceOf;
+use crate::types::{BalanceOf, MaxJobsPerServiceOf};
 
 #[allow(clippy::too_many_arguments)]
 impl<T: Config> Pallet<T> {
+	/// The gas limit of the calls to the blueprint manager hooks, unless the blueprint declares
+	/// its own.
+	pub(crate) const HOOK_GAS_LIMIT: u64 = 300_000;
+
+	/// The gas limit of the ERC-165 `supportsInterface` calls to the blueprint managers.
+	pub(crate) const SUPPORTS_INTERFACE_GAS_LIMIT: u64 = 30_000;
+
+	/// Returns the gas limit of each call to the hooks of the blueprint manager.
+	///
+	/// The limit declared by the blueprint is capped by `MaxHookGasLimit`.
+	pub fn hook_gas_limit(blueprint: &ServiceBlueprint<T::Constraints>) -> u64 {
+		let gas_limit = match blueprint.hook_gas_limit {
+			0 => Self::HOOK_GAS_LIMIT,
+			gas_limit => gas_limit,
+		};
+		gas_limit.min(T::MaxHookGasLimit::get())
+	}
+
+	/// Returns the weight charged up front for the given number of calls to the hooks of a
+	/// blueprint manager, assuming they all use the highest gas limit a blueprint can declare.
+	pub fn max_hook_weight(hooks: u32) -> Weight {
+		T::EvmGasWeightMapping::gas_to_weight(T::MaxHookGasLimit::get(), true)
+			.saturating_mul(hooks.into())
+	}
+
+	/// Returns the weight charged up front for the given number of calls to the hooks of a
+	/// blueprint manager made for a batch, including the ERC-165 check of the batched hooks.
+	///
+	/// The hooks of a batch are bounded by the `MaxBatchHookGas` budget, see
+	/// [`Self::ensure_batch_hook_gas`].
+	pub fn max_batch_hook_weight(hooks: u32) -> Weight {
+		let gas = T::MaxHookGasLimit::get()
+			.saturating_mul(hooks.into())
+			.saturating_add(Self::SUPPORTS_INTERFACE_GAS_LIMIT)
+			.min(T::MaxBatchHookGas::get());
+		T::EvmGasWeightMapping::gas_to_weight(gas, true)
+	}
+
+	/// Ensures that the given number of calls to the hooks of a blueprint manager, made for a
+	/// batch, fit in the `MaxBatchHookGas` budget with the hook gas limit of the blueprint, along
+	/// with the ERC-165 check of the batched hooks.
+	pub fn ensure_batch_hook_gas(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		hooks: u32,
+	) -> DispatchResult {
+		let gas = Self::hook_gas_limit(blueprint)
+			.saturating_mul(hooks.into())
+			.saturating_add(Self::SUPPORTS_INTERFACE_GAS_LIMIT);
+		ensure!(gas <= T::MaxBatchHookGas::get(), Error::<T>::BatchHookGasExceeded);
+		Ok(())
+	}
+
+	/// Returns the weight charged up front for a batch of `n` job calls.
+	///
+	/// Each call can trigger the caller policy hook of its job and the job call hook.
+	pub fn call_batch_weight(n: u32) -> Weight {
+		T::WeightInfo::call_batch(n)
+			.saturating_add(Self::max_batch_hook_weight(n.saturating_mul(2)))
+	}
+
+	/// Returns the weight charged up front for a batch of `n` job results.
+	pub fn submit_results_batch_weight(n: u32) -> Weight {
+		T::WeightInfo::submit_results_batch(n)
+			.saturating_add(Self::max_result_verification_weight().saturating_mul(n.into()))
+			.saturating_add(Self::max_batch_hook_weight(n))
+	}
+
+	/// Returns the weight charged up front for the native verification of a job result, which
+	/// is the most expensive of the verifiers, with the longest verifying key.
+	pub fn max_result_verification_weight() -> Weight {
+		T::WeightInfo::verify_result_proof(T::MaxVerifyingKeyLength::get())
+			.max(T::WeightInfo::verify_result_signature())
+	}
+
 	/// Returns the account id of the pallet.
 	///
 	/// This function retrieves the account id associated with the pallet by converting
@@ -38,6 +117,117 @@ impl<T: Config> Pallet<T> {
 		T::PalletEVMAddress::get()
 	}
 
+	/// Ensures the service blueprint is not deprecated.
+	pub(crate) fn ensure_blueprint_active(blueprint_id: u64) -> Result<(), Error<T>> {
+		match Self::blueprint_state(blueprint_id) {
+			BlueprintState::Active => Ok(()),
+			BlueprintState::Deprecated => Err(Error::<T>::BlueprintDeprecated),
+		}
+	}
+
+	/// Whether the account is the EVM manager contract of a service blueprint, either through the
+	/// account its address maps to, or the native account mapping to its address.
+	pub(crate) fn is_blueprint_manager(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		who: &T::AccountId,
+	) -> bool {
+		match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				T::EvmAddressMapping::into_account_id(contract) == *who ||
+					T::EvmAddressMapping::into_address(who.clone()) == contract
+			},
+			_ => false,
+		}
+	}
+
+	/// Ensures the native manager of a service blueprint, if any, is provided by the runtime.
+	pub(crate) fn ensure_blueprint_manager_exists(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+	) -> Result<(), Error<T>> {
+		match blueprint.manager {
+			BlueprintManager::Native(id) => {
+				ensure!(T::NativeManager::exists(id), Error::<T>::NativeManagerNotFound);
+				Ok(())
+			},
+			_ => Ok(()),
+		}
+	}
+
+	/// Ensures the native result verifiers of the jobs of a service blueprint refer to bytes
+	/// fields of their results.
+	pub(crate) fn ensure_result_verifiers_valid(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+	) -> Result<(), Error<T>> {
+		let is_bytes = |job: &JobDefinition<T::Constraints>, index: u8| {
+			matches!(job.result.get(usize::from(index)), Some(FieldType::Bytes))
+		};
+		let valid = blueprint.jobs.iter().all(|job| match job.verifier {
+			JobResultVerifier::Groth16 { public_inputs, proof, .. } => {
+				is_bytes(job, public_inputs) && is_bytes(job, proof)
+			},
+			JobResultVerifier::GroupSignature { signature } => is_bytes(job, signature),
+			_ => true,
+		});
+		ensure!(valid, Error::<T>::InvalidResultVerifier);
+		Ok(())
+	}
+
+	/// Ensures the parameters and the results of a service blueprint, and of its jobs, have
+	/// valid field types, see [`FieldType::is_valid`].
+	pub(crate) fn ensure_field_types_valid(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+	) -> Result<(), Error<T>> {
+		let valid = blueprint
+			.registration_params
+			.iter()
+			.chain(blueprint.request_params.iter())
+			.chain(blueprint.jobs.iter().flat_map(|job| job.params.iter().chain(job.result.iter())))
+			.all(FieldType::is_valid);
+		ensure!(valid, Error::<T>::InvalidFieldType);
+		Ok(())
+	}
+
+	/// Ensures the jobs of a service blueprint with a fee have a response window, so that the
+	/// fee of a call that gets no result is refunded once the call times out.
+	pub(crate) fn ensure_job_fees_valid(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+	) -> Result<(), Error<T>> {
+		let valid = blueprint
+			.jobs
+			.iter()
+			.all(|job| job.fee.is_none() || job.response_window.is_some());
+		ensure!(valid, Error::<T>::JobFeeWithoutResponseWindow);
+		Ok(())
+	}
+
+	/// Returns a revision of a service blueprint.
+	///
+	/// # Parameters
+	/// * `blueprint_id` - The ID of the service blueprint.
+	/// * `revision` - The revision of the service blueprint.
+	///
+	/// # Returns
+	/// * `ServiceBlueprint<T::Constraints>` - The service blueprint at that revision.
+	pub fn blueprint_at_revision(
+		blueprint_id: u64,
+		revision: u32,
+	) -> Result<ServiceBlueprint<T::Constraints>, Error<T>> {
+		Self::blueprint_revisions(blueprint_id, revision)
+	}
+
+	/// Returns the revision of the service blueprint a service is pinned to.
+	///
+	/// # Parameters
+	/// * `service` - The service instance.
+	///
+	/// # Returns
+	/// * `ServiceBlueprint<T::Constraints>` - The service blueprint the service runs.
+	pub fn service_blueprint(
+		service: &Service<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>,
+	) -> Result<ServiceBlueprint<T::Constraints>, Error<T>> {
+		Self::blueprint_at_revision(service.blueprint, service.blueprint_revision)
+	}
+
 	/// Hook to be called upon a new operator registration on a blueprint.
 	///
 	/// This function is called when a service is registered. It performs an EVM call
@@ -84,11 +274,14 @@ impl<T: Config> Pallet<T> {
 
 				let value = value.using_encoded(U256::from_little_endian);
 				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info = Self::evm_call(Self::address(), contract, value, data, gas_limit)?;
 				(info.exit_reason.is_succeed(), Self::weight_from_call_info(&info))
 			},
+			BlueprintManager::Native(id) => {
+				T::NativeManager::on_register(id, prefrences, registration_args, value)?
+			},
 			_ => (true, Weight::zero()),
 		};
 		Ok((allowed, weight))
@@ -123,11 +316,190 @@ impl<T: Config> Pallet<T> {
 
 				let args = prefrences.to_ethabi();
 				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
 				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
 			},
+			BlueprintManager::Native(id) => Ok(T::NativeManager::on_unregister(id, prefrences)?),
+			_ => Ok((true, Weight::zero())),
+		}
+	}
+
+	/// Hook to be called upon an operator joining a running service.
+	///
+	/// This function is called when an approved operator change adds an operator to a service.
+	/// It performs an EVM call to the `onOperatorJoined` function of the service blueprint's
+	/// manager contract.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `service_id` - The ID of the service.
+	/// * `prefrences` - The operator preferences.
+	/// * `restaking_percent` - The restaking percent of the operator.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///  whether the manager handled the join and the weight of the operation.
+	pub fn on_operator_joined_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service_id: u64,
+		prefrences: &OperatorPreferences,
+		restaking_percent: u8,
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("onOperatorJoined"),
+					inputs: vec![
+						ethabi::Param {
+							name: String::from("serviceId"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+						OperatorPreferences::to_ethabi_param(),
+						ethabi::Param {
+							name: String::from("restakingPercent"),
+							kind: ethabi::ParamType::Uint(8),
+							internal_type: None,
+						},
+					],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::NonPayable,
+				};
+
+				let args = iter::once(Token::Uint(U256::from(service_id)))
+					.chain(prefrences.to_ethabi())
+					.chain(iter::once(Token::Uint(U256::from(restaking_percent))))
+					.collect::<Vec<_>>();
+				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit = Self::hook_gas_limit(blueprint);
+
+				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
+				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
+			},
+			BlueprintManager::Native(id) => Ok(T::NativeManager::on_operator_joined(
+				id,
+				service_id,
+				prefrences,
+				restaking_percent,
+			)?),
+			_ => Ok((true, Weight::zero())),
+		}
+	}
+
+	/// Hook to be called upon an operator leaving a service.
+	///
+	/// This function is called when an operator gets removed from a service, either once its
+	/// notice period is over or by an approved operator change. It performs an EVM call to the
+	/// `onOperatorLeft` function of the service blueprint's manager contract.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `service_id` - The ID of the service.
+	/// * `prefrences` - The operator preferences.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///  whether the manager handled the exit and the weight of the operation.
+	pub fn on_operator_left_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service_id: u64,
+		prefrences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("onOperatorLeft"),
+					inputs: vec![
+						ethabi::Param {
+							name: String::from("serviceId"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+						OperatorPreferences::to_ethabi_param(),
+					],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::NonPayable,
+				};
+
+				let args = iter::once(Token::Uint(U256::from(service_id)))
+					.chain(prefrences.to_ethabi())
+					.collect::<Vec<_>>();
+				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit = Self::hook_gas_limit(blueprint);
+
+				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
+				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
+			},
+			BlueprintManager::Native(id) => {
+				Ok(T::NativeManager::on_operator_left(id, service_id, prefrences)?)
+			},
+			_ => Ok((true, Weight::zero())),
+		}
+	}
+
+	/// Hook to be called upon an operator missing its heartbeat for a service.
+	///
+	/// This function is called when an operator did not send a heartbeat within the
+	/// heartbeat interval of the blueprint. It performs an EVM call to the
+	/// `onOperatorUnresponsive` function of the service blueprint's manager contract.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `service_id` - The ID of the service.
+	/// * `prefrences` - The operator preferences.
+	/// * `last_seen` - The block number of the last heartbeat of the operator.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///  whether the manager handled the missed heartbeat and the weight of the operation.
+	pub fn on_operator_unresponsive_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service_id: u64,
+		prefrences: &OperatorPreferences,
+		last_seen: u64,
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("onOperatorUnresponsive"),
+					inputs: vec![
+						ethabi::Param {
+							name: String::from("serviceId"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+						OperatorPreferences::to_ethabi_param(),
+						ethabi::Param {
+							name: String::from("lastSeen"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+					],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::NonPayable,
+				};
+
+				let args = iter::once(Token::Uint(U256::from(service_id)))
+					.chain(prefrences.to_ethabi())
+					.chain(iter::once(Token::Uint(U256::from(last_seen))))
+					.collect::<Vec<_>>();
+				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit = Self::hook_gas_limit(blueprint);
+
+				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
+				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
+			},
+			BlueprintManager::Native(id) => Ok(T::NativeManager::on_operator_unresponsive(
+				id, service_id, prefrences, last_seen,
+			)?),
 			_ => Ok((true, Weight::zero())),
 		}
 	}
@@ -161,11 +533,14 @@ impl<T: Config> Pallet<T> {
 
 				let args = prefrences.to_ethabi();
 				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
 				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
 			},
+			BlueprintManager::Native(id) => {
+				Ok(T::NativeManager::on_update_price_targets(id, prefrences)?)
+			},
 			_ => Ok((true, Weight::zero())),
 		}
 	}
@@ -221,11 +596,14 @@ impl<T: Config> Pallet<T> {
 					.collect::<Vec<_>>();
 
 				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
 				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
 			},
+			BlueprintManager::Native(id) => {
+				Ok(T::NativeManager::on_approve(id, prefrences, request_id, restaking_percent)?)
+			},
 			_ => Ok((true, Weight::zero())),
 		}
 	}
@@ -272,11 +650,59 @@ impl<T: Config> Pallet<T> {
 					.collect::<Vec<_>>();
 
 				let data = call.encode_input(&args).map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
 				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
 			},
+			BlueprintManager::Native(id) => {
+				Ok(T::NativeManager::on_reject(id, prefrences, request_id)?)
+			},
+			_ => Ok((true, Weight::zero())),
+		}
+	}
+
+	/// Hook to be called upon the owner cancelling a pending service request.
+	/// This function is called when the owner cancels their service request. It performs an EVM
+	/// call to the `onRequestCancelled` function of the service blueprint's manager contract.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `request_id` - The request id.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	/// whether the manager handled the cancellation and the weight of the operation.
+	pub fn on_request_cancelled_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		request_id: u64,
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("onRequestCancelled"),
+					inputs: vec![ethabi::Param {
+						name: String::from("requestId"),
+						kind: ethabi::ParamType::Uint(64),
+						internal_type: None,
+					}],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::NonPayable,
+				};
+
+				let data = call
+					.encode_input(&[Token::Uint(U256::from(request_id))])
+					.map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit = Self::hook_gas_limit(blueprint);
+
+				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
+				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
+			},
+			BlueprintManager::Native(id) => {
+				Ok(T::NativeManager::on_request_cancelled(id, request_id)?)
+			},
 			_ => Ok((true, Weight::zero())),
 		}
 	}
@@ -307,7 +733,7 @@ impl<T: Config> Pallet<T> {
 		operators: &[OperatorPreferences],
 		request_args: &[Field<T::Constraints, T::AccountId>],
 		permitted_callers: &[T::AccountId],
-		_assets: &[T::AssetId],
+		assets: &[T::AssetId],
 		ttl: BlockNumberFor<T>,
 		value: BalanceOf<T>,
 	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
@@ -389,11 +815,22 @@ impl<T: Config> Pallet<T> {
 					])
 					.map_err(|_| Error::<T>::EVMAbiEncode)?;
 				let value = value.using_encoded(U256::from_little_endian);
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info = Self::evm_call(Self::address(), contract, value, data, gas_limit)?;
 				(info.exit_reason.is_succeed(), Self::weight_from_call_info(&info))
 			},
+			BlueprintManager::Native(id) => T::NativeManager::on_request(
+				id,
+				requester,
+				request_id,
+				operators,
+				request_args,
+				permitted_callers,
+				assets,
+				ttl,
+				value,
+			)?,
 			_ => (true, Weight::zero()),
 		};
 
@@ -421,7 +858,7 @@ impl<T: Config> Pallet<T> {
 		service_id: u64,
 		owner: &T::AccountId,
 		permitted_callers: &[T::AccountId],
-		_assets: &[T::AssetId],
+		assets: &[T::AssetId],
 		ttl: BlockNumberFor<T>,
 	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
 		let (allowed, weight) = match blueprint.manager {
@@ -488,10 +925,19 @@ impl<T: Config> Pallet<T> {
 					])
 					.map_err(|_| Error::<T>::EVMAbiEncode)?;
 				let value = U256::zero();
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 				let info = Self::evm_call(Self::address(), contract, value, data, gas_limit)?;
 				(info.exit_reason.is_succeed(), Self::weight_from_call_info(&info))
 			},
+			BlueprintManager::Native(id) => T::NativeManager::on_service_init(
+				id,
+				request_id,
+				service_id,
+				owner,
+				permitted_callers,
+				assets,
+				ttl,
+			)?,
 			_ => (true, Weight::zero()),
 		};
 		Ok((allowed, weight))
@@ -540,15 +986,130 @@ impl<T: Config> Pallet<T> {
 					.encode_input(&[service_id, owner])
 					.map_err(|_| Error::<T>::EVMAbiEncode)?;
 				let value = U256::zero();
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 				let info = Self::evm_call(Self::address(), contract, value, data, gas_limit)?;
 				(info.exit_reason.is_succeed(), Self::weight_from_call_info(&info))
 			},
+			BlueprintManager::Native(id) => {
+				T::NativeManager::on_service_termination(id, service_id, owner)?
+			},
 			_ => (true, Weight::zero()),
 		};
 		Ok((allowed, weight))
 	}
 
+	/// Validates the caller policies of a service request, and sorts them by job index.
+	/// When a job is given several policies, the last one is kept.
+	pub(crate) fn job_caller_policies(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		policies: Vec<(u8, JobCallerPolicy<T::Constraints, T::AccountId>)>,
+	) -> Result<
+		BoundedVec<(u8, JobCallerPolicy<T::Constraints, T::AccountId>), MaxJobsPerServiceOf<T>>,
+		Error<T>,
+	> {
+		let policies = policies.into_iter().collect::<BTreeMap<_, _>>();
+		ensure!(
+			policies.keys().all(|job| usize::from(*job) < blueprint.jobs.len()),
+			Error::<T>::JobDefinitionNotFound
+		);
+		BoundedVec::try_from(policies.into_iter().collect::<Vec<_>>())
+			.map_err(|_| Error::<T>::JobDefinitionNotFound)
+	}
+
+	/// Hook to be called to check whether an account may call a job whose caller policy is
+	/// [JobCallerPolicy::Manager].
+	///
+	/// This function performs an EVM call to the `canCall` function of the service blueprint's
+	/// manager contract, and the call is allowed if it succeeds.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `service_id` - The service ID.
+	/// * `job` - The job index.
+	/// * `caller` - The account calling the job.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///   whether the caller is allowed to call the job and the weight of the operation.
+	pub fn can_call_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service_id: u64,
+		job: u8,
+		caller: &T::AccountId,
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("canCall"),
+					inputs: vec![
+						ethabi::Param {
+							name: String::from("serviceId"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("job"),
+							kind: ethabi::ParamType::Uint(8),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("caller"),
+							kind: ethabi::ParamType::Address,
+							internal_type: None,
+						},
+					],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::View,
+				};
+
+				let caller = T::EvmAddressMapping::into_address(caller.clone());
+				let data = call
+					.encode_input(&[
+						Token::Uint(U256::from(service_id)),
+						Token::Uint(U256::from(job)),
+						Token::Address(caller),
+					])
+					.map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit = Self::hook_gas_limit(blueprint);
+
+				let info = Self::evm_call(Self::address(), contract, 0.into(), data, gas_limit)?;
+				Ok((info.exit_reason.is_succeed(), Self::weight_from_call_info(&info)))
+			},
+			BlueprintManager::Native(id) => {
+				Ok(T::NativeManager::can_call(id, service_id, job, caller)?)
+			},
+			_ => Ok((true, Weight::zero())),
+		}
+	}
+
+	/// Checks whether an account may call a job of a service, according to the caller policy of
+	/// the job.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///   whether the caller is allowed to call the job and the weight of the operation.
+	pub fn is_job_caller_allowed(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service: &Service<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>,
+		job: u8,
+		caller: &T::AccountId,
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		let allowed = match service.caller_policy(job) {
+			JobCallerPolicy::PermittedCallers => {
+				&service.owner == caller || service.permitted_callers.contains(caller)
+			},
+			JobCallerPolicy::OwnerOnly => &service.owner == caller,
+			JobCallerPolicy::Accounts(accounts) => accounts.contains(caller),
+			JobCallerPolicy::Anyone => true,
+			JobCallerPolicy::Manager => {
+				return Self::can_call_hook(blueprint, service.id, job, caller)
+			},
+		};
+		Ok((allowed, Weight::zero()))
+	}
+
 	/// Hook to be called upon job call.
 	///
 	/// This function is called when a job call is made. It performs an EVM call
@@ -609,12 +1170,15 @@ impl<T: Config> Pallet<T> {
 				let data = call
 					.encode_input(&[service_id, job, job_call_id, inputs])
 					.map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info =
 					Self::evm_call(Self::address(), contract, U256::from(0), data, gas_limit)?;
 				(info.exit_reason.is_succeed(), Self::weight_from_call_info(&info))
 			},
+			BlueprintManager::Native(id) => {
+				T::NativeManager::on_job_call(id, service_id, job, job_call_id, inputs)?
+			},
 			_ => (true, Weight::zero()),
 		};
 		Ok((allowed, weight))
@@ -692,17 +1256,317 @@ impl<T: Config> Pallet<T> {
 				let data = call
 					.encode_input(&[service_id, job, job_call_id, operator, inputs, outputs])
 					.map_err(|_| Error::<T>::EVMAbiEncode)?;
-				let gas_limit = 300_000;
+				let gas_limit = Self::hook_gas_limit(blueprint);
 
 				let info =
 					Self::evm_call(Self::address(), contract, U256::from(0), data, gas_limit)?;
 				(info.exit_reason.is_succeed(), Self::weight_from_call_info(&info))
 			},
+			BlueprintManager::Native(id) => T::NativeManager::on_job_result(
+				id,
+				service_id,
+				job,
+				job_call_id,
+				prefrences,
+				inputs,
+				outputs,
+			)?,
+			_ => (true, Weight::zero()),
+		};
+		Ok((allowed, weight))
+	}
+
+	/// Hook to be called upon a batch of job calls.
+	///
+	/// When the manager contract supports the `onJobCallBatch` function, as advertised through
+	/// ERC-165, the whole batch is passed to it in a single EVM call. Otherwise, the `onJobCall`
+	/// hook is called for each job call of the batch.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `service_id` - The service ID.
+	/// * `calls` - The ID and the job call of each call of the batch.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///   whether the job calls are allowed and the weight of the operation.
+	pub fn on_job_call_batch_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service_id: u64,
+		calls: &[(u64, JobCall<T::Constraints, T::AccountId>)],
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		let (allowed, weight) = match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("onJobCallBatch"),
+					inputs: vec![
+						ethabi::Param {
+							name: String::from("serviceId"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("jobs"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(8))),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("jobCallIds"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(64))),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("inputs"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+							internal_type: None,
+						},
+					],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::Payable,
+				};
+				let (supported, probe_weight) =
+					Self::supports_interface(contract, call.short_signature());
+				if !supported {
+					let mut weight = probe_weight;
+					for (call_id, job_call) in calls {
+						let (allowed, hook_weight) = Self::on_job_call_hook(
+							blueprint,
+							service_id,
+							job_call.job,
+							*call_id,
+							&job_call.args,
+						)?;
+						weight.saturating_accrue(hook_weight);
+						if !allowed {
+							return Ok((false, weight));
+						}
+					}
+					return Ok((true, weight));
+				}
+
+				let service_id = Token::Uint(ethabi::Uint::from(service_id));
+				let jobs = Token::Array(
+					calls.iter().map(|(_, c)| Token::Uint(ethabi::Uint::from(c.job))).collect(),
+				);
+				let job_call_ids = Token::Array(
+					calls.iter().map(|(id, _)| Token::Uint(ethabi::Uint::from(*id))).collect(),
+				);
+				let inputs = Token::Array(
+					calls
+						.iter()
+						.map(|(_, c)| Token::Bytes(Field::encode_to_ethabi(&c.args)))
+						.collect(),
+				);
+				let data = call
+					.encode_input(&[service_id, jobs, job_call_ids, inputs])
+					.map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit = Self::hook_gas_limit(blueprint).saturating_mul(calls.len() as u64);
+
+				let info =
+					Self::evm_call(Self::address(), contract, U256::from(0), data, gas_limit)?;
+				(
+					info.exit_reason.is_succeed(),
+					Self::weight_from_call_info(&info).saturating_add(probe_weight),
+				)
+			},
+			BlueprintManager::Native(id) => {
+				let calls = calls
+					.iter()
+					.map(|(call_id, job_call)| (job_call.job, *call_id, &job_call.args[..]))
+					.collect::<Vec<_>>();
+				T::NativeManager::on_job_call_batch(id, service_id, &calls)?
+			},
+			_ => (true, Weight::zero()),
+		};
+		Ok((allowed, weight))
+	}
+
+	/// Hook to be called upon a batch of job results submitted by an operator.
+	///
+	/// When the manager contract supports the `onJobResultBatch` function, as advertised through
+	/// ERC-165, the whole batch is passed to it in a single EVM call. Otherwise, the
+	/// `onJobResult` hook is called for each job result of the batch.
+	///
+	/// # Parameters
+	/// * `blueprint` - The service blueprint.
+	/// * `service_id` - The service ID.
+	/// * `prefrences` - The operator preferences.
+	/// * `results` - The call ID, the job call and the outputs of each result of the batch.
+	///
+	/// # Returns
+	/// * `Result<(bool, Weight), DispatchErrorWithPostInfo>` - A tuple containing a boolean indicating
+	///   whether the job results are allowed and the weight of the operation.
+	pub fn on_job_result_batch_hook(
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		service_id: u64,
+		prefrences: &OperatorPreferences,
+		results: &[(
+			u64,
+			JobCall<T::Constraints, T::AccountId>,
+			Vec<Field<T::Constraints, T::AccountId>>,
+		)],
+	) -> Result<(bool, Weight), DispatchErrorWithPostInfo> {
+		let (allowed, weight) = match blueprint.manager {
+			BlueprintManager::Evm(contract) => {
+				#[allow(deprecated)]
+				let call = ethabi::Function {
+					name: String::from("onJobResultBatch"),
+					inputs: vec![
+						ethabi::Param {
+							name: String::from("serviceId"),
+							kind: ethabi::ParamType::Uint(64),
+							internal_type: None,
+						},
+						OperatorPreferences::to_ethabi_param(),
+						ethabi::Param {
+							name: String::from("jobs"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(8))),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("jobCallIds"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(64))),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("inputs"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+							internal_type: None,
+						},
+						ethabi::Param {
+							name: String::from("outputs"),
+							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes)),
+							internal_type: None,
+						},
+					],
+					outputs: Default::default(),
+					constant: None,
+					state_mutability: ethabi::StateMutability::NonPayable,
+				};
+				let (supported, probe_weight) =
+					Self::supports_interface(contract, call.short_signature());
+				if !supported {
+					let mut weight = probe_weight;
+					for (call_id, job_call, outputs) in results {
+						let (allowed, hook_weight) = Self::on_job_result_hook(
+							blueprint,
+							service_id,
+							job_call.job,
+							*call_id,
+							prefrences,
+							&job_call.args,
+							outputs,
+						)?;
+						weight.saturating_accrue(hook_weight);
+						if !allowed {
+							return Ok((false, weight));
+						}
+					}
+					return Ok((true, weight));
+				}
+
+				let service_id = Token::Uint(ethabi::Uint::from(service_id));
+				let operator = prefrences.to_ethabi().first().unwrap().clone();
+				let jobs = Token::Array(
+					results
+						.iter()
+						.map(|(_, c, _)| Token::Uint(ethabi::Uint::from(c.job)))
+						.collect(),
+				);
+				let job_call_ids = Token::Array(
+					results.iter().map(|(id, _, _)| Token::Uint(ethabi::Uint::from(*id))).collect(),
+				);
+				let inputs = Token::Array(
+					results
+						.iter()
+						.map(|(_, c, _)| Token::Bytes(Field::encode_to_ethabi(&c.args)))
+						.collect(),
+				);
+				let outputs = Token::Array(
+					results
+						.iter()
+						.map(|(_, _, outputs)| Token::Bytes(Field::encode_to_ethabi(outputs)))
+						.collect(),
+				);
+				let data = call
+					.encode_input(&[service_id, operator, jobs, job_call_ids, inputs, outputs])
+					.map_err(|_| Error::<T>::EVMAbiEncode)?;
+				let gas_limit =
+					Self::hook_gas_limit(blueprint).saturating_mul(results.len() as u64);
+
+				let info =
+					Self::evm_call(Self::address(), contract, U256::from(0), data, gas_limit)?;
+				(
+					info.exit_reason.is_succeed(),
+					Self::weight_from_call_info(&info).saturating_add(probe_weight),
+				)
+			},
+			BlueprintManager::Native(id) => {
+				let results = results
+					.iter()
+					.map(|(call_id, job_call, outputs)| {
+						(job_call.job, *call_id, &job_call.args[..], &outputs[..])
+					})
+					.collect::<Vec<_>>();
+				T::NativeManager::on_job_result_batch(id, service_id, prefrences, &results)?
+			},
 			_ => (true, Weight::zero()),
 		};
 		Ok((allowed, weight))
 	}
 
+	/// Checks whether a manager contract implements the given interface, through the ERC-165
+	/// `supportsInterface` function.
+	///
+	/// The check is a static call: it runs in a storage transaction that is always rolled back,
+	/// so the contract can not change any state, nor emit any event. Only the gas allowed by
+	/// ERC-165 is given to the call.
+	///
+	/// # Returns
+	/// * `(bool, Weight)` - Whether the interface is supported and the weight of the check.
+	pub fn supports_interface(contract: H160, interface_id: [u8; 4]) -> (bool, Weight) {
+		#[allow(deprecated)]
+		let call = ethabi::Function {
+			name: String::from("supportsInterface"),
+			inputs: vec![ethabi::Param {
+				name: String::from("interfaceId"),
+				kind: ethabi::ParamType::FixedBytes(4),
+				internal_type: None,
+			}],
+			outputs: vec![ethabi::Param {
+				name: String::from("supported"),
+				kind: ethabi::ParamType::Bool,
+				internal_type: None,
+			}],
+			constant: None,
+			state_mutability: ethabi::StateMutability::View,
+		};
+		let Ok(data) = call.encode_input(&[Token::FixedBytes(interface_id.to_vec())]) else {
+			return (false, Weight::zero());
+		};
+		let result = with_transaction_opaque(|| {
+			TransactionOutcome::Rollback(T::EvmRunner::call(
+				Self::address(),
+				contract,
+				data,
+				U256::from(0),
+				Self::SUPPORTS_INTERFACE_GAS_LIMIT,
+				false,
+				false,
+			))
+		});
+		match result {
+			Ok(info) => {
+				let supported = info.exit_reason.is_succeed() &&
+					matches!(call.decode_output(&info.value).as_deref(), Ok([Token::Bool(true)]));
+				(supported, Self::weight_from_call_info(&info))
+			},
+			Err(e) => (false, e.weight),
+		}
+	}
+
 	/// Queries the slashing origin of a service.
 	///
 	/// This function performs an EVM call to the `querySlashingOrigin` function of the
@@ -717,7 +1581,10 @@ impl<T: Config> Pallet<T> {
 	pub fn query_slashing_origin(
 		service: &Service<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>,
 	) -> Result<(Option<T::AccountId>, Weight), DispatchErrorWithPostInfo> {
-		let (_, blueprint) = Self::blueprints(service.blueprint)?;
+		let blueprint = Self::service_blueprint(service)?;
+		if let BlueprintManager::Native(id) = blueprint.manager {
+			return Ok(T::NativeManager::query_slashing_origin(id, service.id)?);
+		}
 		#[allow(deprecated)]
 		let query_call = ethabi::Function {
 			name: String::from("querySlashingOrigin"),
@@ -744,7 +1611,7 @@ impl<T: Config> Pallet<T> {
 			query_call
 				.encode_input(&[service_id_tok])
 				.map_err(|_| Error::<T>::EVMAbiEncode)?,
-			300_000,
+			Self::hook_gas_limit(&blueprint),
 		)?;
 
 		// decode the result and return it
@@ -778,7 +1645,10 @@ impl<T: Config> Pallet<T> {
 	pub fn query_dispute_origin(
 		service: &Service<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>,
 	) -> Result<(Option<T::AccountId>, Weight), DispatchErrorWithPostInfo> {
-		let (_, blueprint) = Self::blueprints(service.blueprint)?;
+		let blueprint = Self::service_blueprint(service)?;
+		if let BlueprintManager::Native(id) = blueprint.manager {
+			return Ok(T::NativeManager::query_dispute_origin(id, service.id)?);
+		}
 		#[allow(deprecated)]
 		let query_call = ethabi::Function {
 			name: String::from("queryDisputeOrigin"),
@@ -805,7 +1675,7 @@ impl<T: Config> Pallet<T> {
 			query_call
 				.encode_input(&[service_id_tok])
 				.map_err(|_| Error::<T>::EVMAbiEncode)?,
-			300_000,
+			Self::hook_gas_limit(&blueprint),
 		)?;
 
 		// decode the result and return it
@@ -0,0 +1,49 @@
+use super::*;
+use sp_core::{ecdsa, ed25519, sr25519};
+use tangle_primitives::services::{Field, GroupKey, JobCall, SignatureScheme};
+
+impl<T: Config> Pallet<T> {
+	/// Reads a group key from a field of the final result of a job call.
+	pub(crate) fn group_key_from_result(
+		service_id: u64,
+		call_id: u64,
+		field: u8,
+		scheme: SignatureScheme,
+	) -> Result<GroupKey, Error<T>> {
+		let job_result = Self::job_results(service_id, call_id)?;
+		match job_result.result.get(usize::from(field)) {
+			Some(Field::Bytes(bytes)) => scheme.group_key(bytes),
+			_ => None,
+		}
+		.ok_or(Error::<T>::InvalidGroupKey)
+	}
+
+	/// Verifies that a job result carries a signature under the group key of the service, over
+	/// the signing hash of the job call `call_id`.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the verification.
+	pub(crate) fn verify_group_signature(
+		call_id: u64,
+		job_call: &JobCall<T::Constraints, T::AccountId>,
+		signature: u8,
+		result: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<Weight, Error<T>> {
+		let key = Self::service_group_key(job_call.service_id)?;
+		let Some(Field::Bytes(signature)) = result.get(usize::from(signature)) else {
+			return Err(Error::<T>::InvalidJobResultSignature);
+		};
+		let hash = job_call.signing_hash(call_id);
+
+		let valid = match key {
+			GroupKey::EcdsaSecp256k1(public) => ecdsa::Signature::try_from(&signature[..])
+				.map_or(false, |s| sp_io::crypto::ecdsa_verify_prehashed(&s, &hash, &public)),
+			GroupKey::SchnorrSr25519(public) => sr25519::Signature::try_from(&signature[..])
+				.map_or(false, |s| sp_io::crypto::sr25519_verify(&s, &hash, &public)),
+			GroupKey::SchnorrEd25519(public) => ed25519::Signature::try_from(&signature[..])
+				.map_or(false, |s| sp_io::crypto::ed25519_verify(&s, &hash, &public)),
+		};
+		ensure!(valid, Error::<T>::InvalidJobResultSignature);
+		Ok(T::WeightInfo::verify_result_signature())
+	}
+}
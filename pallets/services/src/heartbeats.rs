@@ -0,0 +1,334 @@
+use super::*;
+use frame_system::offchain::SubmitTransaction;
+use sp_runtime::{
+	offchain::storage::{StorageRetrievalError, StorageValueRef},
+	traits::{Saturating, UniqueSaturatedInto, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
+};
+use tangle_crypto_primitives::ROLE_KEY_TYPE;
+use tangle_primitives::services::{OperatorHeartbeat, OperatorPreferences, Service};
+
+type OperatorHeartbeatOf<T> =
+	OperatorHeartbeat<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+impl<T: Config> Pallet<T> {
+	/// The maximum number of heartbeats an operator can send within a heartbeat interval.
+	pub const MAX_HEARTBEATS_PER_INTERVAL: u32 = 4;
+
+	/// The number of blocks between two runs of the offchain worker sending the heartbeats.
+	pub const HEARTBEAT_WORKER_PERIOD: u32 = 3;
+
+	/// The offchain storage key of the last block the heartbeats were sent at.
+	const HEARTBEAT_WORKER_LAST_RUN: &'static [u8] = b"services::heartbeats::last-run";
+
+	/// Ensures that an operator waited long enough since its last heartbeat, so that it sends
+	/// at most [`Self::MAX_HEARTBEATS_PER_INTERVAL`] heartbeats per interval.
+	pub(crate) fn ensure_heartbeat_rate(
+		service_id: u64,
+		operator: &T::AccountId,
+		interval: u32,
+		at: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let min_gap =
+			BlockNumberFor::<T>::from((interval / Self::MAX_HEARTBEATS_PER_INTERVAL).max(1));
+		if let Some(last_seen) = OperatorHeartbeats::<T>::get(service_id, operator) {
+			ensure!(at >= last_seen.saturating_add(min_gap), Error::<T>::HeartbeatTooFrequent);
+		}
+		Ok(())
+	}
+
+	/// Records that an operator of a service was seen alive at `seen_at`, and schedules the
+	/// check of its next heartbeat.
+	///
+	/// Does nothing if the blueprint of the service has no heartbeat interval.
+	pub(crate) fn record_heartbeat(
+		service_id: u64,
+		operator: &T::AccountId,
+		interval: u32,
+		seen_at: BlockNumberFor<T>,
+	) {
+		if interval.is_zero() {
+			return;
+		}
+		let interval = BlockNumberFor::<T>::from(interval);
+		if let Some(last_seen) = OperatorHeartbeats::<T>::get(service_id, operator) {
+			HeartbeatDeadlines::<T>::remove(
+				last_seen.saturating_add(interval),
+				(service_id, operator.clone()),
+			);
+		}
+		OperatorHeartbeats::<T>::insert(service_id, operator, seen_at);
+
+		let deadline = seen_at.saturating_add(interval);
+		HeartbeatDeadlines::<T>::insert(deadline, (service_id, operator.clone()), ());
+		HeartbeatDeadlineCursor::<T>::mutate(|cursor| match cursor {
+			Some(next) if *next <= deadline => {},
+			_ => *cursor = Some(deadline),
+		});
+
+		if UnresponsiveOperators::<T>::take(service_id, operator).is_some() {
+			Self::deposit_event(Event::OperatorResponsive {
+				operator: operator.clone(),
+				service_id,
+			});
+		}
+	}
+
+	/// Checks a heartbeat signed with the operator key, submitted without a signed origin.
+	///
+	/// The heartbeat must be newer than the last one of the operator, and not older than the
+	/// heartbeat interval of the blueprint.
+	pub(crate) fn validate_heartbeat(
+		heartbeat: &OperatorHeartbeatOf<T>,
+		signature: &sp_core::ecdsa::Signature,
+	) -> Result<u32, DispatchError> {
+		let service = Self::services(heartbeat.service_id)?;
+		let blueprint = Self::service_blueprint(&service)?;
+		let interval = blueprint.heartbeat_interval;
+		ensure!(!interval.is_zero(), Error::<T>::HeartbeatsDisabled);
+		let is_operator = service.operators.iter().any(|(v, _)| v == &heartbeat.operator);
+		ensure!(is_operator, DispatchError::BadOrigin);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let fresh = heartbeat.block_number <= now &&
+			now.saturating_sub(heartbeat.block_number) < BlockNumberFor::<T>::from(interval) &&
+			OperatorHeartbeats::<T>::get(heartbeat.service_id, &heartbeat.operator)
+				.map_or(true, |last_seen| heartbeat.block_number > last_seen);
+		ensure!(fresh, Error::<T>::StaleHeartbeat);
+		Self::ensure_heartbeat_rate(
+			heartbeat.service_id,
+			&heartbeat.operator,
+			interval,
+			heartbeat.block_number,
+		)?;
+
+		let preferences = Operators::<T>::get(service.blueprint, &heartbeat.operator)?;
+		let valid = sp_io::crypto::ecdsa_verify_prehashed(
+			signature,
+			&heartbeat.signing_hash(),
+			&preferences.key,
+		);
+		ensure!(valid, Error::<T>::InvalidHeartbeatSignature);
+		Ok(interval)
+	}
+
+	/// Validates an unsigned heartbeat before it gets into the transaction pool.
+	pub(crate) fn validate_unsigned_heartbeat(
+		heartbeat: &OperatorHeartbeatOf<T>,
+		signature: &sp_core::ecdsa::Signature,
+	) -> TransactionValidity {
+		// Heartbeats are cheap, but must be included before the next deadline.
+		const PRIORITY: u64 = 100;
+
+		let interval = Self::validate_heartbeat(heartbeat, signature).map_err(|e| {
+			// a heartbeat sent too early never becomes valid, since its block number is signed.
+			if e == Error::<T>::StaleHeartbeat.into() ||
+				e == Error::<T>::HeartbeatTooFrequent.into()
+			{
+				InvalidTransaction::Stale
+			} else if e == Error::<T>::InvalidHeartbeatSignature.into() {
+				InvalidTransaction::BadProof
+			} else {
+				InvalidTransaction::Call
+			}
+		})?;
+
+		ValidTransaction::with_tag_prefix("ServicesHeartbeat")
+			.priority(PRIORITY)
+			.and_provides((heartbeat.service_id, heartbeat.operator.clone()))
+			.longevity(u64::from(interval))
+			.propagate(true)
+			.build()
+	}
+
+	/// Signs and submits the heartbeats of the operators whose key is in the local keystore,
+	/// for the services they are due for.
+	///
+	/// The heartbeats are sent at most every [`Self::HEARTBEAT_WORKER_PERIOD`] blocks, and only
+	/// the services of the local operators are looked at. An operator is due halfway through
+	/// its heartbeat interval, minus the period of the worker, leaving the rest of the interval
+	/// for the inclusion of the heartbeat. The transaction pool drops the duplicates submitted
+	/// while a heartbeat is pending, since they provide the same tag.
+	pub(crate) fn send_heartbeats(now: BlockNumberFor<T>) {
+		let keys = sp_io::crypto::ecdsa_public_keys(ROLE_KEY_TYPE);
+		if keys.is_empty() {
+			return;
+		}
+
+		// the last run is updated atomically, so that the concurrent workers run only once.
+		let period = BlockNumberFor::<T>::from(Self::HEARTBEAT_WORKER_PERIOD);
+		let last_run = StorageValueRef::persistent(Self::HEARTBEAT_WORKER_LAST_RUN);
+		let run =
+			last_run.mutate(|last: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| {
+				match last {
+					Ok(Some(last)) if now < last.saturating_add(period) => Err(()),
+					_ => Ok(now),
+				}
+			});
+		if run.is_err() {
+			return;
+		}
+
+		for (blueprint_id, operator, preferences) in Operators::<T>::iter() {
+			if !keys.contains(&preferences.key) {
+				continue;
+			}
+			let Ok(profile) = OperatorsProfile::<T>::get(&operator) else {
+				continue;
+			};
+			for service_id in profile.services {
+				let Ok(service) = Self::services(service_id) else {
+					continue;
+				};
+				if service.blueprint != blueprint_id {
+					continue;
+				}
+				Self::send_heartbeat(now, &service, &operator, &preferences);
+			}
+		}
+	}
+
+	/// Signs and submits the heartbeat of a local operator for a service, if it is due.
+	fn send_heartbeat(
+		now: BlockNumberFor<T>,
+		service: &Service<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>,
+		operator: &T::AccountId,
+		preferences: &OperatorPreferences,
+	) {
+		let Ok(blueprint) = Self::service_blueprint(service) else {
+			return;
+		};
+		let interval = blueprint.heartbeat_interval;
+		if interval.is_zero() {
+			return;
+		}
+		// the heartbeat is sent early enough for the next runs of the worker, but not more often
+		// than the heartbeat rate allows.
+		let min_gap = (interval / Self::MAX_HEARTBEATS_PER_INTERVAL).max(1);
+		let lead = (interval / 2).saturating_sub(Self::HEARTBEAT_WORKER_PERIOD).max(min_gap);
+		let due = OperatorHeartbeats::<T>::get(service.id, operator).map_or(true, |last_seen| {
+			now >= last_seen.saturating_add(BlockNumberFor::<T>::from(lead))
+		});
+		if !due {
+			return;
+		}
+
+		let heartbeat = OperatorHeartbeat {
+			service_id: service.id,
+			operator: operator.clone(),
+			block_number: now,
+		};
+		let Some(signature) = sp_io::crypto::ecdsa_sign_prehashed(
+			ROLE_KEY_TYPE,
+			&preferences.key,
+			&heartbeat.signing_hash(),
+		) else {
+			return;
+		};
+		let call = Call::submit_heartbeat { heartbeat, signature };
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::error!(
+				target: "services",
+				"Failed to submit the heartbeat of the service #{}",
+				service.id,
+			);
+		}
+	}
+
+	/// Flags the operators that did not send a heartbeat before their deadline at or before
+	/// `now`, and notifies the blueprint managers.
+	///
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn check_heartbeats(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// Reading the deadline, the last heartbeat, the service, its blueprint and the operator
+		// preferences, and flagging the operator.
+		let per_check_storage = T::DbWeight::get().reads_writes(5, 2);
+		// The hook of the blueprint manager, assuming the worst case gas usage.
+		let per_check = per_check_storage.saturating_add(Self::max_hook_weight(1));
+		let (consumed, cursor) = Self::sweep_expiries(
+			now,
+			remaining_weight,
+			per_check,
+			HeartbeatDeadlineCursor::<T>::get(),
+			|block, limit| HeartbeatDeadlines::<T>::iter_key_prefix(block).take(limit).collect(),
+			|block, (service_id, operator)| {
+				HeartbeatDeadlines::<T>::remove(block, (service_id, operator.clone()));
+				per_check_storage
+					.saturating_add(Self::flag_unresponsive_operator(service_id, &operator, block))
+			},
+		);
+		if let Some(cursor) = cursor {
+			HeartbeatDeadlineCursor::<T>::put(cursor);
+		}
+		consumed
+	}
+
+	/// Flags an operator that missed its heartbeat deadline as unresponsive, and notifies the
+	/// blueprint manager.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the hook.
+	fn flag_unresponsive_operator(
+		service_id: u64,
+		operator: &T::AccountId,
+		deadline: BlockNumberFor<T>,
+	) -> Weight {
+		let Some(last_seen) = OperatorHeartbeats::<T>::get(service_id, operator) else {
+			// the operator left the service, or the service got terminated.
+			return Weight::zero();
+		};
+		let Ok(service) = Self::services(service_id) else {
+			return Weight::zero();
+		};
+		let Ok(blueprint) = Self::service_blueprint(&service) else {
+			return Weight::zero();
+		};
+		let interval = BlockNumberFor::<T>::from(blueprint.heartbeat_interval);
+		if last_seen.saturating_add(interval) != deadline {
+			// a leftover deadline of an operator that left the service and joined it again.
+			return Weight::zero();
+		}
+		UnresponsiveOperators::<T>::insert(service_id, operator, last_seen);
+
+		let hook = Operators::<T>::get(service.blueprint, operator).map_err(Into::into).and_then(
+			|preferences| {
+				Self::on_operator_unresponsive_hook(
+					&blueprint,
+					service_id,
+					&preferences,
+					last_seen.unique_saturated_into(),
+				)
+			},
+		);
+		let weight = match hook {
+			Ok((_, weight)) => weight,
+			Err(e) => {
+				log::error!(
+					target: "services",
+					"Unresponsive operator hook of the service #{} failed: {:?}",
+					service_id,
+					e,
+				);
+				e.post_info.actual_weight.unwrap_or_default()
+			},
+		};
+
+		Self::deposit_event(Event::OperatorUnresponsive {
+			operator: operator.clone(),
+			service_id,
+			blueprint_id: service.blueprint,
+			last_seen,
+		});
+		weight
+	}
+
+	/// Stops tracking the heartbeats of an operator leaving a service.
+	pub(crate) fn clear_operator_heartbeat(service_id: u64, operator: &T::AccountId) {
+		OperatorHeartbeats::<T>::remove(service_id, operator);
+		UnresponsiveOperators::<T>::remove(service_id, operator);
+	}
+}
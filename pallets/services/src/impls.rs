@@ -82,6 +82,54 @@ impl traits::EvmGasWeightMapping for () {
 	}
 }
 
+/// No native blueprint managers, so blueprints can only be managed by EVM contracts.
+impl<T: Config> traits::BlueprintServiceManager<T> for () {
+	fn exists(_manager: u32) -> bool {
+		false
+	}
+}
+
+/// A native blueprint manager, registered under the ID `Id`, that allows every action, and
+/// charges the requester of each service a fee of `Fee`, paid to `Beneficiary`.
+///
+/// The fee is charged on top of the value the request escrows for the operators, and the request
+/// fails if the requester can not pay it.
+pub struct PermissiveWithFee<Id, Fee, Beneficiary>(
+	core::marker::PhantomData<(Id, Fee, Beneficiary)>,
+);
+
+impl<T: Config, Id: Get<u32>, Fee: Get<BalanceOf<T>>, Beneficiary: Get<T::AccountId>>
+	traits::BlueprintServiceManager<T> for PermissiveWithFee<Id, Fee, Beneficiary>
+{
+	fn exists(manager: u32) -> bool {
+		manager == Id::get()
+	}
+
+	fn on_request(
+		_manager: u32,
+		requester: &T::AccountId,
+		_request_id: u64,
+		_operators: &[tangle_primitives::services::OperatorPreferences],
+		_request_args: &[tangle_primitives::services::Field<T::Constraints, T::AccountId>],
+		_permitted_callers: &[T::AccountId],
+		_assets: &[T::AssetId],
+		_ttl: BlockNumberFor<T>,
+		_value: BalanceOf<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		let fee = Fee::get();
+		if fee.is_zero() {
+			return Ok((true, Weight::zero()));
+		}
+		T::Currency::transfer(
+			requester,
+			&Beneficiary::get(),
+			fee,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		Ok((true, T::DbWeight::get().reads_writes(2, 2)))
+	}
+}
+
 impl<T: crate::Config> ServiceManager<T::AccountId, BalanceOf<T>> for crate::Pallet<T> {
 	fn get_active_services_count(operator: &T::AccountId) -> usize {
 		OperatorsProfile::<T>::get(operator)
@@ -134,4 +182,12 @@ impl<T: crate::Config, Balance: Default, AssetId>
 	) -> Balance {
 		Default::default()
 	}
+
+	fn slash_operator(
+		_operator: &T::AccountId,
+		_own: Balance,
+		_others: &[(T::AccountId, Self::AssetId, Balance)],
+	) -> Balance {
+		Default::default()
+	}
 }
@@ -0,0 +1,352 @@
+use super::*;
+use crate::types::MaxFieldsOf;
+use frame_support::dispatch::DispatchErrorWithPostInfo;
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
+use tangle_primitives::{
+	services::{
+		Field, Groth16Verifier, JobCall, JobCallResult, JobDefinition, JobResultVerifier,
+		OperatorPreferences, Service, ServiceBlueprint,
+	},
+	verifier::{
+		arkworks::ArkworksVerifierGroth16Bn254, circom::CircomVerifierGroth16Bn254,
+		InstanceVerifier,
+	},
+};
+
+type ServiceOf<T> = Service<
+	<T as Config>::Constraints,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as Config>::AssetId,
+>;
+
+impl<T: Config> Pallet<T> {
+	/// Calls a job of a service, once the caller is known to be allowed to call it.
+	///
+	/// The service and its blueprint are passed in, so that a batch of calls only reads them
+	/// once.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the job call hook.
+	pub(crate) fn do_call(
+		caller: &T::AccountId,
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		job: u8,
+		args: Vec<Field<T::Constraints, T::AccountId>>,
+	) -> Result<Weight, DispatchErrorWithPostInfo> {
+		let job_call = Self::new_job_call(caller, service, blueprint, job, args)?;
+		let call_id = Self::next_job_call_id();
+
+		let (allowed, weight) =
+			Self::on_job_call_hook(blueprint, service.id, job, call_id, &job_call.args)?;
+
+		ensure!(allowed, Error::<T>::InvalidJobCallInput);
+
+		Self::record_job_call(caller, blueprint, call_id, job_call)?;
+		Ok(weight)
+	}
+
+	/// Calls several jobs of a service, once the caller is known to be allowed to call them.
+	///
+	/// The blueprint manager is notified of the whole batch at once, before any of the calls is
+	/// recorded.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the job call hooks.
+	pub(crate) fn do_call_batch(
+		caller: &T::AccountId,
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		calls: Vec<(u8, Vec<Field<T::Constraints, T::AccountId>>)>,
+	) -> Result<Weight, DispatchErrorWithPostInfo> {
+		let first_call_id = Self::next_job_call_id();
+		let job_calls = calls
+			.into_iter()
+			.zip(first_call_id..)
+			.map(|((job, args), call_id)| {
+				Self::new_job_call(caller, service, blueprint, job, args).map(|c| (call_id, c))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let (allowed, weight) = Self::on_job_call_batch_hook(blueprint, service.id, &job_calls)?;
+
+		ensure!(allowed, Error::<T>::InvalidJobCallInput);
+
+		for (call_id, job_call) in job_calls {
+			Self::record_job_call(caller, blueprint, call_id, job_call)?;
+		}
+		Ok(weight)
+	}
+
+	/// Builds a job call of a service, and checks its arguments against the job definition.
+	fn new_job_call(
+		caller: &T::AccountId,
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		job: u8,
+		args: Vec<Field<T::Constraints, T::AccountId>>,
+	) -> Result<JobCall<T::Constraints, T::AccountId>, Error<T>> {
+		let job_def =
+			blueprint.jobs.get(usize::from(job)).ok_or(Error::<T>::JobDefinitionNotFound)?;
+		let args = BoundedVec::<_, MaxFieldsOf<T>>::try_from(args)
+			.map_err(|_| Error::<T>::MaxFieldsExceeded)?;
+		let job_call = JobCall { service_id: service.id, job, args, caller: caller.clone() };
+
+		job_call.type_check(job_def).map_err(|e| Error::<T>::TypeCheck(e.into()))?;
+		Ok(job_call)
+	}
+
+	/// Records a job call the blueprint manager allowed, charging its fee and scheduling its
+	/// deadline.
+	fn record_job_call(
+		caller: &T::AccountId,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		call_id: u64,
+		job_call: JobCall<T::Constraints, T::AccountId>,
+	) -> DispatchResult {
+		let service_id = job_call.service_id;
+		let job = job_call.job;
+		let job_def =
+			blueprint.jobs.get(usize::from(job)).ok_or(Error::<T>::JobDefinitionNotFound)?;
+		if let Some(window) = job_def.response_window {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::schedule_job_call_deadline(
+				service_id,
+				call_id,
+				now.saturating_add(window.into()),
+			);
+		}
+		if let Some(fee) = &job_def.fee {
+			Self::charge_job_call_fee(caller, service_id, call_id, fee, &job_call.args)?;
+		}
+		let args = job_call.args.to_vec();
+		JobCalls::<T>::insert(service_id, call_id, job_call);
+		NextJobCallId::<T>::set(call_id.saturating_add(1));
+		Self::deposit_event(Event::JobCalled {
+			caller: caller.clone(),
+			service_id,
+			call_id,
+			job,
+			args,
+		});
+		Ok(())
+	}
+
+	/// Submits the result of a job call, once the caller is known to be an operator of the
+	/// service.
+	///
+	/// The service, its blueprint and the preferences of the operator are passed in, so that a
+	/// batch of results only reads them once.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the verification of the result and the job result
+	///   hook.
+	pub(crate) fn do_submit_result(
+		operator: &T::AccountId,
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		preferences: &OperatorPreferences,
+		call_id: u64,
+		result: Vec<Field<T::Constraints, T::AccountId>>,
+	) -> Result<Weight, DispatchErrorWithPostInfo> {
+		let (job_call, verification_weight) =
+			Self::check_job_result(service, blueprint, call_id, &result)?;
+
+		let (allowed, weight) = Self::on_job_result_hook(
+			blueprint,
+			service.id,
+			job_call.job,
+			call_id,
+			preferences,
+			&job_call.args,
+			&result,
+		)?;
+
+		ensure!(allowed, Error::<T>::InvalidJobResult);
+
+		Self::record_job_result(operator, service, blueprint, call_id, &job_call, result)?;
+		Ok(weight.saturating_add(verification_weight))
+	}
+
+	/// Submits the results of several job calls of a service, once the caller is known to be an
+	/// operator of the service.
+	///
+	/// The blueprint manager is notified of the whole batch at once, before any of the results
+	/// is recorded.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the verification of the results and the job result
+	///   hooks.
+	pub(crate) fn do_submit_results_batch(
+		operator: &T::AccountId,
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		preferences: &OperatorPreferences,
+		results: Vec<(u64, Vec<Field<T::Constraints, T::AccountId>>)>,
+	) -> Result<Weight, DispatchErrorWithPostInfo> {
+		// the results are only recorded after the hook, so a call can not appear twice.
+		let mut call_ids = results.iter().map(|(call_id, _)| *call_id).collect::<Vec<_>>();
+		call_ids.sort();
+		call_ids.dedup();
+		ensure!(call_ids.len() == results.len(), Error::<T>::JobResultAlreadySubmitted);
+
+		let mut verification_weight = Weight::zero();
+		let mut checked = Vec::with_capacity(results.len());
+		for (call_id, result) in results {
+			let (job_call, weight) = Self::check_job_result(service, blueprint, call_id, &result)?;
+			verification_weight.saturating_accrue(weight);
+			checked.push((call_id, job_call, result));
+		}
+
+		let (allowed, weight) =
+			Self::on_job_result_batch_hook(blueprint, service.id, preferences, &checked)?;
+
+		ensure!(allowed, Error::<T>::InvalidJobResult);
+
+		for (call_id, job_call, result) in checked {
+			Self::record_job_result(operator, service, blueprint, call_id, &job_call, result)?;
+		}
+		Ok(weight.saturating_add(verification_weight))
+	}
+
+	/// Checks that a job call still accepts results, and checks and verifies the given result
+	/// against the job definition.
+	///
+	/// # Returns
+	/// * `(JobCall, Weight)` - The job call and the weight consumed by the verification.
+	fn check_job_result(
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		call_id: u64,
+		result: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<(JobCall<T::Constraints, T::AccountId>, Weight), DispatchErrorWithPostInfo> {
+		let service_id = service.id;
+		let job_call = Self::job_calls(service_id, call_id)?;
+		ensure!(
+			!JobResults::<T>::contains_key(service_id, call_id),
+			Error::<T>::JobResultAlreadyFinalized
+		);
+		ensure!(
+			!TimedOutJobCalls::<T>::contains_key(service_id, call_id),
+			Error::<T>::JobCallTimedOut
+		);
+		// the call might not have been swept yet once its response window ended.
+		if let Some(deadline) = JobCallResponseDeadlines::<T>::get(service_id, call_id) {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < deadline, Error::<T>::JobCallTimedOut);
+		}
+
+		let job_def = blueprint
+			.jobs
+			.get(usize::from(job_call.job))
+			.ok_or(Error::<T>::JobDefinitionNotFound)?;
+
+		let bounded_result = BoundedVec::<_, MaxFieldsOf<T>>::try_from(result.to_vec())
+			.map_err(|_| Error::<T>::MaxFieldsExceeded)?;
+
+		let job_result = JobCallResult { service_id, call_id, result: bounded_result };
+		job_result.type_check(job_def).map_err(|e| Error::<T>::TypeCheck(e.into()))?;
+		let verification_weight =
+			Self::verify_job_result(service.blueprint, call_id, &job_call, job_def, result)?;
+		Ok((job_call, verification_weight))
+	}
+
+	/// Records the result of a job call the blueprint manager allowed, and finalizes the job
+	/// call once its result policy is met.
+	fn record_job_result(
+		operator: &T::AccountId,
+		service: &ServiceOf<T>,
+		blueprint: &ServiceBlueprint<T::Constraints>,
+		call_id: u64,
+		job_call: &JobCall<T::Constraints, T::AccountId>,
+		result: Vec<Field<T::Constraints, T::AccountId>>,
+	) -> DispatchResult {
+		let service_id = service.id;
+		let job_def = blueprint
+			.jobs
+			.get(usize::from(job_call.job))
+			.ok_or(Error::<T>::JobDefinitionNotFound)?;
+		let bounded_result = BoundedVec::<_, MaxFieldsOf<T>>::try_from(result.clone())
+			.map_err(|_| Error::<T>::MaxFieldsExceeded)?;
+
+		let submissions = JobResultSubmissions::<T>::try_mutate(service_id, call_id, |s| {
+			ensure!(!s.iter().any(|(v, _)| v == operator), Error::<T>::JobResultAlreadySubmitted);
+			s.try_push((operator.clone(), bounded_result))
+				.map_err(|_| Error::<T>::MaxServiceProvidersExceeded)?;
+			Result::<_, Error<T>>::Ok(s.clone())
+		})?;
+		Self::deposit_event(Event::JobResultSubmitted {
+			operator: operator.clone(),
+			service_id,
+			call_id,
+			job: job_call.job,
+			result,
+		});
+
+		Self::finalize_job_result(service.operators.len(), job_def, job_call, call_id, submissions);
+		Ok(())
+	}
+
+	/// Verifies a job result natively, when the job declares a zero-knowledge or a group
+	/// signature verifier.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the verification.
+	pub(crate) fn verify_job_result(
+		blueprint_id: u64,
+		call_id: u64,
+		job_call: &JobCall<T::Constraints, T::AccountId>,
+		job_def: &JobDefinition<T::Constraints>,
+		result: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<Weight, Error<T>> {
+		match job_def.verifier {
+			JobResultVerifier::Groth16 { verifier, public_inputs, proof } => {
+				Self::verify_job_result_proof(
+					blueprint_id,
+					job_call.job,
+					verifier,
+					public_inputs,
+					proof,
+					result,
+				)
+			},
+			JobResultVerifier::GroupSignature { signature } => {
+				Self::verify_group_signature(call_id, job_call, signature, result)
+			},
+			_ => Ok(Weight::zero()),
+		}
+	}
+
+	/// Verifies the Groth16 proof carried by a job result.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the verification.
+	fn verify_job_result_proof(
+		blueprint_id: u64,
+		job: u8,
+		verifier: Groth16Verifier,
+		public_inputs: u8,
+		proof: u8,
+		result: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<Weight, Error<T>> {
+		let key = Self::verifying_keys(blueprint_id, job)?;
+		let (Some(Field::Bytes(public_inputs)), Some(Field::Bytes(proof))) =
+			(result.get(usize::from(public_inputs)), result.get(usize::from(proof)))
+		else {
+			return Err(Error::<T>::InvalidJobResultProof);
+		};
+
+		let valid = match verifier {
+			Groth16Verifier::Arkworks => {
+				ArkworksVerifierGroth16Bn254::verify(public_inputs, proof, &key)
+			},
+			Groth16Verifier::Circom => {
+				CircomVerifierGroth16Bn254::verify(public_inputs, proof, &key)
+			},
+		};
+		ensure!(matches!(valid, Ok(true)), Error::<T>::InvalidJobResultProof);
+		Ok(T::WeightInfo::verify_result_proof(key.len() as u32))
+	}
+}
@@ -23,14 +23,25 @@ extern crate alloc;
 
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ReservableCurrency},
+	traits::{fungibles, Currency, ExistenceRequirement, ReservableCurrency},
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::{traits::Get, DispatchResult};
 
+mod escrow;
+mod expiry;
+mod fees;
 mod functions;
+mod group_keys;
+mod heartbeats;
 mod impls;
+mod jobs;
+pub mod migrations;
+mod operators;
+mod results;
+mod retention;
 mod rpc;
+mod slashing;
 pub mod traits;
 pub mod types;
 
@@ -46,6 +57,7 @@ mod benchmarking;
 
 pub mod weights;
 
+pub use impls::PermissiveWithFee;
 pub use module::*;
 pub use traits::*;
 pub use weights::WeightInfo;
@@ -59,23 +71,30 @@ pub mod module {
 	use super::*;
 	use frame_support::dispatch::PostDispatchInfo;
 	use sp_core::{H160, H256};
-	use sp_runtime::traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize};
+	use sp_runtime::traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize, Saturating, Zero};
 	use sp_runtime::Percent;
 	use sp_std::vec::Vec;
 	use tangle_primitives::{
 		services::{PriceTargets, *},
+		verifier::arkworks::ArkworksVerifierGroth16Bn254,
 		MultiAssetDelegationInfo,
 	};
 	use types::*;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config:
+		frame_system::Config + frame_system::offchain::SendTransactionTypes<Call<Self>>
+	{
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The origin which may set filter.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// The currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The fungibles trait used to pay job fees in assets other than the native currency.
+		type Fungibles: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+			+ fungibles::Mutate<Self::AccountId, AssetId = Self::AssetId>;
+
 		/// `Pallet` EVM Address.
 		#[pallet::constant]
 		type PalletEVMAddress: Get<H160>;
@@ -91,6 +110,12 @@ pub mod module {
 		/// A type that implements the `EvmAddressMapping` trait for the conversion of EVM address
 		type EvmAddressMapping: traits::EvmAddressMapping<Self::AccountId>;
 
+		/// The blueprint managers implemented natively by the runtime, used by the blueprints
+		/// whose manager is `BlueprintManager::Native`.
+		///
+		/// Several native managers can be composed as a tuple, each with its own ID.
+		type NativeManager: traits::BlueprintServiceManager<Self>;
+
 		/// The asset ID type.
 		type AssetId: AtLeast32BitUnsigned
 			+ Parameter
@@ -176,6 +201,7 @@ pub mod module {
 		///
 		/// This should be less than the bonding duration. Set to 0 if slashes
 		/// should be applied immediately, without opportunity for intervention.
+		/// Once deferred, the slashes are applied in the `on_idle` hook.
 		#[pallet::constant]
 		type SlashDeferDuration: Get<u32> + Default + Parameter + MaybeSerializeDeserialize;
 
@@ -184,6 +210,42 @@ pub mod module {
 		/// Supported actions:
 		/// 1. cancel deferred slash.
 		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of blocks a service request can stay pending before it expires.
+		///
+		/// Expired requests are removed and their escrowed value is refunded to the owner.
+		#[pallet::constant]
+		type ServiceRequestTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of job calls, or job results, in a single batch.
+		#[pallet::constant]
+		type MaxJobBatchSize: Get<u32>;
+
+		/// Number of blocks the data of a job call is kept once it got a final result or timed
+		/// out, before it gets pruned.
+		#[pallet::constant]
+		type JobRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum gas limit a blueprint can declare for each call to the hooks of its manager.
+		///
+		/// The extrinsics calling the hooks are charged for this limit up front, and refunded the
+		/// gas left unused.
+		#[pallet::constant]
+		type MaxHookGasLimit: Get<u64>;
+
+		/// Maximum gas the hooks of the blueprint manager can use for a single batch of job
+		/// calls, or job results.
+		///
+		/// The budget also covers the ERC-165 check of the batched hooks. The batch extrinsics are
+		/// charged for this budget up front, rather than for `MaxHookGasLimit` per item, and the
+		/// batches whose hooks could use more are rejected.
+		#[pallet::constant]
+		type MaxBatchHookGas: Get<u64>;
+
+		/// Maximum length of the zero-knowledge verifying key of a job.
+		#[pallet::constant]
+		type MaxVerifyingKeyLength: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -195,6 +257,57 @@ pub mod module {
 			// 1. Make sure that pallet's associated AccountId value maps correctly to the EVM address.
 			let account_id = T::EvmAddressMapping::into_account_id(Self::address());
 			assert_eq!(account_id, Self::account_id(), "Services: AccountId mapping is incorrect.");
+			// 2. Make sure that the batches of `MaxJobBatchSize` items fit in an extrinsic.
+			let block_weights = T::BlockWeights::get();
+			let max_extrinsic = block_weights
+				.get(DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or(block_weights.max_block);
+			let batch_size = T::MaxJobBatchSize::get();
+			assert!(
+				Self::call_batch_weight(batch_size).all_lte(max_extrinsic),
+				"Services: a call batch of `MaxJobBatchSize` calls does not fit in an extrinsic.",
+			);
+			assert!(
+				Self::submit_results_batch_weight(batch_size).all_lte(max_extrinsic),
+				"Services: a result batch of `MaxJobBatchSize` results does not fit in an extrinsic.",
+			);
+		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed = Self::apply_deferred_slashes(remaining_weight);
+			consumed.saturating_accrue(Self::expire_service_requests(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_accrue(Self::expire_services(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_accrue(Self::expire_job_calls(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_accrue(Self::process_operator_exits(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_accrue(Self::prune_job_calls(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_accrue(Self::clean_up_terminated_services(
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_accrue(Self::check_heartbeats(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed
+		}
+
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			Self::send_heartbeats(now);
 		}
 	}
 
@@ -231,7 +344,10 @@ pub mod module {
 		/// The termination of the service was interrupted.
 		TerminationInterrupted,
 		/// An error occurred while type checking the provided input input.
-		TypeCheck(TypeCheckError),
+		///
+		/// The full error, with the path to the mismatching value, is returned by the type check
+		/// runtime API.
+		TypeCheck(TypeCheckErrorSummary),
 		/// The maximum number of permitted callers per service has been exceeded.
 		MaxPermittedCallersExceeded,
 		/// The maximum number of operators per service has been exceeded.
@@ -250,6 +366,72 @@ pub mod module {
 		ServiceOrJobCallNotFound,
 		/// The result of the job call was not found.
 		JobCallResultNotFound,
+		/// The operator already submitted a result for the job call.
+		JobResultAlreadySubmitted,
+		/// The result of the job call is already final.
+		JobResultAlreadyFinalized,
+		/// The response window of the job call has passed.
+		JobCallTimedOut,
+		/// The asset of the job fee is not a valid asset ID.
+		InvalidJobFeeAsset,
+		/// A job with a fee has no response window.
+		JobFeeWithoutResponseWindow,
+		/// The operator is already leaving the service.
+		AlreadyLeavingService,
+		/// The last operator of a service can not leave it.
+		LastServiceOperator,
+		/// The operator still provides services using the blueprint.
+		OperatorStillServing,
+		/// The operator change request could not be found.
+		OperatorChangeNotFound,
+		/// The service already has a pending operator change.
+		OperatorChangeAlreadyProposed,
+		/// The operator change is invalid, as an operator joining the service already serves it,
+		/// or one leaving it does not.
+		InvalidOperatorChange,
+		/// The account is already a permitted caller of the service.
+		AlreadyPermittedCaller,
+		/// The account is not a permitted caller of the service.
+		NotPermittedCaller,
+		/// The batch has more items than allowed by `MaxJobBatchSize`.
+		JobBatchTooLarge,
+		/// The hook gas limit of the blueprint is higher than allowed by `MaxHookGasLimit`.
+		HookGasLimitExceeded,
+		/// The hooks of the batch could use more gas than allowed by `MaxBatchHookGas`.
+		BatchHookGasExceeded,
+		/// The blueprint manager is a native manager that the runtime does not provide.
+		NativeManagerNotFound,
+		/// The blueprint of the service does not define a heartbeat interval.
+		HeartbeatsDisabled,
+		/// The heartbeat is older than the last one of the operator, or than the heartbeat
+		/// interval.
+		StaleHeartbeat,
+		/// The heartbeat is not signed with the key of the operator.
+		InvalidHeartbeatSignature,
+		/// The operator sent its last heartbeat too recently.
+		HeartbeatTooFrequent,
+		/// The result verifier of a job refers to result fields that are missing, or are not
+		/// bytes.
+		InvalidResultVerifier,
+		/// A field type of the blueprint can not describe any field, e.g. fixed bytes longer
+		/// than 32 bytes.
+		InvalidFieldType,
+		/// The verifying key is longer than allowed by `MaxVerifyingKeyLength`.
+		MaxVerifyingKeyLengthExceeded,
+		/// The verifying key is not a compressed Groth16 verifying key over BN254.
+		InvalidVerifyingKey,
+		/// The blueprint did not store a verifying key for the job.
+		VerifyingKeyNotFound,
+		/// The zero-knowledge proof of the job result is malformed or invalid.
+		InvalidJobResultProof,
+		/// The service did not register a group key.
+		GroupKeyNotFound,
+		/// The service already registered its group key.
+		GroupKeyAlreadyRegistered,
+		/// The result field does not hold a public key of the signature scheme.
+		InvalidGroupKey,
+		/// The signature of the job result is malformed or invalid.
+		InvalidJobResultSignature,
 		/// An error occurred while encoding the EVM ABI.
 		EVMAbiEncode,
 		/// An error occurred while decoding the EVM ABI.
@@ -274,6 +456,17 @@ pub mod module {
 		NoDisputeOrigin,
 		/// The Unapplied Slash are not found.
 		UnappliedSlashNotFound,
+		/// The revision of the service blueprint was not found.
+		BlueprintRevisionNotFound,
+		/// The operator did not opt in to the latest revision of the service blueprint.
+		OperatorNotOnLatestRevision,
+		/// The service blueprint is deprecated.
+		BlueprintDeprecated,
+		/// The operator did not opt in to the revision of the service blueprint the service is
+		/// pinned to.
+		OperatorNotOnServiceRevision,
+		/// The number of operators changed by the operator change is higher than its witness.
+		InvalidOperatorChangeWitness,
 	}
 
 	#[pallet::event]
@@ -286,6 +479,47 @@ pub mod module {
 			/// The ID of the service blueprint.
 			blueprint_id: u64,
 		},
+		/// A new revision of a service blueprint has been published.
+		BlueprintUpdated {
+			/// The owner of the service blueprint.
+			owner: T::AccountId,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The new revision of the service blueprint.
+			revision: u32,
+		},
+		/// The ownership of a service blueprint has been transferred.
+		BlueprintOwnershipTransferred {
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The previous owner of the service blueprint.
+			from: T::AccountId,
+			/// The new owner of the service blueprint.
+			to: T::AccountId,
+		},
+		/// A service blueprint has been deprecated by its owner.
+		BlueprintDeprecated {
+			/// The owner of the service blueprint.
+			owner: T::AccountId,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+		},
+		/// An operator has opted in to a revision of a service blueprint.
+		OperatorRevisionUpdated {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The revision the operator is now running.
+			revision: u32,
+		},
+		/// The owner of a service blueprint has stored the verifying key of a job.
+		VerifyingKeySet {
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The index of the job.
+			job: u8,
+		},
 		/// An operator has pre-registered for a service blueprint.
 		PreRegistration {
 			/// The account that pre-registered as an operator.
@@ -304,12 +538,14 @@ pub mod module {
 			/// The arguments used for registration.
 			registration_args: Vec<Field<T::Constraints, T::AccountId>>,
 		},
-		/// An operator has been unregistered.
+		/// An operator has been unregistered and its registration value got refunded.
 		Unregistered {
 			/// The account that unregistered as am operator.
 			operator: T::AccountId,
 			/// The ID of the service blueprint.
 			blueprint_id: u64,
+			/// The registration value refunded to the operator.
+			refunded: BalanceOf<T>,
 		},
 
 		/// The price targets for an operator has been updated.
@@ -358,6 +594,147 @@ pub mod module {
 			request_id: u64,
 			/// The ID of the service blueprint.
 			blueprint_id: u64,
+			/// The value refunded to the owner of the service request.
+			refunded: BalanceOf<T>,
+		},
+		/// A pending service request has been cancelled by its owner and its value got refunded.
+		ServiceRequestCancelled {
+			/// The owner of the service request.
+			owner: T::AccountId,
+			/// The ID of the service request.
+			request_id: u64,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The value refunded to the owner of the service request.
+			refunded: BalanceOf<T>,
+		},
+		/// An operator has announced leaving a service, and keeps serving it until its notice
+		/// period is over.
+		OperatorLeaving {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The block at which the operator leaves the service.
+			exit_block: BlockNumberFor<T>,
+		},
+		/// An operator has left a service.
+		OperatorLeftService {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+		},
+		/// A change of the operators of a service has been proposed.
+		OperatorChangeProposed {
+			/// The account that proposed the change.
+			proposer: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The operators joining the service.
+			joining: Vec<T::AccountId>,
+			/// The operators leaving the service.
+			leaving: Vec<T::AccountId>,
+		},
+		/// An operator joining a service has approved the operator change.
+		OperatorChangeApproved {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The operators yet to approve the change.
+			pending_approvals: Vec<T::AccountId>,
+		},
+		/// An operator joining a service has rejected the operator change, which got dropped.
+		OperatorChangeRejected {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+		},
+		/// An operator has joined a running service.
+		OperatorJoinedService {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The restaking percent of the operator.
+			restaking_percent: Percent,
+		},
+		/// The caller policy of a job of a service has been updated.
+		JobCallerPolicyUpdated {
+			/// The ID of the service.
+			service_id: u64,
+			/// The index of the job.
+			job: u8,
+			/// The new caller policy of the job.
+			policy: JobCallerPolicy<T::Constraints, T::AccountId>,
+		},
+		/// An account has been added to the permitted callers of a service.
+		PermittedCallerAdded {
+			/// The ID of the service.
+			service_id: u64,
+			/// The permitted caller.
+			caller: T::AccountId,
+		},
+		/// An account has been removed from the permitted callers of a service.
+		PermittedCallerRemoved {
+			/// The ID of the service.
+			service_id: u64,
+			/// The removed caller.
+			caller: T::AccountId,
+		},
+		/// A consumer of a job result has acknowledged it, and the data of the job call got
+		/// pruned.
+		JobResultAcknowledged {
+			/// The account that acknowledged the result.
+			caller: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the job call.
+			call_id: u64,
+		},
+		/// An operator did not send a heartbeat for a service within the heartbeat interval.
+		OperatorUnresponsive {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The block number of the last heartbeat of the operator.
+			last_seen: BlockNumberFor<T>,
+		},
+		/// An operator flagged as unresponsive sent a heartbeat again.
+		OperatorResponsive {
+			/// The account of the operator.
+			operator: T::AccountId,
+			/// The ID of the service.
+			service_id: u64,
+		},
+		/// The owner of a service registered the group key of its operators.
+		GroupKeyRegistered {
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the job call whose result holds the key.
+			call_id: u64,
+			/// The group key.
+			key: GroupKey,
+		},
+		/// A pending service request has expired and its value got refunded.
+		ServiceRequestExpired {
+			/// The owner of the service request.
+			owner: T::AccountId,
+			/// The ID of the service request.
+			request_id: u64,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The amount refunded to the owner.
+			refunded: BalanceOf<T>,
 		},
 		/// A service has been initiated.
 		ServiceInitiated {
@@ -381,6 +758,20 @@ pub mod module {
 			service_id: u64,
 			/// The ID of the service blueprint.
 			blueprint_id: u64,
+			/// The reason the service got terminated.
+			reason: ServiceTerminationReason,
+			/// The escrowed value that did not vest and got refunded to the owner.
+			refunded: BalanceOf<T>,
+		},
+
+		/// The vested value of a service got paid out to its operators.
+		ServicePaidOut {
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the service blueprint.
+			blueprint_id: u64,
+			/// The value paid out to each operator.
+			payouts: Vec<(T::AccountId, BalanceOf<T>)>,
 		},
 
 		/// A job has been called.
@@ -411,6 +802,57 @@ pub mod module {
 			result: Vec<Field<T::Constraints, T::AccountId>>,
 		},
 
+		/// The submitted job results met the result policy of the job, and the canonical result
+		/// of the job call is final.
+		JobResultFinalized {
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the call.
+			call_id: u64,
+			/// The index of the job.
+			job: u8,
+			/// The canonical result of the job.
+			result: Vec<Field<T::Constraints, T::AccountId>>,
+		},
+
+		/// The response window of a job call has passed without a final result.
+		JobCallTimedOut {
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the call.
+			call_id: u64,
+			/// The index of the job.
+			job: u8,
+			/// The operators that did not submit a result in time.
+			missed: Vec<T::AccountId>,
+		},
+
+		/// The fee of a job call has been paid out to the operators whose results got accepted.
+		JobFeePaid {
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the call.
+			call_id: u64,
+			/// The asset the fee is paid in, `None` for the native currency.
+			asset: Option<T::AssetId>,
+			/// The amount paid to each operator.
+			payouts: Vec<(T::AccountId, BalanceOf<T>)>,
+		},
+
+		/// The fee of a failed job call has been refunded to its caller.
+		JobFeeRefunded {
+			/// The ID of the service.
+			service_id: u64,
+			/// The ID of the call.
+			call_id: u64,
+			/// The account that called the job.
+			payer: T::AccountId,
+			/// The asset the fee is paid in, `None` for the native currency.
+			asset: Option<T::AssetId>,
+			/// The refunded amount.
+			amount: BalanceOf<T>,
+		},
+
 		/// An EVM log has been emitted during an execution.
 		EvmLog {
 			/// The account that emitted the log
@@ -452,9 +894,26 @@ pub mod module {
 			/// Era index
 			era: u32,
 		},
+		/// An Unapplied Slash got applied after its defer duration.
+		SlashApplied {
+			/// The index of the slash.
+			index: u32,
+			/// The account that got slashed.
+			operator: T::AccountId,
+			/// The amount that got slashed from the operator and its delegators.
+			amount: BalanceOf<T>,
+			/// Service ID
+			service_id: u64,
+			/// Era index
+			era: u32,
+		},
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// Counters
@@ -495,6 +954,53 @@ pub mod module {
 		ResultQuery<Error<T>::BlueprintNotFound>,
 	>;
 
+	/// The lifecycle state of the service blueprints.
+	/// Blueprint ID -> Blueprint State
+	#[pallet::storage]
+	#[pallet::getter(fn blueprint_state)]
+	pub type BlueprintStates<T: Config> = StorageMap<_, Identity, u64, BlueprintState, ValueQuery>;
+
+	/// Every published revision of the service blueprints.
+	/// Blueprint ID -> Revision -> Service Blueprint
+	#[pallet::storage]
+	#[pallet::getter(fn blueprint_revisions)]
+	pub type BlueprintRevisions<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		u32,
+		ServiceBlueprint<T::Constraints>,
+		ResultQuery<Error<T>::BlueprintRevisionNotFound>,
+	>;
+
+	/// The latest revision of the service blueprints, which is the one stored in [`Blueprints`].
+	/// Blueprint ID -> Revision
+	#[pallet::storage]
+	#[pallet::getter(fn latest_blueprint_revision)]
+	pub type LatestBlueprintRevision<T: Config> = StorageMap<_, Identity, u64, u32, ValueQuery>;
+
+	/// The revision of the service blueprint each operator opted in to.
+	/// Blueprint ID -> Operator -> Revision
+	#[pallet::storage]
+	#[pallet::getter(fn operator_blueprint_revision)]
+	pub type OperatorBlueprintRevision<T: Config> =
+		StorageDoubleMap<_, Identity, u64, Identity, T::AccountId, u32, ValueQuery>;
+
+	/// The zero-knowledge verifying keys of the jobs of the service blueprints.
+	/// Blueprint ID -> Job Index -> Verifying Key
+	#[pallet::storage]
+	#[pallet::getter(fn verifying_keys)]
+	pub type VerifyingKeys<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		u8,
+		BoundedVec<u8, T::MaxVerifyingKeyLength>,
+		ResultQuery<Error<T>::VerifyingKeyNotFound>,
+	>;
+
 	/// The operators for a specific service blueprint.
 	/// Blueprint ID -> Operator -> Operator Preferences
 	#[pallet::storage]
@@ -521,6 +1027,37 @@ pub mod module {
 		ResultQuery<Error<T>::ServiceRequestNotFound>,
 	>;
 
+	/// The value escrowed by the owner of a pending service request.
+	/// Request ID -> Value
+	#[pallet::storage]
+	#[pallet::getter(fn request_escrow)]
+	pub type RequestEscrow<T: Config> = StorageMap<_, Identity, u64, BalanceOf<T>, ValueQuery>;
+
+	/// The value escrowed by the operators registered for a service blueprint.
+	/// Blueprint ID -> Operator -> Value
+	#[pallet::storage]
+	#[pallet::getter(fn registration_escrow)]
+	pub type RegistrationEscrow<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The pending service requests indexed by the block at which they expire.
+	/// Expiry Block -> Request ID -> ()
+	#[pallet::storage]
+	pub type ServiceRequestExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, u64, (), OptionQuery>;
+
+	/// The next expiry block to be checked for expired service requests.
+	#[pallet::storage]
+	pub type ServiceRequestExpiryCursor<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	/// The Services Instances
 	/// Service ID -> Service
 	#[pallet::storage]
@@ -533,6 +1070,113 @@ pub mod module {
 		ResultQuery<Error<T>::ServiceNotFound>,
 	>;
 
+	/// The value escrowed for the service instances.
+	/// Service ID -> Escrow
+	#[pallet::storage]
+	#[pallet::getter(fn service_escrow)]
+	pub type ServiceEscrows<T: Config> = StorageMap<
+		_,
+		Identity,
+		u64,
+		ServiceEscrow<BalanceOf<T>>,
+		ResultQuery<Error<T>::ServiceNotFound>,
+	>;
+
+	/// The group keys of the operators of the service instances.
+	/// Service ID -> Group Key
+	#[pallet::storage]
+	#[pallet::getter(fn service_group_key)]
+	pub type ServiceGroupKeys<T: Config> =
+		StorageMap<_, Identity, u64, GroupKey, ResultQuery<Error<T>::GroupKeyNotFound>>;
+
+	/// The service instances indexed by the block at which their lifetime ends.
+	/// End Block -> Service ID -> ()
+	#[pallet::storage]
+	pub type ServiceExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, u64, (), OptionQuery>;
+
+	/// The next end block to be checked for expired service instances.
+	#[pallet::storage]
+	pub type ServiceExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The operators leaving a service, along with the block at which they leave it.
+	/// Service ID -> Operator -> Exit Block
+	#[pallet::storage]
+	#[pallet::getter(fn operator_exits)]
+	pub type OperatorExits<T: Config> =
+		StorageDoubleMap<_, Identity, u64, Identity, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The operators leaving a service indexed by the block at which they leave it.
+	/// Exit Block -> (Service ID, Operator) -> ()
+	#[pallet::storage]
+	pub type OperatorExitSchedule<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Identity,
+		(u64, T::AccountId),
+		(),
+		OptionQuery,
+	>;
+
+	/// The next exit block to be checked for operators leaving a service.
+	#[pallet::storage]
+	pub type OperatorExitCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The job calls whose data gets pruned, indexed by the block at which they get pruned.
+	/// Prune Block -> (Service ID, Call ID) -> ()
+	#[pallet::storage]
+	pub type JobPruningSchedule<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, (u64, u64), (), OptionQuery>;
+
+	/// The next prune block to be checked for job calls to prune.
+	#[pallet::storage]
+	pub type JobPruningCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The block number of the last heartbeat of the operators of a service.
+	/// Service ID -> Operator -> Last Seen Block
+	#[pallet::storage]
+	#[pallet::getter(fn operator_heartbeats)]
+	pub type OperatorHeartbeats<T: Config> =
+		StorageDoubleMap<_, Identity, u64, Identity, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The operators of a service indexed by the block at which their next heartbeat is due.
+	/// Deadline Block -> (Service ID, Operator) -> ()
+	#[pallet::storage]
+	pub type HeartbeatDeadlines<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Identity,
+		(u64, T::AccountId),
+		(),
+		OptionQuery,
+	>;
+
+	/// The next deadline block to be checked for missed heartbeats.
+	#[pallet::storage]
+	pub type HeartbeatDeadlineCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The operators of a service that missed their heartbeat, along with the block number of
+	/// their last heartbeat.
+	/// Service ID -> Operator -> Last Seen Block
+	#[pallet::storage]
+	#[pallet::getter(fn unresponsive_operators)]
+	pub type UnresponsiveOperators<T: Config> =
+		StorageDoubleMap<_, Identity, u64, Identity, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The pending operator change of a service.
+	/// Service ID -> Operator Change Request
+	#[pallet::storage]
+	#[pallet::getter(fn operator_change_requests)]
+	pub type OperatorChangeRequests<T: Config> = StorageMap<
+		_,
+		Identity,
+		u64,
+		OperatorChangeRequest<T::Constraints, T::AccountId>,
+		ResultQuery<Error<T>::OperatorChangeNotFound>,
+	>;
+
 	/// User Service Instances
 	/// User Account ID -> Service ID
 	#[pallet::storage]
@@ -559,7 +1203,26 @@ pub mod module {
 		ResultQuery<Error<T>::ServiceOrJobCallNotFound>,
 	>;
 
-	/// The Service Job Call Results
+	/// The results submitted by the operators of a service for a job call, ordered from the
+	/// earliest to the latest.
+	/// Service ID -> Call ID -> [(Operator, Job Call Result)]
+	#[pallet::storage]
+	#[pallet::getter(fn job_result_submissions)]
+	pub type JobResultSubmissions<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		u64,
+		BoundedVec<
+			(T::AccountId, BoundedVec<Field<T::Constraints, T::AccountId>, MaxFieldsOf<T>>),
+			MaxOperatorsPerServiceOf<T>,
+		>,
+		ValueQuery,
+	>;
+
+	/// The canonical Service Job Call Results, once final according to the result policy of the
+	/// job.
 	/// Service ID -> Call ID -> Job Call Result
 	#[pallet::storage]
 	#[pallet::getter(fn job_results)]
@@ -573,9 +1236,70 @@ pub mod module {
 		ResultQuery<Error<T>::ServiceOrJobCallNotFound>,
 	>;
 
-	/// All unapplied slashes that are queued for later.
-	///
-	/// EraIndex -> Index -> UnappliedSlash
+	/// The job calls indexed by the block at which their response window ends.
+	/// Deadline Block -> (Service ID, Call ID) -> ()
+	#[pallet::storage]
+	pub type JobCallDeadlines<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, (u64, u64), (), OptionQuery>;
+
+	/// The next deadline block to be checked for timed out job calls.
+	#[pallet::storage]
+	pub type JobCallDeadlineCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The block at which the response window of each job call ends.
+	/// Service ID -> Call ID -> Deadline Block
+	#[pallet::storage]
+	pub type JobCallResponseDeadlines<T: Config> =
+		StorageDoubleMap<_, Identity, u64, Identity, u64, BlockNumberFor<T>, OptionQuery>;
+
+	/// The job calls that timed out, along with the operators that did not submit a result in
+	/// time.
+	/// Service ID -> Call ID -> Operators
+	#[pallet::storage]
+	#[pallet::getter(fn timed_out_job_calls)]
+	pub type TimedOutJobCalls<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		u64,
+		BoundedVec<T::AccountId, MaxOperatorsPerServiceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The number of job calls of a service an operator did not respond to in time.
+	/// Service ID -> Operator -> Missed Job Calls
+	#[pallet::storage]
+	#[pallet::getter(fn missed_job_calls)]
+	pub type MissedJobCalls<T: Config> =
+		StorageDoubleMap<_, Identity, u64, Identity, T::AccountId, u32, ValueQuery>;
+
+	/// The fees escrowed by the job calls that have no final result yet.
+	/// Service ID -> Call ID -> Job Call Fee
+	#[pallet::storage]
+	#[pallet::getter(fn job_call_fees)]
+	pub type JobCallFees<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		u64,
+		JobCallFee<T::AccountId, T::AssetId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The terminated services whose job data is still to be removed.
+	/// Service ID -> ()
+	#[pallet::storage]
+	pub type ServiceCleanupQueue<T: Config> = StorageMap<_, Identity, u64, (), OptionQuery>;
+
+	/// The terminated service whose job data is being removed.
+	#[pallet::storage]
+	pub type ServiceCleanupCursor<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// All unapplied slashes that are queued for later.
+	///
+	/// EraIndex -> Index -> UnappliedSlash
 	#[pallet::storage]
 	#[pallet::unbounded]
 	#[pallet::getter(fn unapplied_slashes)]
@@ -585,10 +1309,14 @@ pub mod module {
 		u32,
 		Identity,
 		u32,
-		UnappliedSlash<T::AccountId, BalanceOf<T>>,
+		UnappliedSlash<T::AccountId, BalanceOf<T>, T::AssetId>,
 		ResultQuery<Error<T>::UnappliedSlashNotFound>,
 	>;
 
+	/// The oldest era that may still have unapplied slashes to be applied.
+	#[pallet::storage]
+	pub type UnappliedSlashesCursor<T> = StorageValue<_, u32, ValueQuery>;
+
 	// *** auxiliary storage and maps ***
 	#[pallet::storage]
 	#[pallet::getter(fn operator_profile)]
@@ -616,7 +1344,16 @@ pub mod module {
 			blueprint: ServiceBlueprint<T::Constraints>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
+			ensure!(
+				blueprint.hook_gas_limit <= T::MaxHookGasLimit::get(),
+				Error::<T>::HookGasLimitExceeded
+			);
+			Self::ensure_blueprint_manager_exists(&blueprint)?;
+			Self::ensure_result_verifiers_valid(&blueprint)?;
+			Self::ensure_field_types_valid(&blueprint)?;
+			Self::ensure_job_fees_valid(&blueprint)?;
 			let blueprint_id = Self::next_blueprint_id();
+			BlueprintRevisions::<T>::insert(blueprint_id, 0, &blueprint);
 			Blueprints::<T>::insert(blueprint_id, (owner.clone(), blueprint));
 			NextBlueprintId::<T>::set(blueprint_id.saturating_add(1));
 
@@ -639,6 +1376,7 @@ pub mod module {
 			#[pallet::compact] blueprint_id: u64,
 		) -> DispatchResult {
 			let operator_controller = ensure_signed(origin)?;
+			Self::ensure_blueprint_active(blueprint_id)?;
 
 			// Emit the PreRegistration event
 			Self::deposit_event(Event::PreRegistration {
@@ -653,7 +1391,9 @@ pub mod module {
 		///
 		/// The caller may require an approval first before they can accept to provide the service
 		/// for the users.
-		#[pallet::weight(T::WeightInfo::register())]
+		#[pallet::weight(
+			T::WeightInfo::register().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn register(
 			origin: OriginFor<T>,
 			#[pallet::compact] blueprint_id: u64,
@@ -663,6 +1403,7 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
 			let (_, blueprint) = Self::blueprints(blueprint_id)?;
+			Self::ensure_blueprint_active(blueprint_id)?;
 
 			ensure!(
 				T::OperatorDelegationManager::is_operator_active(&caller),
@@ -673,7 +1414,7 @@ pub mod module {
 			ensure!(!already_registered, Error::<T>::AlreadyRegistered);
 			blueprint
 				.type_check_registration(&registration_args)
-				.map_err(Error::<T>::TypeCheck)?;
+				.map_err(|e| Error::<T>::TypeCheck(e.into()))?;
 
 			// Transfer the registration value to the pallet
 			T::Currency::transfer(
@@ -683,12 +1424,17 @@ pub mod module {
 				ExistenceRequirement::KeepAlive,
 			)?;
 
-			let (allowed, _weight) =
+			let (allowed, hook_weight) =
 				Self::on_register_hook(&blueprint, &preferences, &registration_args, value)?;
 
 			ensure!(allowed, Error::<T>::InvalidRegistrationInput);
 
 			Operators::<T>::insert(blueprint_id, &caller, preferences);
+			if !value.is_zero() {
+				RegistrationEscrow::<T>::insert(blueprint_id, &caller, value);
+			}
+			let revision = Self::latest_blueprint_revision(blueprint_id);
+			OperatorBlueprintRevision::<T>::insert(blueprint_id, &caller, revision);
 
 			OperatorsProfile::<T>::try_mutate(&caller, |profile| {
 				match profile {
@@ -715,17 +1461,21 @@ pub mod module {
 				registration_args,
 			});
 
-			// TODO: update weight for the registration.
-
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::register().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Unregister the caller from being an operator for the service blueprint
 		/// so that, no more services will assigned to the caller for this specific blueprint.
+		/// The value escrowed on registration is refunded to the caller.
 		/// Note that, the caller needs to keep providing service for other active service
 		/// that uses this blueprint, until the end of service time, otherwise they may get reported
 		/// and slashed.
-		#[pallet::weight(T::WeightInfo::unregister())]
+		#[pallet::weight(
+			T::WeightInfo::unregister().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn unregister(
 			origin: OriginFor<T>,
 			#[pallet::compact] blueprint_id: u64,
@@ -733,10 +1483,20 @@ pub mod module {
 			let caller = ensure_signed(origin)?;
 			let (_, blueprint) = Self::blueprints(blueprint_id)?;
 			let preferences = Operators::<T>::get(blueprint_id, &caller)?;
-			let (allowed, _weight) = Self::on_unregister_hook(&blueprint, &preferences)?;
+			// the operator has to leave all the services of the blueprint first.
+			let serving = OperatorsProfile::<T>::get(&caller)
+				.map(|profile| profile.services)
+				.unwrap_or_default()
+				.into_iter()
+				.any(|service_id| {
+					Self::services(service_id)
+						.is_ok_and(|service| service.blueprint == blueprint_id)
+				});
+			ensure!(!serving, Error::<T>::OperatorStillServing);
+			let (allowed, hook_weight) = Self::on_unregister_hook(&blueprint, &preferences)?;
 			ensure!(allowed, Error::<T>::NotAllowedToUnregister);
-			// TODO: check if the caller is not providing any service for the blueprint.
 			Operators::<T>::remove(blueprint_id, &caller);
+			OperatorBlueprintRevision::<T>::remove(blueprint_id, &caller);
 
 			// TODO: also remove all the services that uses this blueprint?
 			let removed = OperatorsProfile::<T>::try_mutate_exists(&caller, |profile| {
@@ -747,15 +1507,24 @@ pub mod module {
 			})?;
 
 			ensure!(removed, Error::<T>::NotRegistered);
-			Self::deposit_event(Event::Unregistered { operator: caller.clone(), blueprint_id });
-			// TODO: update weight for the unregistration.
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			let refunded = Self::refund_registration_escrow(blueprint_id, &caller)?;
+			Self::deposit_event(Event::Unregistered {
+				operator: caller.clone(),
+				blueprint_id,
+				refunded,
+			});
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::unregister().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Update the price targets for the caller for a specific service blueprint.
 		///
 		/// See [`Self::register`] for more information.
-		#[pallet::weight(T::WeightInfo::update_price_targets())]
+		#[pallet::weight(
+			T::WeightInfo::update_price_targets().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn update_price_targets(
 			origin: OriginFor<T>,
 			#[pallet::compact] blueprint_id: u64,
@@ -775,7 +1544,7 @@ pub mod module {
 						.ok_or(Error::<T>::NotRegistered)
 				})?;
 
-			let (allowed, _weight) =
+			let (allowed, hook_weight) =
 				Self::on_update_price_targets(&blueprint, &updated_preferences)?;
 
 			ensure!(allowed, Error::<T>::NotAllowedToUpdatePriceTargets);
@@ -785,13 +1554,23 @@ pub mod module {
 				blueprint_id,
 				price_targets,
 			});
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::update_price_targets().saturating_add(hook_weight),
+				),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Request a new service to be initiated using the provided blueprint with a list of
 		/// operators that will run your service. Optionally, you can specifiy who is permitted
 		/// caller of this service, by default only the caller is allowed to call the service.
-		#[pallet::weight(T::WeightInfo::request())]
+		///
+		/// The `caller_policies` restrict who is allowed to call each job of the service, jobs
+		/// without a policy can be called by the owner and the permitted callers.
+		#[pallet::weight(
+			T::WeightInfo::request().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn request(
 			origin: OriginFor<T>,
 			#[pallet::compact] blueprint_id: u64,
@@ -801,18 +1580,27 @@ pub mod module {
 			assets: Vec<T::AssetId>,
 			#[pallet::compact] ttl: BlockNumberFor<T>,
 			#[pallet::compact] value: BalanceOf<T>,
+			caller_policies: Vec<(u8, JobCallerPolicy<T::Constraints, T::AccountId>)>,
 		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
 			let (_, blueprint) = Self::blueprints(blueprint_id)?;
+			Self::ensure_blueprint_active(blueprint_id)?;
 
-			blueprint.type_check_request(&request_args).map_err(Error::<T>::TypeCheck)?;
+			blueprint
+				.type_check_request(&request_args)
+				.map_err(|e| Error::<T>::TypeCheck(e.into()))?;
 			// ensure we at least have one asset
 			ensure!(!assets.is_empty(), Error::<T>::NoAssetsProvided);
 
+			let revision = Self::latest_blueprint_revision(blueprint_id);
 			let mut preferences = Vec::new();
 			let mut pending_approvals = Vec::new();
 			for provider in &operators {
 				let prefs = Self::operators(blueprint_id, provider)?;
+				ensure!(
+					Self::operator_blueprint_revision(blueprint_id, provider) == revision,
+					Error::<T>::OperatorNotOnLatestRevision
+				);
 				pending_approvals.push(provider.clone());
 				preferences.push(prefs);
 			}
@@ -826,7 +1614,7 @@ pub mod module {
 			)?;
 
 			let service_id = Self::next_instance_id();
-			let (allowed, _weight) = Self::on_request_hook(
+			let (allowed, hook_weight) = Self::on_request_hook(
 				&caller,
 				&blueprint,
 				service_id,
@@ -854,21 +1642,29 @@ pub mod module {
 
 			let args = BoundedVec::<_, MaxFieldsOf<T>>::try_from(request_args)
 				.map_err(|_| Error::<T>::MaxFieldsExceeded)?;
+			let caller_policies = Self::job_caller_policies(&blueprint, caller_policies)?;
 
 			let operators_with_approval_state =
 				BoundedVec::<_, MaxOperatorsPerServiceOf<T>>::try_from(operators)
 					.map_err(|_| Error::<T>::MaxServiceProvidersExceeded)?;
 
+			let now = frame_system::Pallet::<T>::block_number();
+			let expiry = now.saturating_add(T::ServiceRequestTimeout::get());
 			let service_request = ServiceRequest {
 				blueprint: blueprint_id,
+				blueprint_revision: revision,
 				owner: caller.clone(),
 				assets: assets.clone(),
 				ttl,
+				expiry,
 				args,
 				permitted_callers,
 				operators_with_approval_state,
+				caller_policies,
 			};
 			ServiceRequests::<T>::insert(request_id, service_request);
+			RequestEscrow::<T>::insert(request_id, value);
+			Self::schedule_service_request_expiry(request_id, expiry);
 			NextServiceRequestId::<T>::set(request_id.saturating_add(1));
 
 			Self::deposit_event(Event::ServiceRequested {
@@ -880,15 +1676,19 @@ pub mod module {
 				assets: assets.to_vec(),
 			});
 
-			// TODO: add weight for the request to the total weight.
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::request().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Approve a service request, so that the service can be initiated.
 		///
 		/// The `restaking_percent` is the percentage of the restaked tokens that will be exposed to
 		/// the service.
-		#[pallet::weight(T::WeightInfo::approve())]
+		#[pallet::weight(
+			T::WeightInfo::approve().saturating_add(Pallet::<T>::max_hook_weight(2))
+		)]
 		pub fn approve(
 			origin: OriginFor<T>,
 			#[pallet::compact] request_id: u64,
@@ -902,8 +1702,11 @@ pub mod module {
 				.find(|(v, _)| v == &caller)
 				.map(|(_, s)| *s = ApprovalState::Approved { restaking_percent });
 			ensure!(updated.is_some(), Error::<T>::ApprovalNotRequested);
+			// the pending requests of a deprecated blueprint can only be cancelled or expire.
+			Self::ensure_blueprint_active(request.blueprint)?;
 
-			let (_, blueprint) = Self::blueprints(request.blueprint)?;
+			let blueprint =
+				Self::blueprint_at_revision(request.blueprint, request.blueprint_revision)?;
 			let preferences = Operators::<T>::get(request.blueprint, caller.clone())?;
 			let approved = request
 				.operators_with_approval_state
@@ -924,7 +1727,7 @@ pub mod module {
 				)
 				.collect::<Vec<_>>();
 
-			let (allowed, _weight) = Self::on_approve_hook(
+			let (allowed, mut hook_weight) = Self::on_approve_hook(
 				&blueprint,
 				&preferences,
 				request_id,
@@ -944,8 +1747,11 @@ pub mod module {
 			if request.is_approved() {
 				// remove the service request.
 				ServiceRequests::<T>::remove(request_id);
+				ServiceRequestExpiries::<T>::remove(request.expiry, request_id);
 
 				let service_id = Self::next_instance_id();
+				// the escrowed value is now paid out across the lifetime of the service.
+				Self::open_service_escrow(request_id, service_id);
 				let operators = request
 					.operators_with_approval_state
 					.into_iter()
@@ -969,14 +1775,29 @@ pub mod module {
 				}
 				let operators = BoundedVec::<_, MaxOperatorsPerServiceOf<T>>::try_from(operators)
 					.map_err(|_| Error::<T>::MaxServiceProvidersExceeded)?;
+				let start_block = frame_system::Pallet::<T>::block_number();
+				let end_block = start_block.saturating_add(request.ttl);
+				// the operators have a full heartbeat interval to get their gadget running.
+				for (operator, _) in &operators {
+					Self::record_heartbeat(
+						service_id,
+						operator,
+						blueprint.heartbeat_interval,
+						start_block,
+					);
+				}
 				let service = Service {
 					id: service_id,
 					blueprint: request.blueprint,
+					blueprint_revision: request.blueprint_revision,
 					owner: request.owner.clone(),
 					assets: request.assets.clone(),
 					permitted_callers: request.permitted_callers.clone(),
 					operators,
 					ttl: request.ttl,
+					start_block,
+					end_block,
+					caller_policies: request.caller_policies.clone(),
 				};
 
 				UserServices::<T>::try_mutate(&request.owner, |service_ids| {
@@ -986,8 +1807,9 @@ pub mod module {
 						.try_insert(service_id)
 						.map_err(|_| Error::<T>::MaxServicesPerUserExceeded)
 				})?;
+				Self::schedule_service_expiry(service_id, end_block);
 
-				let (allowed, _weight) = Self::on_service_init_hook(
+				let (allowed, init_weight) = Self::on_service_init_hook(
 					&blueprint,
 					request_id,
 					service_id,
@@ -997,6 +1819,7 @@ pub mod module {
 					request.ttl,
 				)?;
 
+				hook_weight.saturating_accrue(init_weight);
 				ensure!(allowed, Error::<T>::ServiceInitializationInterrupted);
 
 				Self::deposit_event(Event::ServiceInitiated {
@@ -1011,13 +1834,18 @@ pub mod module {
 				ServiceRequests::<T>::insert(request_id, request);
 			}
 
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::approve().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Reject a service request.
-		/// The service will not be initiated, and the requester will need to update the service
-		/// request.
-		#[pallet::weight(T::WeightInfo::reject())]
+		/// The service will not be initiated, the service request is removed and its escrowed
+		/// value is refunded to the requester.
+		#[pallet::weight(
+			T::WeightInfo::reject().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn reject(
 			origin: OriginFor<T>,
 			#[pallet::compact] request_id: u64,
@@ -1035,24 +1863,35 @@ pub mod module {
 
 			ensure!(updated.is_some(), Error::<T>::ApprovalNotRequested);
 
-			let (_, blueprint) = Self::blueprints(request.blueprint)?;
+			let blueprint =
+				Self::blueprint_at_revision(request.blueprint, request.blueprint_revision)?;
 			let prefs = Operators::<T>::get(request.blueprint, caller.clone())?;
 
-			let (allowed, _weight) = Self::on_reject_hook(&blueprint, &prefs, request_id)?;
+			let (allowed, hook_weight) = Self::on_reject_hook(&blueprint, &prefs, request_id)?;
 
 			ensure!(allowed, Error::<T>::RejectionInterrupted);
+
+			ServiceRequests::<T>::remove(request_id);
+			ServiceRequestExpiries::<T>::remove(request.expiry, request_id);
+			let refunded = Self::refund_request_escrow(request_id, &request.owner)?;
+
 			Self::deposit_event(Event::ServiceRequestRejected {
 				operator: caller,
 				blueprint_id: request.blueprint,
 				request_id,
+				refunded,
 			});
 
-			// TODO: make use of the returned weight from the hook.
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::reject().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Terminates the service by the owner of the service.
-		#[pallet::weight(T::WeightInfo::terminate())]
+		#[pallet::weight(
+			T::WeightInfo::terminate().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn terminate(
 			origin: OriginFor<T>,
 			#[pallet::compact] service_id: u64,
@@ -1066,8 +1905,10 @@ pub mod module {
 			})?;
 			ensure!(removed, Error::<T>::ServiceNotFound);
 			Instances::<T>::remove(service_id);
-			let (_, blueprint) = Self::blueprints(service.blueprint)?;
-			let (allowed, _weight) =
+			ServiceExpiries::<T>::remove(service.end_block, service_id);
+			OperatorChangeRequests::<T>::remove(service_id);
+			let blueprint = Self::service_blueprint(&service)?;
+			let (allowed, hook_weight) =
 				Self::on_service_termination_hook(&blueprint, service_id, &service.owner)?;
 
 			ensure!(allowed, Error::<T>::TerminationInterrupted);
@@ -1081,17 +1922,31 @@ pub mod module {
 				})?;
 			}
 
+			// the job data is removed in the background, refunding the pending job call fees.
+			Self::queue_service_cleanup(service_id);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let refunded = Self::close_service_escrow(&service, now)?;
+
 			Self::deposit_event(Event::ServiceTerminated {
 				owner: caller.clone(),
 				service_id,
 				blueprint_id: service.blueprint,
+				reason: ServiceTerminationReason::Owner,
+				refunded,
 			});
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::terminate().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Call a Job in the service.
-		/// The caller needs to be the owner of the service, or a permitted caller.
-		#[pallet::weight(T::WeightInfo::call())]
+		/// The caller needs to be allowed by the caller policy of the job, by default the owner of
+		/// the service or a permitted caller.
+		#[pallet::weight(
+			T::WeightInfo::call().saturating_add(Pallet::<T>::max_hook_weight(2))
+		)]
 		pub fn call(
 			origin: OriginFor<T>,
 			#[pallet::compact] service_id: u64,
@@ -1100,39 +1955,24 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
 			let service = Self::services(service_id)?;
-			let (_, blueprint) = Self::blueprints(service.blueprint)?;
-			let is_permitted_caller = service.permitted_callers.iter().any(|v| v == &caller);
-			ensure!(service.owner == caller || is_permitted_caller, DispatchError::BadOrigin);
-
-			let job_def =
-				blueprint.jobs.get(usize::from(job)).ok_or(Error::<T>::JobDefinitionNotFound)?;
-			let bounded_args = BoundedVec::<_, MaxFieldsOf<T>>::try_from(args.clone())
-				.map_err(|_| Error::<T>::MaxFieldsExceeded)?;
-			let job_call = JobCall { service_id, job, args: bounded_args };
-
-			job_call.type_check(job_def).map_err(Error::<T>::TypeCheck)?;
-			let call_id = Self::next_job_call_id();
-
-			let (allowed, _weight) =
-				Self::on_job_call_hook(&blueprint, service_id, job, call_id, &args)?;
-
-			ensure!(allowed, Error::<T>::InvalidJobCallInput);
-
-			JobCalls::<T>::insert(service_id, call_id, job_call);
-			NextJobCallId::<T>::set(call_id.saturating_add(1));
-			Self::deposit_event(Event::JobCalled {
-				caller: caller.clone(),
-				service_id,
-				call_id,
-				job,
-				args,
-			});
-			// TODO: add weight for the call to the total weight.
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			let blueprint = Self::service_blueprint(&service)?;
+			let (allowed, mut hook_weight) =
+				Self::is_job_caller_allowed(&blueprint, &service, job, &caller)?;
+			ensure!(allowed, DispatchError::BadOrigin);
+
+			hook_weight.saturating_accrue(Self::do_call(&caller, &service, &blueprint, job, args)?);
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::call().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Submit the job result by using the service ID and call ID.
-		#[pallet::weight(T::WeightInfo::submit_result())]
+		#[pallet::weight(
+			T::WeightInfo::submit_result()
+				.saturating_add(Pallet::<T>::max_result_verification_weight())
+				.saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
 		pub fn submit_result(
 			origin: OriginFor<T>,
 			#[pallet::compact] service_id: u64,
@@ -1140,54 +1980,33 @@ pub mod module {
 			result: Vec<Field<T::Constraints, T::AccountId>>,
 		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
-			let job_call = Self::job_calls(service_id, call_id)?;
-			let service = Self::services(job_call.service_id)?;
-			let (_, blueprint) = Self::blueprints(service.blueprint)?;
+			let service = Self::services(service_id)?;
+			let blueprint = Self::service_blueprint(&service)?;
 
 			let is_operator = service.operators.iter().any(|(v, _)| v == &caller);
 			ensure!(is_operator, DispatchError::BadOrigin);
 			let operator_preferences = Operators::<T>::get(service.blueprint, &caller)?;
 
-			let job_def = blueprint
-				.jobs
-				.get(usize::from(job_call.job))
-				.ok_or(Error::<T>::JobDefinitionNotFound)?;
-
-			let bounded_result = BoundedVec::<_, MaxFieldsOf<T>>::try_from(result.clone())
-				.map_err(|_| Error::<T>::MaxFieldsExceeded)?;
-
-			let job_result = JobCallResult { service_id, call_id, result: bounded_result };
-			job_result.type_check(job_def).map_err(Error::<T>::TypeCheck)?;
-
-			let (allowed, _weight) = Self::on_job_result_hook(
+			let hook_weight = Self::do_submit_result(
+				&caller,
+				&service,
 				&blueprint,
-				service_id,
-				job_call.job,
-				call_id,
 				&operator_preferences,
-				&job_call.args,
-				&result,
-			)?;
-
-			ensure!(allowed, Error::<T>::InvalidJobResult);
-
-			JobResults::<T>::insert(service_id, call_id, job_result);
-			Self::deposit_event(Event::JobResultSubmitted {
-				operator: caller.clone(),
-				service_id,
 				call_id,
-				job: job_call.job,
 				result,
-			});
-			// TODO: add weight for the call to the total weight.
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			)?;
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::submit_result().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Slash an operator (offender) for a service id with a given percent of their exposed stake for that service.
 		///
 		/// The caller needs to be an authorized Slash Origin for this service.
-		/// Note that this does not apply the slash directly, but instead schedules a deferred call to apply the slash
-		/// by another entity.
+		/// Note that this does not apply the slash directly, but instead defers it by `SlashDeferDuration` eras,
+		/// after which it gets applied unless it has been disputed in the meantime.
+		#[pallet::weight(T::WeightInfo::slash().saturating_add(Pallet::<T>::max_hook_weight(1)))]
 		pub fn slash(
 			origin: OriginFor<T>,
 			offender: T::AccountId,
@@ -1196,7 +2015,7 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
 			let service = Self::services(service_id)?;
-			let (maybe_slashing_origin, _used_weight) = Self::query_slashing_origin(&service)?;
+			let (maybe_slashing_origin, hook_weight) = Self::query_slashing_origin(&service)?;
 			let slashing_origin = maybe_slashing_origin.ok_or(Error::<T>::NoSlashingOrigin)?;
 			ensure!(slashing_origin == caller, DispatchError::BadOrigin);
 
@@ -1215,12 +2034,14 @@ pub mod module {
 			let exposed_stake = percent.mul_floor(own_stake);
 			let others_slash = delegators
 				.into_iter()
-				.map(|(delegator, stake, _asset_id)| (delegator, percent.mul_floor(stake)))
+				.map(|(delegator, stake, asset_id)| {
+					(delegator, asset_id, percent.mul_floor(stake))
+				})
 				.collect::<Vec<_>>();
-			let total_slash =
-				others_slash.iter().fold(exposed_stake, |acc, (_, slash)| acc + *slash);
 			// TODO: take into account the delegators' asset kind.
-			// for now, we treat all assets equally, which is not the case in reality.
+			// for now, we treat all assets equally in the total, which is not the case in reality.
+			let total_slash =
+				others_slash.iter().fold(exposed_stake, |acc, (_, _, slash)| acc + *slash);
 			let unapplied_slash = UnappliedSlash {
 				service_id,
 				operator: offender.clone(),
@@ -1244,12 +2065,16 @@ pub mod module {
 				era,
 			});
 
-			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::slash().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
 		/// Dispute an [UnappliedSlash] for a given era and index.
 		///
 		/// The caller needs to be an authorized Dispute Origin for the service in the [UnappliedSlash].
+		#[pallet::weight(T::WeightInfo::dispute().saturating_add(Pallet::<T>::max_hook_weight(1)))]
 		pub fn dispute(
 			origin: OriginFor<T>,
 			#[pallet::compact] era: u32,
@@ -1258,7 +2083,7 @@ pub mod module {
 			let caller = ensure_signed(origin)?;
 			let unapplied_slash = Self::unapplied_slashes(era, index)?;
 			let service = Self::services(unapplied_slash.service_id)?;
-			let (maybe_dispute_origin, _used_weight) = Self::query_dispute_origin(&service)?;
+			let (maybe_dispute_origin, hook_weight) = Self::query_dispute_origin(&service)?;
 			let dispute_origin = maybe_dispute_origin.ok_or(Error::<T>::NoDisputeOrigin)?;
 			ensure!(dispute_origin == caller, DispatchError::BadOrigin);
 
@@ -1273,7 +2098,746 @@ pub mod module {
 				era,
 			});
 
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::dispute().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Pay out the value of a service that vested so far to its operators.
+		///
+		/// The value escrowed by the service request vests linearly across the lifetime of the
+		/// service, and is split between the operators weighted by their restaking percentage.
+		/// Anyone can trigger the payout.
+		#[pallet::weight(T::WeightInfo::payout())]
+		pub fn payout(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::payout_service_escrow(&service, now)?;
 			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
 		}
+
+		/// Publish a new revision of a service blueprint.
+		///
+		/// The existing services keep running the revision they were instantiated with, and the
+		/// registered operators need to opt in to the new revision using
+		/// `update_operator_revision` before they can be requested for new services.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service blueprint.
+		/// - `blueprint_id`: The ID of the service blueprint.
+		/// - `blueprint`: The new revision of the service blueprint.
+		#[pallet::weight(T::WeightInfo::update_blueprint())]
+		pub fn update_blueprint(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+			blueprint: ServiceBlueprint<T::Constraints>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (owner, _) = Self::blueprints(blueprint_id)?;
+			ensure!(owner == caller, DispatchError::BadOrigin);
+			Self::ensure_blueprint_active(blueprint_id)?;
+			ensure!(
+				blueprint.hook_gas_limit <= T::MaxHookGasLimit::get(),
+				Error::<T>::HookGasLimitExceeded
+			);
+			Self::ensure_blueprint_manager_exists(&blueprint)?;
+			Self::ensure_result_verifiers_valid(&blueprint)?;
+			Self::ensure_field_types_valid(&blueprint)?;
+			Self::ensure_job_fees_valid(&blueprint)?;
+
+			let revision = Self::latest_blueprint_revision(blueprint_id).saturating_add(1);
+			BlueprintRevisions::<T>::insert(blueprint_id, revision, &blueprint);
+			LatestBlueprintRevision::<T>::insert(blueprint_id, revision);
+			Blueprints::<T>::insert(blueprint_id, (owner.clone(), blueprint));
+
+			Self::deposit_event(Event::BlueprintUpdated { owner, blueprint_id, revision });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Opt in to the latest revision of a service blueprint the caller is registered for.
+		///
+		/// New services are only instantiated with operators running the latest revision.
+		///
+		/// # Parameters
+		/// - `origin`: The operator registered for the service blueprint.
+		/// - `blueprint_id`: The ID of the service blueprint.
+		#[pallet::weight(T::WeightInfo::update_operator_revision())]
+		pub fn update_operator_revision(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(blueprint_id, &caller), Error::<T>::NotRegistered);
+			let revision = Self::latest_blueprint_revision(blueprint_id);
+			OperatorBlueprintRevision::<T>::insert(blueprint_id, &caller, revision);
+
+			Self::deposit_event(Event::OperatorRevisionUpdated {
+				operator: caller,
+				blueprint_id,
+				revision,
+			});
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Transfer the ownership of a service blueprint to another account.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service blueprint.
+		/// - `blueprint_id`: The ID of the service blueprint.
+		/// - `new_owner`: The account that becomes the owner of the service blueprint.
+		#[pallet::weight(T::WeightInfo::transfer_blueprint_ownership())]
+		pub fn transfer_blueprint_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Blueprints::<T>::try_mutate(blueprint_id, |blueprint| {
+				let (owner, _) = blueprint.as_mut().map_err(|_| Error::<T>::BlueprintNotFound)?;
+				ensure!(*owner == caller, DispatchError::BadOrigin);
+				*owner = new_owner.clone();
+				Result::<_, DispatchError>::Ok(())
+			})?;
+
+			Self::deposit_event(Event::BlueprintOwnershipTransferred {
+				blueprint_id,
+				from: caller,
+				to: new_owner,
+			});
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Deprecate a service blueprint.
+		///
+		/// A deprecated blueprint does not accept new operator registrations, service requests
+		/// nor revisions, and its pending service requests can no longer be approved, only
+		/// cancelled or left to expire. The services already running keep running until they
+		/// terminate. The deprecation can not be undone.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service blueprint.
+		/// - `blueprint_id`: The ID of the service blueprint.
+		#[pallet::weight(T::WeightInfo::deprecate_blueprint())]
+		pub fn deprecate_blueprint(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (owner, _) = Self::blueprints(blueprint_id)?;
+			ensure!(owner == caller, DispatchError::BadOrigin);
+			Self::ensure_blueprint_active(blueprint_id)?;
+
+			BlueprintStates::<T>::insert(blueprint_id, BlueprintState::Deprecated);
+
+			Self::deposit_event(Event::BlueprintDeprecated { owner, blueprint_id });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Store the zero-knowledge verifying key of a job of a service blueprint.
+		///
+		/// The results of the job are checked against this key when its verifier is
+		/// `JobResultVerifier::Groth16`. The key is kept across the revisions of the blueprint.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service blueprint.
+		/// - `blueprint_id`: The ID of the service blueprint.
+		/// - `job`: The index of the job in the latest revision of the blueprint.
+		/// - `key`: The verifying key, compressed with arkworks.
+		#[pallet::weight(T::WeightInfo::set_verifying_key(key.len() as u32))]
+		pub fn set_verifying_key(
+			origin: OriginFor<T>,
+			#[pallet::compact] blueprint_id: u64,
+			job: u8,
+			key: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (owner, blueprint) = Self::blueprints(blueprint_id)?;
+			ensure!(owner == caller, DispatchError::BadOrigin);
+			Self::ensure_blueprint_active(blueprint_id)?;
+			ensure!(usize::from(job) < blueprint.jobs.len(), Error::<T>::JobDefinitionNotFound);
+			let key = BoundedVec::<_, T::MaxVerifyingKeyLength>::try_from(key)
+				.map_err(|_| Error::<T>::MaxVerifyingKeyLengthExceeded)?;
+			// both verifiers read the same key, only their proofs are encoded differently.
+			ArkworksVerifierGroth16Bn254::validate_verifying_key(&key)
+				.map_err(|_| Error::<T>::InvalidVerifyingKey)?;
+
+			VerifyingKeys::<T>::insert(blueprint_id, job, key);
+
+			Self::deposit_event(Event::VerifyingKeySet { blueprint_id, job });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Register the group key of the operators of a service, taken from the final result of
+		/// a job call, e.g. of a distributed key generation.
+		///
+		/// The results of the jobs whose verifier is `JobResultVerifier::GroupSignature` must
+		/// then be signed under this key. The key can only be registered once, so that the
+		/// results are verified against the same key for the lifetime of the service.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service.
+		/// - `service_id`: The ID of the service.
+		/// - `call_id`: The ID of the job call whose final result holds the key.
+		/// - `field`: The index of the result field holding the key.
+		/// - `scheme`: The signature scheme of the key.
+		#[pallet::weight(T::WeightInfo::register_group_key())]
+		pub fn register_group_key(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			#[pallet::compact] call_id: u64,
+			field: u8,
+			scheme: SignatureScheme,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			ensure!(service.owner == caller, DispatchError::BadOrigin);
+			ensure!(
+				!ServiceGroupKeys::<T>::contains_key(service_id),
+				Error::<T>::GroupKeyAlreadyRegistered
+			);
+
+			let key = Self::group_key_from_result(service_id, call_id, field, scheme)?;
+			ServiceGroupKeys::<T>::insert(service_id, &key);
+
+			Self::deposit_event(Event::GroupKeyRegistered { service_id, call_id, key });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Cancel a pending service request.
+		///
+		/// The request is removed, the blueprint manager gets notified, and the escrowed value is
+		/// refunded to the owner.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service request.
+		/// - `request_id`: The ID of the service request.
+		#[pallet::weight(
+			T::WeightInfo::cancel_request().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
+		pub fn cancel_request(
+			origin: OriginFor<T>,
+			#[pallet::compact] request_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let request = Self::service_requests(request_id)?;
+			ensure!(request.owner == caller, DispatchError::BadOrigin);
+
+			ServiceRequests::<T>::remove(request_id);
+			ServiceRequestExpiries::<T>::remove(request.expiry, request_id);
+
+			let blueprint =
+				Self::blueprint_at_revision(request.blueprint, request.blueprint_revision)?;
+			// the blueprint manager is only notified, it can not prevent the cancellation.
+			let (_handled, hook_weight) = Self::on_request_cancelled_hook(&blueprint, request_id)?;
+
+			let refunded = Self::refund_request_escrow(request_id, &request.owner)?;
+
+			Self::deposit_event(Event::ServiceRequestCancelled {
+				owner: caller,
+				request_id,
+				blueprint_id: request.blueprint,
+				refunded,
+			});
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::cancel_request().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Leave a service the caller is an operator of.
+		///
+		/// The operator has to keep serving the service, answering its job calls, for the notice
+		/// period set by the blueprint, after which it gets removed from the service and paid
+		/// its share of the service value vested so far.
+		///
+		/// # Parameters
+		/// - `origin`: The operator leaving the service.
+		/// - `service_id`: The ID of the service.
+		#[pallet::weight(
+			T::WeightInfo::leave_service().saturating_add(Pallet::<T>::max_hook_weight(1))
+		)]
+		pub fn leave_service(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			let is_operator = service.operators.iter().any(|(v, _)| v == &caller);
+			ensure!(is_operator, DispatchError::BadOrigin);
+			ensure!(
+				!OperatorExits::<T>::contains_key(service_id, &caller),
+				Error::<T>::AlreadyLeavingService
+			);
+			let remaining = service
+				.operators
+				.iter()
+				.filter(|(operator, _)| !OperatorExits::<T>::contains_key(service_id, operator))
+				.count();
+			ensure!(remaining > 1, Error::<T>::LastServiceOperator);
+
+			let blueprint = Self::service_blueprint(&service)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let exit_block = now.saturating_add(blueprint.exit_notice_period.into());
+			Self::deposit_event(Event::OperatorLeaving {
+				operator: caller.clone(),
+				service_id,
+				exit_block,
+			});
+
+			let mut hook_weight = Weight::zero();
+			if exit_block <= now {
+				hook_weight = Self::remove_service_operator(service_id, &caller);
+			} else {
+				Self::schedule_operator_exit(service_id, &caller, exit_block);
+			}
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::leave_service().saturating_add(hook_weight)),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Propose a change of the operators of a running service.
+		///
+		/// The change is applied once all the `joining` operators approved it, with their
+		/// restaking percent, or right away if no operator is joining. The caller needs to be the
+		/// owner of the service, or the manager contract of its blueprint, through the account its
+		/// address maps to or the native account mapping to its address.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service or the manager of its blueprint.
+		/// - `service_id`: The ID of the service.
+		/// - `joining`: The active operators joining the service, which must be registered on the
+		///   revision of the blueprint the service is pinned to.
+		/// - `leaving`: The operators leaving the service.
+		#[pallet::weight({
+			let changed = (joining.len() + leaving.len()) as u32;
+			T::WeightInfo::propose_operator_change(changed)
+				.saturating_add(Pallet::<T>::max_hook_weight(changed))
+		})]
+		pub fn propose_operator_change(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			joining: Vec<T::AccountId>,
+			leaving: Vec<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			let blueprint = Self::service_blueprint(&service)?;
+			ensure!(
+				service.owner == caller || Self::is_blueprint_manager(&blueprint, &caller),
+				DispatchError::BadOrigin
+			);
+			ensure!(
+				!OperatorChangeRequests::<T>::contains_key(service_id),
+				Error::<T>::OperatorChangeAlreadyProposed
+			);
+			ensure!(!joining.is_empty() || !leaving.is_empty(), Error::<T>::InvalidOperatorChange);
+
+			let mut changed = joining.iter().chain(&leaving).collect::<Vec<_>>();
+			changed.sort();
+			changed.dedup();
+			ensure!(
+				changed.len() == joining.len().saturating_add(leaving.len()),
+				Error::<T>::InvalidOperatorChange
+			);
+			Self::ensure_operator_change_valid(&service, &joining, &leaving)?;
+
+			let change = OperatorChangeRequest {
+				proposer: caller.clone(),
+				joining: joining
+					.iter()
+					.cloned()
+					.map(|v| (v, ApprovalState::Pending))
+					.collect::<Vec<_>>()
+					.try_into()
+					.map_err(|_| Error::<T>::MaxServiceProvidersExceeded)?,
+				leaving: leaving
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::MaxServiceProvidersExceeded)?,
+			};
+			Self::deposit_event(Event::OperatorChangeProposed {
+				proposer: caller,
+				service_id,
+				joining,
+				leaving,
+			});
+
+			let (mut applied, mut hook_weight) = (0, Weight::zero());
+			if change.is_approved() {
+				applied = change.joining.len().saturating_add(change.leaving.len()) as u32;
+				hook_weight = Self::apply_operator_change(service_id, change)?;
+			} else {
+				OperatorChangeRequests::<T>::insert(service_id, change);
+			}
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::propose_operator_change(applied).saturating_add(hook_weight),
+				),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Approve joining a running service through its pending operator change.
+		///
+		/// The `restaking_percent` is the percentage of the restaked tokens that will be exposed to
+		/// the service. The `change_witness` is the number of operators joining and leaving the
+		/// service through the change, as the last approval applies it. The last approval fails if
+		/// the change is no longer valid, in which case a joining operator can reject it.
+		#[pallet::weight(
+			T::WeightInfo::approve_operator_change(*change_witness)
+				.saturating_add(Pallet::<T>::max_hook_weight(*change_witness))
+		)]
+		pub fn approve_operator_change(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			#[pallet::compact] restaking_percent: Percent,
+			#[pallet::compact] change_witness: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let mut change = Self::operator_change_requests(service_id)?;
+			let changed = change.joining.len().saturating_add(change.leaving.len());
+			ensure!(changed <= change_witness as usize, Error::<T>::InvalidOperatorChangeWitness);
+			let updated = change
+				.joining
+				.iter_mut()
+				.find(|(v, _)| v == &caller)
+				.map(|(_, s)| *s = ApprovalState::Approved { restaking_percent });
+			ensure!(updated.is_some(), Error::<T>::ApprovalNotRequested);
+
+			let pending_approvals = change
+				.joining
+				.iter()
+				.filter_map(
+					|(v, s)| if *s == ApprovalState::Pending { Some(v.clone()) } else { None },
+				)
+				.collect::<Vec<_>>();
+			Self::deposit_event(Event::OperatorChangeApproved {
+				operator: caller,
+				service_id,
+				pending_approvals,
+			});
+
+			let (mut applied, mut hook_weight) = (0, Weight::zero());
+			if change.is_approved() {
+				// the operators or the blueprint might have changed since the proposal.
+				let joining = change.joining.iter().map(|(v, _)| v.clone()).collect::<Vec<_>>();
+				Self::ensure_operator_change_valid(
+					&Self::services(service_id)?,
+					&joining,
+					&change.leaving,
+				)?;
+				OperatorChangeRequests::<T>::remove(service_id);
+				applied = changed as u32;
+				hook_weight = Self::apply_operator_change(service_id, change)?;
+			} else {
+				OperatorChangeRequests::<T>::insert(service_id, change);
+			}
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::approve_operator_change(applied).saturating_add(hook_weight),
+				),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Reject joining a running service, which drops its pending operator change.
+		#[pallet::weight(T::WeightInfo::reject_operator_change())]
+		pub fn reject_operator_change(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let change = Self::operator_change_requests(service_id)?;
+			let is_joining = change.joining.iter().any(|(v, _)| v == &caller);
+			ensure!(is_joining, Error::<T>::ApprovalNotRequested);
+
+			OperatorChangeRequests::<T>::remove(service_id);
+			Self::deposit_event(Event::OperatorChangeRejected { operator: caller, service_id });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Set the caller policy of a job of a service.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service.
+		/// - `service_id`: The ID of the service.
+		/// - `job`: The index of the job.
+		/// - `policy`: The new caller policy of the job.
+		#[pallet::weight(T::WeightInfo::set_job_caller_policy())]
+		pub fn set_job_caller_policy(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			#[pallet::compact] job: u8,
+			policy: JobCallerPolicy<T::Constraints, T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let mut service = Self::services(service_id)?;
+			ensure!(service.owner == caller, DispatchError::BadOrigin);
+			let blueprint = Self::service_blueprint(&service)?;
+			ensure!(usize::from(job) < blueprint.jobs.len(), Error::<T>::JobDefinitionNotFound);
+
+			match service.caller_policies.binary_search_by_key(&job, |(j, _)| *j) {
+				Ok(i) => service.caller_policies[i].1 = policy.clone(),
+				Err(i) => service
+					.caller_policies
+					.try_insert(i, (job, policy.clone()))
+					.map_err(|_| Error::<T>::JobDefinitionNotFound)?,
+			}
+			Instances::<T>::insert(service_id, service);
+
+			Self::deposit_event(Event::JobCallerPolicyUpdated { service_id, job, policy });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Add a permitted caller to a service.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service.
+		/// - `service_id`: The ID of the service.
+		/// - `caller`: The account to permit calling the service.
+		#[pallet::weight(T::WeightInfo::add_permitted_caller())]
+		pub fn add_permitted_caller(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			caller: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut service = Self::services(service_id)?;
+			ensure!(service.owner == owner, DispatchError::BadOrigin);
+			ensure!(
+				!service.permitted_callers.contains(&caller),
+				Error::<T>::AlreadyPermittedCaller
+			);
+			service
+				.permitted_callers
+				.try_push(caller.clone())
+				.map_err(|_| Error::<T>::MaxPermittedCallersExceeded)?;
+			Instances::<T>::insert(service_id, service);
+
+			Self::deposit_event(Event::PermittedCallerAdded { service_id, caller });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Remove a permitted caller from a service.
+		///
+		/// # Parameters
+		/// - `origin`: The owner of the service.
+		/// - `service_id`: The ID of the service.
+		/// - `caller`: The account to no longer permit calling the service.
+		#[pallet::weight(T::WeightInfo::remove_permitted_caller())]
+		pub fn remove_permitted_caller(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			caller: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut service = Self::services(service_id)?;
+			ensure!(service.owner == owner, DispatchError::BadOrigin);
+			let index = service
+				.permitted_callers
+				.iter()
+				.position(|v| v == &caller)
+				.ok_or(Error::<T>::NotPermittedCaller)?;
+			service.permitted_callers.remove(index);
+			Instances::<T>::insert(service_id, service);
+
+			Self::deposit_event(Event::PermittedCallerRemoved { service_id, caller });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Call several jobs of a service at once.
+		///
+		/// The service and its blueprint are only read once for the whole batch, and the caller
+		/// policy of each job is only checked once. The blueprint manager is notified of the
+		/// whole batch at once, through `onJobCallBatch` when its contract supports it. The batch
+		/// fails as a whole if any of the calls fails.
+		///
+		/// # Parameters
+		/// - `origin`: The account calling the jobs.
+		/// - `service_id`: The ID of the service.
+		/// - `calls`: The index and the arguments of each job call.
+		#[pallet::weight(Pallet::<T>::call_batch_weight(calls.len() as u32))]
+		pub fn call_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			calls: Vec<(u8, Vec<Field<T::Constraints, T::AccountId>>)>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let batch_size = calls.len() as u32;
+			ensure!(
+				calls.len() <= T::MaxJobBatchSize::get() as usize,
+				Error::<T>::JobBatchTooLarge
+			);
+			let service = Self::services(service_id)?;
+			let blueprint = Self::service_blueprint(&service)?;
+			Self::ensure_batch_hook_gas(&blueprint, batch_size.saturating_mul(2))?;
+
+			let mut jobs = calls.iter().map(|(job, _)| *job).collect::<Vec<_>>();
+			jobs.sort();
+			jobs.dedup();
+			let mut hook_weight = Weight::zero();
+			for job in jobs {
+				let (allowed, weight) =
+					Self::is_job_caller_allowed(&blueprint, &service, job, &caller)?;
+				hook_weight.saturating_accrue(weight);
+				ensure!(allowed, DispatchError::BadOrigin);
+			}
+
+			hook_weight
+				.saturating_accrue(Self::do_call_batch(&caller, &service, &blueprint, calls)?);
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::call_batch(batch_size).saturating_add(hook_weight),
+				),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Submit the results of several job calls of a service at once.
+		///
+		/// The service, its blueprint and the operator preferences are only read once for the
+		/// whole batch. The blueprint manager is notified of the whole batch at once, through
+		/// `onJobResultBatch` when its contract supports it. The batch fails as a whole if any of
+		/// the results fails.
+		///
+		/// # Parameters
+		/// - `origin`: The operator submitting the results.
+		/// - `service_id`: The ID of the service.
+		/// - `results`: The call ID and the result of each job call.
+		#[pallet::weight(Pallet::<T>::submit_results_batch_weight(results.len() as u32))]
+		pub fn submit_results_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			results: Vec<(u64, Vec<Field<T::Constraints, T::AccountId>>)>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let batch_size = results.len() as u32;
+			ensure!(
+				results.len() <= T::MaxJobBatchSize::get() as usize,
+				Error::<T>::JobBatchTooLarge
+			);
+			let service = Self::services(service_id)?;
+			let blueprint = Self::service_blueprint(&service)?;
+			Self::ensure_batch_hook_gas(&blueprint, batch_size)?;
+
+			let is_operator = service.operators.iter().any(|(v, _)| v == &caller);
+			ensure!(is_operator, DispatchError::BadOrigin);
+			let operator_preferences = Operators::<T>::get(service.blueprint, &caller)?;
+
+			let hook_weight = Self::do_submit_results_batch(
+				&caller,
+				&service,
+				&blueprint,
+				&operator_preferences,
+				results,
+			)?;
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::submit_results_batch(batch_size).saturating_add(hook_weight),
+				),
+				pays_fee: Pays::Yes,
+			})
+		}
+
+		/// Acknowledge the final result of a job call, so that the data of the call gets pruned
+		/// right away instead of at the end of the retention period.
+		///
+		/// The caller needs to be the account that called the job, or the owner of the service.
+		///
+		/// # Parameters
+		/// - `origin`: The consumer of the job result.
+		/// - `service_id`: The ID of the service.
+		/// - `call_id`: The ID of the job call.
+		#[pallet::weight(T::WeightInfo::acknowledge_job_result())]
+		pub fn acknowledge_job_result(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+			#[pallet::compact] call_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			let job_call = Self::job_calls(service_id, call_id)?;
+			ensure!(
+				job_call.caller == caller || service.owner == caller,
+				DispatchError::BadOrigin
+			);
+			ensure!(
+				JobResults::<T>::contains_key(service_id, call_id),
+				Error::<T>::JobCallResultNotFound
+			);
+
+			Self::prune_job_call(service_id, call_id);
+			Self::deposit_event(Event::JobResultAcknowledged { caller, service_id, call_id });
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Sends a heartbeat for a service, proving that the gadget of the operator is running.
+		///
+		/// The caller must be an operator of the service, and the blueprint of the service must
+		/// define a heartbeat interval. Operators that do not send a heartbeat within the
+		/// interval get flagged as unresponsive.
+		///
+		/// An operator can send at most [`Pallet::MAX_HEARTBEATS_PER_INTERVAL`] heartbeats per
+		/// interval.
+		#[pallet::weight(T::WeightInfo::heartbeat())]
+		pub fn heartbeat(
+			origin: OriginFor<T>,
+			#[pallet::compact] service_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let service = Self::services(service_id)?;
+			let blueprint = Self::service_blueprint(&service)?;
+			ensure!(blueprint.heartbeat_interval > 0, Error::<T>::HeartbeatsDisabled);
+			let is_operator = service.operators.iter().any(|(v, _)| v == &caller);
+			ensure!(is_operator, DispatchError::BadOrigin);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::ensure_heartbeat_rate(service_id, &caller, blueprint.heartbeat_interval, now)?;
+			Self::record_heartbeat(service_id, &caller, blueprint.heartbeat_interval, now);
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes })
+		}
+
+		/// Submits a heartbeat signed with the ECDSA key of the operator preferences, without
+		/// a signed origin, e.g. from an offchain worker.
+		///
+		/// The heartbeat must be newer than the last one of the operator, and must have been
+		/// created within the heartbeat interval of the blueprint. The offchain worker of the
+		/// pallet submits them for the operator keys of the local keystore.
+		#[pallet::weight(T::WeightInfo::submit_heartbeat())]
+		pub fn submit_heartbeat(
+			origin: OriginFor<T>,
+			heartbeat: OperatorHeartbeat<T::AccountId, BlockNumberFor<T>>,
+			signature: sp_core::ecdsa::Signature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let interval = Self::validate_heartbeat(&heartbeat, &signature)?;
+			Self::record_heartbeat(
+				heartbeat.service_id,
+				&heartbeat.operator,
+				interval,
+				heartbeat.block_number,
+			);
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::submit_heartbeat { heartbeat, signature } => {
+					Self::validate_unsigned_heartbeat(heartbeat, signature)
+				},
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
 	}
 }
@@ -0,0 +1,337 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Webb Technologies Inc.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the services pallet.
+
+use super::*;
+
+/// Migrates the service blueprints, the service requests and the service instances to the layout
+/// with blueprint revisions, request expiries, service lifetimes, job result policies, job response
+/// windows, job fees, caller policies, exit notice periods, heartbeats and hook gas limits, the job
+/// calls to the layout with their callers, and the unapplied slashes to the layout with the assets
+/// of the slashed delegators.
+pub mod v1 {
+	use super::*;
+	use crate::types::BalanceOf;
+	use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		Percent,
+	};
+	use sp_std::{marker::PhantomData, vec::Vec};
+	use tangle_primitives::services::{
+		ApprovalState, BlueprintManager, Constraints, Field, FieldType, Gadget, JobCall,
+		JobDefinition, JobMetadata, JobResultVerifier, Service, ServiceBlueprint, ServiceEscrow,
+		ServiceMetadata, ServiceRegistrationHook, ServiceRequest, ServiceRequestHook,
+		UnappliedSlash,
+	};
+	use tangle_primitives::MultiAssetDelegationInfo;
+
+	/// A job definition, before the result policies, the response windows and the fees.
+	#[derive(Encode, Decode)]
+	#[codec(encode_bound(skip_type_params(C)))]
+	#[codec(decode_bound(skip_type_params(C)))]
+	pub struct OldJobDefinition<C: Constraints> {
+		pub metadata: JobMetadata<C>,
+		pub params: BoundedVec<FieldType, C::MaxFields>,
+		pub result: BoundedVec<FieldType, C::MaxFields>,
+		pub verifier: JobResultVerifier,
+	}
+
+	/// A service blueprint, before the exit notice periods, the heartbeats and the hook gas limits.
+	#[derive(Encode, Decode)]
+	#[codec(encode_bound(skip_type_params(C)))]
+	#[codec(decode_bound(skip_type_params(C)))]
+	pub struct OldServiceBlueprint<C: Constraints> {
+		pub metadata: ServiceMetadata<C>,
+		pub jobs: BoundedVec<OldJobDefinition<C>, C::MaxJobsPerService>,
+		pub registration_hook: ServiceRegistrationHook,
+		pub registration_params: BoundedVec<FieldType, C::MaxFields>,
+		pub request_hook: ServiceRequestHook,
+		pub request_params: BoundedVec<FieldType, C::MaxFields>,
+		pub manager: BlueprintManager,
+		pub gadget: Gadget<C>,
+	}
+
+	/// A service request, before the blueprint revisions, the expiries and the caller policies.
+	#[derive(Encode, Decode)]
+	#[codec(encode_bound(skip_type_params(C)))]
+	#[codec(decode_bound(skip_type_params(C)))]
+	pub struct OldServiceRequest<C: Constraints, AccountId, BlockNumber, AssetId> {
+		pub blueprint: u64,
+		pub owner: AccountId,
+		pub permitted_callers: BoundedVec<AccountId, C::MaxPermittedCallers>,
+		pub assets: BoundedVec<AssetId, C::MaxAssetsPerService>,
+		pub ttl: BlockNumber,
+		pub args: BoundedVec<Field<C, AccountId>, C::MaxFields>,
+		pub operators_with_approval_state:
+			BoundedVec<(AccountId, ApprovalState), C::MaxOperatorsPerService>,
+	}
+
+	/// A service instance, before the blueprint revisions, the lifetimes and the caller
+	/// policies.
+	#[derive(Encode, Decode)]
+	#[codec(encode_bound(skip_type_params(C)))]
+	#[codec(decode_bound(skip_type_params(C)))]
+	pub struct OldService<C: Constraints, AccountId, BlockNumber, AssetId> {
+		pub id: u64,
+		pub blueprint: u64,
+		pub owner: AccountId,
+		pub permitted_callers: BoundedVec<AccountId, C::MaxPermittedCallers>,
+		pub operators: BoundedVec<(AccountId, Percent), C::MaxOperatorsPerService>,
+		pub assets: BoundedVec<AssetId, C::MaxAssetsPerService>,
+		pub ttl: BlockNumber,
+	}
+
+	/// A job call, before its caller.
+	#[derive(Encode, Decode)]
+	#[codec(encode_bound(skip_type_params(C)))]
+	#[codec(decode_bound(skip_type_params(C)))]
+	pub struct OldJobCall<C: Constraints, AccountId> {
+		pub service_id: u64,
+		pub job: u8,
+		pub args: BoundedVec<Field<C, AccountId>, C::MaxFields>,
+	}
+
+	/// An unapplied slash, before the assets of the slashed delegators.
+	#[derive(Encode, Decode)]
+	pub struct OldUnappliedSlash<AccountId, Balance> {
+		pub service_id: u64,
+		pub operator: AccountId,
+		pub own: Balance,
+		pub others: Vec<(AccountId, Balance)>,
+		pub reporters: Vec<AccountId>,
+		pub payout: Balance,
+	}
+
+	type OldServiceRequestOf<T> = OldServiceRequest<
+		<T as Config>::Constraints,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		<T as Config>::AssetId,
+	>;
+
+	type OldServiceOf<T> = OldService<
+		<T as Config>::Constraints,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		<T as Config>::AssetId,
+	>;
+
+	impl<C: Constraints> OldServiceBlueprint<C> {
+		/// The blueprint with the defaults of the new fields, which keep its previous behavior: the
+		/// first result of a job call is final, job calls never time out and are free, the
+		/// operators leave without notice, no heartbeats are required, and the hooks run with the
+		/// default gas limit.
+		#[allow(deprecated)]
+		fn migrate(self) -> ServiceBlueprint<C> {
+			let jobs = self
+				.jobs
+				.into_iter()
+				.map(|job| JobDefinition {
+					metadata: job.metadata,
+					params: job.params,
+					result: job.result,
+					verifier: job.verifier,
+					result_policy: Default::default(),
+					response_window: None,
+					fee: None,
+				})
+				.collect::<Vec<_>>();
+			ServiceBlueprint {
+				metadata: self.metadata,
+				// the number of jobs is unchanged, so they still fit.
+				jobs: BoundedVec::truncate_from(jobs),
+				registration_hook: self.registration_hook,
+				registration_params: self.registration_params,
+				request_hook: self.request_hook,
+				request_params: self.request_params,
+				manager: self.manager,
+				gadget: self.gadget,
+				exit_notice_period: 0,
+				heartbeat_interval: 0,
+				hook_gas_limit: 0,
+			}
+		}
+	}
+
+	/// Migrates the storage from the version 0 to the version 1, see [`MigrateV0ToV1`].
+	pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let now = frame_system::Pallet::<T>::block_number();
+			let (mut reads, mut writes) = (1u64, 0u64);
+
+			// Every blueprint is at its first revision, which starts the revision history.
+			Blueprints::<T>::translate::<(T::AccountId, OldServiceBlueprint<T::Constraints>), _>(
+				|blueprint_id, (owner, old)| {
+					let blueprint = old.migrate();
+					BlueprintRevisions::<T>::insert(blueprint_id, 0, &blueprint);
+					reads.saturating_inc();
+					writes.saturating_accrue(2);
+					Some((owner, blueprint))
+				},
+			);
+
+			// The pending requests get the full request timeout from now on, and nothing was
+			// escrowed for them.
+			let expiry = now.saturating_add(T::ServiceRequestTimeout::get());
+			ServiceRequests::<T>::translate::<OldServiceRequestOf<T>, _>(|request_id, old| {
+				Pallet::<T>::schedule_service_request_expiry(request_id, expiry);
+				reads.saturating_accrue(2);
+				writes.saturating_accrue(3);
+				Some(ServiceRequest {
+					blueprint: old.blueprint,
+					blueprint_revision: 0,
+					owner: old.owner,
+					permitted_callers: old.permitted_callers,
+					assets: old.assets,
+					ttl: old.ttl,
+					expiry,
+					args: old.args,
+					operators_with_approval_state: old.operators_with_approval_state,
+					caller_policies: Default::default(),
+				})
+			});
+
+			// The start of the running services is not known, so their lifetime starts now, and
+			// their escrow is empty.
+			Instances::<T>::translate::<OldServiceOf<T>, _>(|service_id, old| {
+				let end_block = now.saturating_add(old.ttl);
+				Pallet::<T>::schedule_service_expiry(service_id, end_block);
+				ServiceEscrows::<T>::insert(
+					service_id,
+					ServiceEscrow { value: Zero::zero(), paid_out: Zero::zero() },
+				);
+				reads.saturating_accrue(2);
+				writes.saturating_accrue(4);
+				Some(Service {
+					id: old.id,
+					blueprint: old.blueprint,
+					blueprint_revision: 0,
+					owner: old.owner,
+					permitted_callers: old.permitted_callers,
+					operators: old.operators,
+					assets: old.assets,
+					ttl: old.ttl,
+					start_block: now,
+					end_block,
+					caller_policies: Default::default(),
+				})
+			});
+
+			// The callers of the job calls are not known, so the calls are attributed to the
+			// owners of their services, who can acknowledge their results. The calls of the
+			// services that are gone are dropped.
+			JobCalls::<T>::translate::<OldJobCall<T::Constraints, T::AccountId>, _>(
+				|service_id, _call_id, old| {
+					reads.saturating_accrue(2);
+					writes.saturating_inc();
+					let owner = Instances::<T>::get(service_id).ok()?.owner;
+					Some(JobCall {
+						service_id: old.service_id,
+						job: old.job,
+						args: old.args,
+						caller: owner,
+					})
+				},
+			);
+
+			// The slashes of the delegators were computed with one entry per bond to the
+			// operator, in the order of the bonds, so each entry gets the asset of the next bond
+			// of its delegator. The entries of the bonds that are gone since are dropped.
+			UnappliedSlashes::<T>::translate::<OldUnappliedSlash<T::AccountId, BalanceOf<T>>, _>(
+				|_era, _index, old| {
+					let mut bonds =
+						T::OperatorDelegationManager::get_delegators_for_operator(&old.operator)
+							.into_iter()
+							.map(Some)
+							.collect::<Vec<_>>();
+					let others = old
+						.others
+						.into_iter()
+						.filter_map(|(delegator, amount)| {
+							let bond = bonds.iter_mut().find(
+								|bond| matches!(bond, Some((bonded, ..)) if *bonded == delegator),
+							)?;
+							let (_, _, asset_id) = bond.take()?;
+							Some((delegator, asset_id, amount))
+						})
+						.collect::<Vec<_>>();
+					reads.saturating_accrue(2);
+					writes.saturating_inc();
+					Some(UnappliedSlash {
+						service_id: old.service_id,
+						operator: old.operator,
+						own: old.own,
+						others,
+						reporters: old.reporters,
+						payout: old.payout,
+					})
+				},
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let counts = (
+				Blueprints::<T>::iter_keys().count() as u64,
+				ServiceRequests::<T>::iter_keys().count() as u64,
+				Instances::<T>::iter_keys().count() as u64,
+			);
+			Ok(counts.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (blueprints, requests, services) = <(u64, u64, u64)>::decode(&mut &state[..])
+				.map_err(|_| "the pre-upgrade state does not decode")?;
+			// the iterators skip the values that fail to decode.
+			ensure!(
+				Blueprints::<T>::iter_values().count() as u64 == blueprints,
+				"a blueprint did not migrate"
+			);
+			ensure!(
+				ServiceRequests::<T>::iter_values().count() as u64 == requests,
+				"a service request did not migrate"
+			);
+			ensure!(
+				Instances::<T>::iter_values().count() as u64 == services,
+				"a service did not migrate"
+			);
+			for blueprint_id in Blueprints::<T>::iter_keys() {
+				ensure!(
+					BlueprintRevisions::<T>::contains_key(blueprint_id, 0),
+					"a blueprint has no first revision"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the storage from the version 0 to the version 1, only if the pallet is at the
+	/// version 0.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -23,7 +23,7 @@ use frame_election_provider_support::{
 use frame_support::derive_impl;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU128, ConstU32, OneSessionHandler},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, OneSessionHandler},
 };
 use mock_evm::MockedEvmRunner;
 use pallet_evm::GasWeightMapping;
@@ -31,10 +31,12 @@ use pallet_session::historical as pallet_session_historical;
 use sp_core::{sr25519, H160};
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt, KeystorePtr};
 use sp_runtime::{
-	testing::UintAuthorityId,
+	testing::{TestXt, UintAuthorityId},
 	traits::{ConvertInto, IdentityLookup},
 	AccountId32, BuildStorage, Perbill,
 };
+use tangle_primitives::services::{Field, OperatorPreferences};
+use types::ConstraintsOf;
 
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -47,7 +49,7 @@ impl frame_system::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type Nonce = Nonce;
 	type RuntimeCall = RuntimeCall;
@@ -203,6 +205,16 @@ impl pallet_staking::Config for Runtime {
 	type DisablingStrategy = pallet_staking::UpToLimitDisablingStrategy;
 }
 
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
 parameter_types! {
 	pub const ServicesEVMAddress: H160 = H160([0x11; 20]);
 }
@@ -238,6 +250,33 @@ impl EvmAddressMapping<AccountId> for PalletEVMAddressMapping {
 
 pub type AssetId = u32;
 
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type AssetIdParameter = AssetId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<10>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+}
+
+parameter_types! {
+	pub static CurrentRound: tangle_primitives::types::RoundIndex = 0;
+	pub static AppliedSlashes: Vec<(AccountId, Balance, Vec<(AccountId, AssetId, Balance)>)> =
+		Vec::new();
+}
+
 pub struct MockDelegationManager;
 impl tangle_primitives::traits::MultiAssetDelegationInfo<AccountId, Balance>
 	for MockDelegationManager
@@ -245,7 +284,7 @@ impl tangle_primitives::traits::MultiAssetDelegationInfo<AccountId, Balance>
 	type AssetId = AssetId;
 
 	fn get_current_round() -> tangle_primitives::types::RoundIndex {
-		Default::default()
+		CurrentRound::get()
 	}
 
 	fn is_operator(_operator: &AccountId) -> bool {
@@ -280,6 +319,15 @@ impl tangle_primitives::traits::MultiAssetDelegationInfo<AccountId, Balance>
 	) -> Vec<(AccountId, Balance, Self::AssetId)> {
 		Default::default()
 	}
+
+	fn slash_operator(
+		operator: &AccountId,
+		own: Balance,
+		others: &[(AccountId, Self::AssetId, Balance)],
+	) -> Balance {
+		AppliedSlashes::mutate(|slashes| slashes.push((operator.clone(), own, others.to_vec())));
+		others.iter().fold(own, |acc, (_, _, amount)| acc + amount)
+	}
 }
 
 parameter_types! {
@@ -368,10 +416,66 @@ parameter_types! {
 	pub const SlashDeferDuration: u32 = 7;
 }
 
+parameter_types! {
+	pub const ServiceRequestTimeout: u64 = 10;
+	pub const MaxJobBatchSize: u32 = 10;
+	pub const JobRetentionPeriod: u64 = 20;
+	pub const MaxHookGasLimit: u64 = 1_000_000;
+	pub const MaxBatchHookGas: u64 = 10_000_000;
+	pub const PermissiveManagerId: u32 = 0;
+	pub const PermissiveManagerFee: Balance = 100;
+	pub PermissiveManagerBeneficiary: AccountId = mock_pub_key(100);
+	pub const RecordingManagerId: u32 = 1;
+	pub const MaxVerifyingKeyLength: u32 = 1024;
+}
+
+type PermissiveManager =
+	PermissiveWithFee<PermissiveManagerId, PermissiveManagerFee, PermissiveManagerBeneficiary>;
+
+thread_local! {
+	/// The sizes of the batches of job calls and results the native manager was notified of.
+	pub static NATIVE_MANAGER_BATCHES: std::cell::RefCell<Vec<usize>> = Default::default();
+}
+
+/// A native manager of the tests, which allows every action and records the batches it is
+/// notified of.
+pub struct RecordingManager;
+
+impl traits::BlueprintServiceManager<Runtime> for RecordingManager {
+	fn exists(manager: u32) -> bool {
+		manager == RecordingManagerId::get()
+	}
+
+	fn on_job_call_batch(
+		_manager: u32,
+		_service_id: u64,
+		calls: &[(u8, u64, &[Field<ConstraintsOf<Runtime>, AccountId>])],
+	) -> Result<(bool, Weight), DispatchError> {
+		NATIVE_MANAGER_BATCHES.with(|batches| batches.borrow_mut().push(calls.len()));
+		Ok((true, Weight::zero()))
+	}
+
+	fn on_job_result_batch(
+		_manager: u32,
+		_service_id: u64,
+		_preferences: &OperatorPreferences,
+		results: &[(
+			u8,
+			u64,
+			&[Field<ConstraintsOf<Runtime>, AccountId>],
+			&[Field<ConstraintsOf<Runtime>, AccountId>],
+		)],
+	) -> Result<(bool, Weight), DispatchError> {
+		NATIVE_MANAGER_BATCHES.with(|batches| batches.borrow_mut().push(results.len()));
+		Ok((true, Weight::zero()))
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Currency = Balances;
+	type Fungibles = Assets;
 	type PalletEVMAddress = ServicesEVMAddress;
 	type AssetId = AssetId;
 	type EvmRunner = MockedEvmRunner;
@@ -401,6 +505,13 @@ impl Config for Runtime {
 	type OperatorDelegationManager = MockDelegationManager;
 	type SlashDeferDuration = SlashDeferDuration;
 	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type ServiceRequestTimeout = ServiceRequestTimeout;
+	type MaxJobBatchSize = MaxJobBatchSize;
+	type JobRetentionPeriod = JobRetentionPeriod;
+	type MaxHookGasLimit = MaxHookGasLimit;
+	type MaxBatchHookGas = MaxBatchHookGas;
+	type MaxVerifyingKeyLength = MaxVerifyingKeyLength;
+	type NativeManager = (PermissiveManager, RecordingManager);
 	type WeightInfo = ();
 }
 
@@ -412,6 +523,7 @@ construct_runtime!(
 		System: frame_system,
 		Timestamp: pallet_timestamp,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Services: pallet_services,
 		EVM: pallet_evm,
 		Ethereum: pallet_ethereum,
@@ -0,0 +1,199 @@
+use super::*;
+use crate::types::MaxOperatorsPerServiceOf;
+use sp_runtime::traits::Saturating;
+use tangle_primitives::{
+	services::{ApprovalState, OperatorChangeRequest, Service},
+	MultiAssetDelegationInfo,
+};
+
+type OperatorChangeRequestOf<T> =
+	OperatorChangeRequest<<T as Config>::Constraints, <T as frame_system::Config>::AccountId>;
+
+type ServiceOf<T> = Service<
+	<T as Config>::Constraints,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as Config>::AssetId,
+>;
+
+impl<T: Config> Pallet<T> {
+	/// Ensures an operator change can be applied to a service: the joining operators are not
+	/// serving it yet, are active and registered on the revision of the blueprint the service is
+	/// pinned to, the leaving operators are serving it, and the service keeps between one and
+	/// the maximum number of operators.
+	pub(crate) fn ensure_operator_change_valid(
+		service: &ServiceOf<T>,
+		joining: &[T::AccountId],
+		leaving: &[T::AccountId],
+	) -> DispatchResult {
+		let is_operator = |who: &T::AccountId| service.operators.iter().any(|(v, _)| v == who);
+		ensure!(
+			joining.iter().all(|v| !is_operator(v)) && leaving.iter().all(is_operator),
+			Error::<T>::InvalidOperatorChange
+		);
+		if !joining.is_empty() {
+			Self::ensure_blueprint_active(service.blueprint)?;
+		}
+		for operator in joining {
+			ensure!(
+				Operators::<T>::contains_key(service.blueprint, operator),
+				Error::<T>::NotRegistered
+			);
+			ensure!(
+				T::OperatorDelegationManager::is_operator_active(operator),
+				Error::<T>::OperatorNotActive
+			);
+			ensure!(
+				Self::operator_blueprint_revision(service.blueprint, operator) ==
+					service.blueprint_revision,
+				Error::<T>::OperatorNotOnServiceRevision
+			);
+		}
+
+		let operators = service
+			.operators
+			.len()
+			.saturating_sub(leaving.len())
+			.saturating_add(joining.len());
+		ensure!(operators > 0, Error::<T>::LastServiceOperator);
+		ensure!(
+			operators <= MaxOperatorsPerServiceOf::<T>::get() as usize,
+			Error::<T>::MaxServiceProvidersExceeded
+		);
+		Ok(())
+	}
+
+	/// Applies an approved operator change to a running service.
+	///
+	/// The leaving operators are removed first, then the value of the service vested so far is
+	/// paid out to the current operators, so that the joining operators only share the value
+	/// vesting from now on.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the operator hooks.
+	pub(crate) fn apply_operator_change(
+		service_id: u64,
+		change: OperatorChangeRequestOf<T>,
+	) -> Result<Weight, DispatchError> {
+		let mut weight = Weight::zero();
+		for operator in &change.leaving {
+			// the operator might have been leaving the service already.
+			if let Some(exit_block) = OperatorExits::<T>::get(service_id, operator) {
+				OperatorExitSchedule::<T>::remove(exit_block, (service_id, operator.clone()));
+			}
+			weight.saturating_accrue(Self::remove_service_operator(service_id, operator));
+		}
+
+		let mut service = Self::services(service_id)?;
+		let blueprint = Self::service_blueprint(&service)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::payout_service_escrow(&service, now)?;
+		for (operator, state) in change.joining {
+			let ApprovalState::Approved { restaking_percent } = state else {
+				// N.B: this should not happen, as the change is applied once all approved.
+				continue;
+			};
+			let preferences = Operators::<T>::get(service.blueprint, &operator)?;
+			OperatorsProfile::<T>::try_mutate_exists(&operator, |profile| {
+				profile
+					.as_mut()
+					.and_then(|p| p.services.try_insert(service_id).ok())
+					.ok_or(Error::<T>::NotRegistered)
+			})?;
+			service
+				.operators
+				.try_push((operator.clone(), restaking_percent))
+				.map_err(|_| Error::<T>::MaxServiceProvidersExceeded)?;
+			Self::record_heartbeat(service_id, &operator, blueprint.heartbeat_interval, now);
+
+			match Self::on_operator_joined_hook(
+				&blueprint,
+				service_id,
+				&preferences,
+				restaking_percent.deconstruct(),
+			) {
+				Ok((_, hook_weight)) => weight.saturating_accrue(hook_weight),
+				Err(e) => {
+					log::error!(
+						target: "services",
+						"Join hook of the service #{} failed: {:?}",
+						service_id,
+						e,
+					);
+					weight.saturating_accrue(e.post_info.actual_weight.unwrap_or_default());
+				},
+			}
+
+			Self::deposit_event(Event::OperatorJoinedService {
+				operator,
+				service_id,
+				blueprint_id: service.blueprint,
+				restaking_percent,
+			});
+		}
+		Instances::<T>::insert(service_id, service);
+		Ok(weight)
+	}
+
+	/// Removes an operator from a service, after paying out the value of the service vested so
+	/// far, and notifies the blueprint manager.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the exit hook.
+	pub(crate) fn remove_service_operator(service_id: u64, operator: &T::AccountId) -> Weight {
+		OperatorExits::<T>::remove(service_id, operator);
+		let Ok(mut service) = Instances::<T>::get(service_id) else {
+			// the service got terminated in the meantime.
+			return Weight::zero();
+		};
+		if !service.operators.iter().any(|(v, _)| v == operator) {
+			// the operator already left the service through an operator change.
+			return Weight::zero();
+		}
+
+		// the leaving operator gets its share of the value vested while it was serving.
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Err(e) = Self::payout_service_escrow(&service, now) {
+			log::error!(
+				target: "services",
+				"Failed to pay out the service #{} before {:?} left: {:?}",
+				service_id,
+				operator,
+				e,
+			);
+		}
+
+		service.operators.retain(|(v, _)| v != operator);
+		Instances::<T>::insert(service_id, &service);
+		Self::clear_operator_heartbeat(service_id, operator);
+		OperatorsProfile::<T>::mutate_exists(operator, |profile| {
+			if let Some(profile) = profile {
+				profile.services.remove(&service_id);
+			}
+		});
+
+		let hook = Self::service_blueprint(&service).map_err(Into::into).and_then(|blueprint| {
+			let preferences = Operators::<T>::get(service.blueprint, operator)?;
+			Self::on_operator_left_hook(&blueprint, service_id, &preferences)
+		});
+		let weight = match hook {
+			Ok((_, weight)) => weight,
+			Err(e) => {
+				log::error!(
+					target: "services",
+					"Exit hook of the service #{} failed: {:?}",
+					service_id,
+					e,
+				);
+				e.post_info.actual_weight.unwrap_or_default()
+			},
+		};
+
+		Self::deposit_event(Event::OperatorLeftService {
+			operator: operator.clone(),
+			service_id,
+			blueprint_id: service.blueprint,
+		});
+		weight
+	}
+}
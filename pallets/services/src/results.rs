@@ -0,0 +1,52 @@
+use super::*;
+use crate::types::{MaxFieldsOf, MaxOperatorsPerServiceOf};
+use sp_std::vec::Vec;
+use tangle_primitives::services::{Field, JobCall, JobCallResult, JobDefinition};
+
+impl<T: Config> Pallet<T> {
+	/// Finalizes the result of a job call once the submitted results meet the result policy of
+	/// the job, storing the canonical result in [`JobResults`] and paying the fee of the call
+	/// to the operators that submitted it.
+	///
+	/// # Parameters
+	/// * `operators` - The number of operators of the service.
+	/// * `job_def` - The definition of the called job.
+	/// * `job_call` - The job call.
+	/// * `call_id` - The ID of the job call.
+	/// * `submissions` - The results submitted so far, ordered from the earliest to the latest.
+	pub(crate) fn finalize_job_result(
+		operators: usize,
+		job_def: &JobDefinition<T::Constraints>,
+		job_call: &JobCall<T::Constraints, T::AccountId>,
+		call_id: u64,
+		submissions: BoundedVec<
+			(T::AccountId, BoundedVec<Field<T::Constraints, T::AccountId>, MaxFieldsOf<T>>),
+			MaxOperatorsPerServiceOf<T>,
+		>,
+	) {
+		let results = submissions.iter().map(|(_, result)| result).collect::<Vec<_>>();
+		let Some(index) = job_def.result_policy.canonical_result(operators, &results) else {
+			return;
+		};
+		let result = results[index].clone();
+		let accepted = submissions
+			.iter()
+			.filter(|(_, submitted)| submitted == &result)
+			.map(|(operator, _)| operator.clone())
+			.collect::<Vec<_>>();
+
+		JobResults::<T>::insert(
+			job_call.service_id,
+			call_id,
+			JobCallResult { service_id: job_call.service_id, call_id, result: result.clone() },
+		);
+		Self::schedule_job_call_pruning(job_call.service_id, call_id);
+		Self::deposit_event(Event::JobResultFinalized {
+			service_id: job_call.service_id,
+			call_id,
+			job: job_call.job,
+			result: result.into_inner(),
+		});
+		Self::pay_job_call_fee(job_call.service_id, call_id, &accepted);
+	}
+}
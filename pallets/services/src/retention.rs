@@ -0,0 +1,182 @@
+use super::*;
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Index a job call that got a final result or timed out, so that its data gets pruned once
+	/// the `JobRetentionPeriod` is over.
+	pub(crate) fn schedule_job_call_pruning(service_id: u64, call_id: u64) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let prune_at = now.saturating_add(T::JobRetentionPeriod::get());
+		JobPruningSchedule::<T>::insert(prune_at, (service_id, call_id), ());
+		JobPruningCursor::<T>::mutate(|cursor| match cursor {
+			Some(next) if *next <= prune_at => {},
+			_ => *cursor = Some(prune_at),
+		});
+	}
+
+	/// Prunes the data of the job calls whose retention period ended at or before `now`.
+	///
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn prune_job_calls(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// Removing the job call, its result, its submissions, its timeout record and its
+		// deadline, and refunding the fee it might still escrow.
+		let per_call = T::DbWeight::get().reads_writes(4, 10);
+		let (consumed, cursor) = Self::sweep_expiries(
+			now,
+			remaining_weight,
+			per_call,
+			JobPruningCursor::<T>::get(),
+			|block, limit| JobPruningSchedule::<T>::iter_key_prefix(block).take(limit).collect(),
+			|block, (service_id, call_id)| {
+				JobPruningSchedule::<T>::remove(block, (service_id, call_id));
+				Self::prune_job_call(service_id, call_id);
+				per_call
+			},
+		);
+		if let Some(cursor) = cursor {
+			JobPruningCursor::<T>::put(cursor);
+		}
+		consumed
+	}
+
+	/// Removes the data of a job call, along with its deadline and the fee it might still
+	/// escrow.
+	pub(crate) fn prune_job_call(service_id: u64, call_id: u64) {
+		Self::refund_job_call_fee(service_id, call_id);
+		JobCalls::<T>::remove(service_id, call_id);
+		JobResults::<T>::remove(service_id, call_id);
+		JobResultSubmissions::<T>::remove(service_id, call_id);
+		TimedOutJobCalls::<T>::remove(service_id, call_id);
+		if let Some(deadline) = JobCallResponseDeadlines::<T>::take(service_id, call_id) {
+			JobCallDeadlines::<T>::remove(deadline, (service_id, call_id));
+		}
+	}
+
+	/// Queues the removal of the job data of a terminated service, which happens in the
+	/// background as the `on_idle` weight allows.
+	pub(crate) fn queue_service_cleanup(service_id: u64) {
+		ServiceGroupKeys::<T>::remove(service_id);
+		ServiceCleanupQueue::<T>::insert(service_id, ());
+	}
+
+	/// Removes the job data of the terminated services queued for cleanup.
+	///
+	/// The service being cleaned up is kept in the `ServiceCleanupCursor`, so that the cleanup
+	/// continues with it on the next call once the `remaining_weight` is exhausted.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn clean_up_terminated_services(remaining_weight: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		// Reading and updating the cursor.
+		let mut consumed = db.reads_writes(1, 1);
+		// Reading the next queued service.
+		let per_service = db.reads(1);
+
+		if remaining_weight.any_lt(consumed) {
+			return Weight::zero();
+		}
+
+		let mut cursor = ServiceCleanupCursor::<T>::get();
+		while remaining_weight.all_gte(consumed.saturating_add(per_service)) {
+			consumed.saturating_accrue(per_service);
+			let Some(service_id) = cursor.or_else(|| ServiceCleanupQueue::<T>::iter_keys().next())
+			else {
+				break;
+			};
+
+			let (weight, cleared) =
+				Self::clear_service_job_data(service_id, remaining_weight.saturating_sub(consumed));
+			consumed.saturating_accrue(weight);
+			if !cleared {
+				cursor = Some(service_id);
+				break;
+			}
+			ServiceCleanupQueue::<T>::remove(service_id);
+			consumed.saturating_accrue(db.writes(1));
+			cursor = None;
+		}
+		ServiceCleanupCursor::<T>::set(cursor);
+		consumed
+	}
+
+	/// Removes the data of the job calls and the heartbeats of a terminated service, refunding
+	/// the fees still escrowed by its job calls.
+	///
+	/// At most as many entries as the `remaining_weight` allows are removed.
+	///
+	/// # Returns
+	/// * `(Weight, bool)` - The weight consumed by the removals, and whether all the data of
+	///   the service got removed.
+	pub(crate) fn clear_service_job_data(
+		service_id: u64,
+		remaining_weight: Weight,
+	) -> (Weight, bool) {
+		let db = T::DbWeight::get();
+		let limit = |consumed: Weight, per_entry: Weight| {
+			remaining_weight
+				.saturating_sub(consumed)
+				.checked_div_per_component(&per_entry)
+				.map_or(u32::MAX, |n| u32::try_from(n).unwrap_or(u32::MAX))
+		};
+
+		// Reading and removing the fee, and updating both balances.
+		let per_fee = db.reads_writes(3, 3);
+		let mut consumed = Weight::zero();
+		let refunds = limit(consumed, per_fee) as usize;
+		// Fetch one extra fee, so that we know whether all of them got refunded.
+		let fees = JobCallFees::<T>::iter_key_prefix(service_id)
+			.take(refunds.saturating_add(1))
+			.collect::<Vec<_>>();
+		for call_id in fees.iter().take(refunds) {
+			Self::refund_job_call_fee(service_id, *call_id);
+			consumed.saturating_accrue(per_fee);
+		}
+		if fees.len() > refunds {
+			return (consumed, false);
+		}
+
+		// Reading and removing the deadline, and removing its index.
+		let per_deadline = db.reads_writes(1, 2);
+		let deadlines = limit(consumed, per_deadline);
+		let mut drained = 0;
+		for (call_id, deadline) in
+			JobCallResponseDeadlines::<T>::drain_prefix(service_id).take(deadlines as usize)
+		{
+			JobCallDeadlines::<T>::remove(deadline, (service_id, call_id));
+			consumed.saturating_accrue(per_deadline);
+			drained += 1;
+		}
+		if drained == deadlines &&
+			JobCallResponseDeadlines::<T>::iter_key_prefix(service_id).next().is_some()
+		{
+			return (consumed, false);
+		}
+
+		let clears: [fn(u64, u32) -> sp_io::MultiRemovalResults; 7] = [
+			|id, limit| JobCalls::<T>::clear_prefix(id, limit, None),
+			|id, limit| JobResults::<T>::clear_prefix(id, limit, None),
+			|id, limit| JobResultSubmissions::<T>::clear_prefix(id, limit, None),
+			|id, limit| TimedOutJobCalls::<T>::clear_prefix(id, limit, None),
+			|id, limit| MissedJobCalls::<T>::clear_prefix(id, limit, None),
+			|id, limit| OperatorHeartbeats::<T>::clear_prefix(id, limit, None),
+			|id, limit| UnresponsiveOperators::<T>::clear_prefix(id, limit, None),
+		];
+		// Reading and removing an entry.
+		let per_entry = db.reads_writes(1, 1);
+		for clear in clears {
+			let removed = clear(service_id, limit(consumed, per_entry));
+			consumed
+				.saturating_accrue(db.reads_writes(removed.loops.into(), removed.unique.into()));
+			if removed.maybe_cursor.is_some() {
+				return (consumed, false);
+			}
+		}
+		(consumed, true)
+	}
+}
@@ -1,11 +1,15 @@
 use itertools::Itertools;
-use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use sp_std::vec::Vec;
 
 use tangle_primitives::services::*;
 
 use super::*;
+use crate::types::BalanceOf;
 
 impl<T: Config> Pallet<T> {
+	/// The maximum number of items returned by a page of the paginated queries.
+	pub const MAX_PAGE_SIZE: u32 = 100;
+
 	#[allow(clippy::type_complexity)]
 	pub fn services_with_blueprints_by_operator(
 		operator: T::AccountId,
@@ -19,26 +23,203 @@ impl<T: Config> Pallet<T> {
 			.services
 			.into_iter()
 			.flat_map(Self::services)
-			.chunk_by(|service| service.blueprint);
+			.chunk_by(|service| (service.blueprint, service.blueprint_revision));
 
-		let blueprints = profile
-			.blueprints
-			.into_iter()
-			.flat_map(|id| Self::blueprints(id).map(|(_, b)| (id, b)))
-			.collect::<BTreeMap<_, _>>();
-
-		for (blueprint_id, services) in services.into_iter() {
-			match blueprints.get(&blueprint_id) {
-				Some(blueprint) => {
-					result.push(RpcServicesWithBlueprint {
-						blueprint_id,
-						blueprint: blueprint.clone(),
-						services: services.collect(),
-					});
-				},
-				None => return Err(Error::<T>::BlueprintNotFound),
+		for ((blueprint_id, revision), services) in services.into_iter() {
+			if !profile.blueprints.contains(&blueprint_id) {
+				return Err(Error::<T>::BlueprintNotFound);
 			}
+			// services are pinned to the blueprint revision they were instantiated with.
+			let blueprint = Self::blueprint_at_revision(blueprint_id, revision)?;
+			result.push(RpcServicesWithBlueprint {
+				blueprint_id,
+				blueprint,
+				state: Self::blueprint_state(blueprint_id),
+				services: services.collect(),
+			});
 		}
 		Ok(result)
 	}
+
+	/// The value escrowed by a pending service request.
+	pub fn service_request_escrow(request_id: u64) -> Result<BalanceOf<T>, Error<T>> {
+		// ensure the request is still pending.
+		Self::service_requests(request_id)?;
+		Ok(Self::request_escrow(request_id))
+	}
+
+	/// The value escrowed by an operator on its registration for a service blueprint.
+	pub fn operator_registration_escrow(
+		blueprint_id: u64,
+		operator: T::AccountId,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		// ensure the operator is still registered.
+		Operators::<T>::get(blueprint_id, &operator)?;
+		Ok(Self::registration_escrow(blueprint_id, operator))
+	}
+
+	/// All the revisions of a service blueprint, ordered from the oldest to the latest.
+	pub fn blueprint_revisions_of(
+		blueprint_id: u64,
+	) -> Result<Vec<(u32, ServiceBlueprint<T::Constraints>)>, Error<T>> {
+		// ensure the blueprint exists.
+		Self::blueprints(blueprint_id)?;
+		let mut revisions = BlueprintRevisions::<T>::iter_prefix(blueprint_id).collect::<Vec<_>>();
+		revisions.sort_by_key(|(revision, _)| *revision);
+		Ok(revisions)
+	}
+
+	/// A page of the service blueprints ordered by ID, optionally only the ones owned by
+	/// `owner`.
+	pub fn blueprints_page(
+		owner: Option<T::AccountId>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<(u64, T::AccountId, ServiceBlueprint<T::Constraints>)> {
+		(0..Self::next_blueprint_id())
+			.filter_map(|id| Self::blueprints(id).ok().map(|(o, blueprint)| (id, o, blueprint)))
+			.filter(|(_, o, _)| owner.as_ref().map_or(true, |owner| owner == o))
+			.skip(offset as usize)
+			.take(limit.min(Self::MAX_PAGE_SIZE) as usize)
+			.collect()
+	}
+
+	/// A page of the operators registered on a service blueprint, with their preferences.
+	pub fn blueprint_operators_page(
+		blueprint_id: u64,
+		offset: u32,
+		limit: u32,
+	) -> Result<Vec<(T::AccountId, OperatorPreferences)>, Error<T>> {
+		// ensure the blueprint exists.
+		Self::blueprints(blueprint_id)?;
+		Ok(Operators::<T>::iter_prefix(blueprint_id)
+			.skip(offset as usize)
+			.take(limit.min(Self::MAX_PAGE_SIZE) as usize)
+			.collect())
+	}
+
+	/// A page of the services owned by `owner`, ordered by ID.
+	#[allow(clippy::type_complexity)]
+	pub fn services_by_owner_page(
+		owner: T::AccountId,
+		offset: u32,
+		limit: u32,
+	) -> Vec<Service<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>> {
+		Self::user_services(owner)
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit.min(Self::MAX_PAGE_SIZE) as usize)
+			.flat_map(Self::services)
+			.collect()
+	}
+
+	/// A page of the pending service requests still waiting for the approval of `operator`,
+	/// ordered by ID.
+	///
+	/// All the pending requests are read and sorted to build any page, so the cost of the
+	/// query grows with the number of pending requests rather than with the page size.
+	#[allow(clippy::type_complexity)]
+	pub fn pending_requests_by_operator_page(
+		operator: T::AccountId,
+		offset: u32,
+		limit: u32,
+	) -> Vec<(u64, ServiceRequest<T::Constraints, T::AccountId, BlockNumberFor<T>, T::AssetId>)> {
+		let mut requests = ServiceRequests::<T>::iter()
+			.filter(|(_, request)| {
+				request
+					.operators_with_approval_state
+					.iter()
+					.any(|(v, state)| v == &operator && *state == ApprovalState::Pending)
+			})
+			.collect::<Vec<_>>();
+		requests.sort_by_key(|(request_id, _)| *request_id);
+		requests
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit.min(Self::MAX_PAGE_SIZE) as usize)
+			.collect()
+	}
+
+	/// A page of the job calls of a service ordered by ID, along with their final result if
+	/// they got one.
+	#[allow(clippy::type_complexity)]
+	pub fn job_calls_page(
+		service_id: u64,
+		offset: u32,
+		limit: u32,
+	) -> Result<
+		Vec<(
+			u64,
+			JobCall<T::Constraints, T::AccountId>,
+			Option<JobCallResult<T::Constraints, T::AccountId>>,
+		)>,
+		Error<T>,
+	> {
+		// ensure the service exists.
+		Self::services(service_id)?;
+		let mut calls = JobCalls::<T>::iter_prefix(service_id).collect::<Vec<_>>();
+		calls.sort_by_key(|(call_id, _)| *call_id);
+		Ok(calls
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit.min(Self::MAX_PAGE_SIZE) as usize)
+			.map(|(call_id, call)| (call_id, call, Self::job_results(service_id, call_id).ok()))
+			.collect())
+	}
+
+	/// A page of the unapplied slashes of `operator`, ordered by era and index.
+	///
+	/// All the unapplied slashes are read and sorted to build any page, so the cost of the
+	/// query grows with the number of unapplied slashes rather than with the page size.
+	pub fn unapplied_slashes_by_operator_page(
+		operator: T::AccountId,
+		offset: u32,
+		limit: u32,
+	) -> Vec<(u32, u32, UnappliedSlash<T::AccountId, BalanceOf<T>, T::AssetId>)> {
+		let mut slashes = UnappliedSlashes::<T>::iter()
+			.filter(|(_, _, slash)| slash.operator == operator)
+			.collect::<Vec<_>>();
+		slashes.sort_by_key(|(era, index, _)| (*era, *index));
+		slashes
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit.min(Self::MAX_PAGE_SIZE) as usize)
+			.collect()
+	}
+
+	/// Type checks the arguments of a job call against the parameters of the job, without
+	/// calling it.
+	///
+	/// Returns the full type check error the call would fail with, if any.
+	pub fn type_check_job_call(
+		service_id: u64,
+		job: u8,
+		args: Vec<Field<T::Constraints, T::AccountId>>,
+	) -> Result<Option<TypeCheckError>, Error<T>> {
+		let service = Self::services(service_id)?;
+		let blueprint = Self::service_blueprint(&service)?;
+		let job_def =
+			blueprint.jobs.get(usize::from(job)).ok_or(Error::<T>::JobDefinitionNotFound)?;
+		Ok(type_checker(&job_def.params, &args).err())
+	}
+
+	/// Type checks the result of a job call against the result of the job, without submitting
+	/// it.
+	///
+	/// Returns the full type check error the submission would fail with, if any.
+	pub fn type_check_job_result(
+		service_id: u64,
+		call_id: u64,
+		result: Vec<Field<T::Constraints, T::AccountId>>,
+	) -> Result<Option<TypeCheckError>, Error<T>> {
+		let service = Self::services(service_id)?;
+		let blueprint = Self::service_blueprint(&service)?;
+		let job_call = Self::job_calls(service_id, call_id)?;
+		let job_def = blueprint
+			.jobs
+			.get(usize::from(job_call.job))
+			.ok_or(Error::<T>::JobDefinitionNotFound)?;
+		let result = BoundedVec::try_from(result).map_err(|_| Error::<T>::MaxFieldsExceeded)?;
+		Ok(JobCallResult { service_id, call_id, result }.type_check(job_def).err())
+	}
 }
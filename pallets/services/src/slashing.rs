@@ -0,0 +1,122 @@
+use super::*;
+use crate::types::{BalanceOf, UnappliedSlash};
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+use tangle_primitives::MultiAssetDelegationInfo;
+
+impl<T: Config> Pallet<T> {
+	/// Applies the unapplied slashes whose defer duration has passed.
+	///
+	/// A slash computed in era `e` gets applied once the current round reaches
+	/// `e + SlashDeferDuration`, and it is then pruned from [`UnappliedSlashes`].
+	/// The processing stops as soon as the `remaining_weight` is exhausted, and continues
+	/// from where it stopped on the next call. A slash of more delegators than fit in the
+	/// `remaining_weight` gets applied in parts: the operator and the delegators that fit
+	/// are slashed, and the other delegators are left in the slash for the next call.
+	///
+	/// # Returns
+	/// * `Weight` - The weight consumed by the processing.
+	pub(crate) fn apply_deferred_slashes(remaining_weight: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		// Reading the current round and the cursor, then updating the cursor.
+		let mut consumed = db.reads_writes(2, 1);
+		if remaining_weight.any_lt(consumed) {
+			return Weight::zero();
+		}
+
+		let current_round = T::OperatorDelegationManager::get_current_round();
+		let Some(last_era) = current_round.checked_sub(T::SlashDeferDuration::get()) else {
+			return consumed;
+		};
+
+		let mut era = UnappliedSlashesCursor::<T>::get();
+		while era <= last_era {
+			// Reading the slashes of the era.
+			if remaining_weight.any_lt(consumed.saturating_add(db.reads(1))) {
+				break;
+			}
+			consumed.saturating_accrue(db.reads(1));
+
+			// Removing the slash, then updating the stake of the operator, and the stake of
+			// each delegator.
+			let per_slash = db.reads_writes(2, 3);
+			let per_stake = db.reads_writes(2, 2);
+
+			let mut due = Vec::new();
+			let mut partial = None;
+			let mut exhausted = true;
+			for (index, slash) in UnappliedSlashes::<T>::iter_prefix(era) {
+				let stakes = u64::try_from(slash.others.len()).unwrap_or(u64::MAX);
+				let weight = per_slash.saturating_add(per_stake.saturating_mul(stakes));
+				if remaining_weight.all_gte(consumed.saturating_add(weight)) {
+					consumed.saturating_accrue(weight);
+					due.push((index, slash));
+					continue;
+				}
+
+				// The slash does not fit, so only the delegators that fit get slashed now.
+				exhausted = false;
+				let mut fitting = 0;
+				let mut weight = per_slash.saturating_add(per_stake);
+				while fitting < slash.others.len() &&
+					remaining_weight.all_gte(consumed.saturating_add(weight))
+				{
+					fitting += 1;
+					weight.saturating_accrue(per_stake);
+				}
+				if fitting > 0 {
+					consumed.saturating_accrue(
+						per_slash.saturating_add(per_stake.saturating_mul(fitting as u64)),
+					);
+					partial = Some((index, slash, fitting));
+				}
+				break;
+			}
+
+			for (index, slash) in due {
+				UnappliedSlashes::<T>::remove(era, index);
+				Self::apply_slash(era, index, slash);
+			}
+
+			if let Some((index, mut slash, fitting)) = partial {
+				let rest = UnappliedSlash {
+					service_id: slash.service_id,
+					operator: slash.operator.clone(),
+					own: Zero::zero(),
+					others: slash.others.split_off(fitting),
+					reporters: slash.reporters.clone(),
+					payout: slash.payout,
+				};
+				UnappliedSlashes::<T>::insert(era, index, rest);
+				Self::apply_slash(era, index, slash);
+			}
+
+			// New slashes can still be added to the last era, so the cursor never goes past it.
+			if !exhausted || era == last_era {
+				break;
+			}
+			era = era.saturating_add(1);
+		}
+
+		UnappliedSlashesCursor::<T>::put(era);
+		consumed
+	}
+
+	/// Slashes the operator and its delegators by the amounts of an [`UnappliedSlash`].
+	fn apply_slash(
+		era: u32,
+		index: u32,
+		slash: UnappliedSlash<T::AccountId, BalanceOf<T>, T::AssetId>,
+	) {
+		let amount =
+			T::OperatorDelegationManager::slash_operator(&slash.operator, slash.own, &slash.others);
+
+		Self::deposit_event(Event::<T>::SlashApplied {
+			index,
+			operator: slash.operator,
+			amount,
+			service_id: slash.service_id,
+			era,
+		});
+	}
+}
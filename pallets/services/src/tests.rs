@@ -16,10 +16,19 @@
 use crate::types::ConstraintsOf;
 
 use super::*;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, dispatch::GetDispatchInfo, traits::Hooks};
 use mock::*;
-use sp_core::{bounded_vec, ecdsa, ByteArray};
-use sp_runtime::{KeyTypeId, Percent};
+use sp_core::{
+	bounded_vec, ecdsa,
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	ByteArray,
+};
+use sp_runtime::{
+	traits::ValidateUnsigned, transaction_validity::TransactionSource, KeyTypeId, Percent,
+};
 use tangle_primitives::services::*;
 use tangle_primitives::MultiAssetDelegationInfo;
 
@@ -80,6 +89,9 @@ fn cggmp21_blueprint() -> ServiceBlueprint<ConstraintsOf<Runtime>> {
 				params: bounded_vec![FieldType::Uint8],
 				result: bounded_vec![FieldType::Bytes],
 				verifier: JobResultVerifier::Evm(CGGMP21_BLUEPRINT),
+				result_policy: JobResultPolicy::First,
+				response_window: None,
+				fee: None,
 			},
 			JobDefinition {
 				metadata: JobMetadata { name: "sign".try_into().unwrap(), ..Default::default() },
@@ -87,6 +99,9 @@ fn cggmp21_blueprint() -> ServiceBlueprint<ConstraintsOf<Runtime>> {
 				result: bounded_vec![FieldType::Bytes],
 				#[allow(deprecated)]
 				verifier: JobResultVerifier::Evm(CGGMP21_BLUEPRINT),
+				result_policy: JobResultPolicy::First,
+				response_window: None,
+				fee: None,
 			},
 		],
 		registration_hook: ServiceRegistrationHook::Evm(CGGMP21_BLUEPRINT),
@@ -94,6 +109,9 @@ fn cggmp21_blueprint() -> ServiceBlueprint<ConstraintsOf<Runtime>> {
 		request_hook: ServiceRequestHook::Evm(CGGMP21_BLUEPRINT),
 		request_params: bounded_vec![],
 		gadget: Default::default(),
+		exit_notice_period: 0,
+		heartbeat_interval: 0,
+		hook_gas_limit: 0,
 	}
 }
 
@@ -283,16 +301,24 @@ fn unregister_from_blueprint() {
 		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
 
 		let bob = mock_pub_key(BOB);
+		let balance = Balances::free_balance(&bob);
 		assert_ok!(Services::register(
 			RuntimeOrigin::signed(bob.clone()),
 			0,
 			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
 			Default::default(),
-			0,
+			100,
 		));
+		assert_eq!(Balances::free_balance(&bob), balance - 100);
+		assert_eq!(Services::operator_registration_escrow(0, bob.clone()), Ok(100));
+
 		assert_ok!(Services::unregister(RuntimeOrigin::signed(bob.clone()), 0));
 		assert!(!Operators::<Runtime>::contains_key(0, &bob));
 
+		// The registration value should be refunded.
+		assert_eq!(Balances::free_balance(&bob), balance);
+		assert_eq!(Services::registration_escrow(0, &bob), 0);
+
 		// The blueprint should be removed from my blueprints in my profile.
 		let profile = OperatorsProfile::<Runtime>::get(bob.clone()).unwrap();
 		assert!(!profile.blueprints.contains(&0));
@@ -300,6 +326,7 @@ fn unregister_from_blueprint() {
 		assert_events(vec![RuntimeEvent::Services(crate::Event::Unregistered {
 			operator: bob,
 			blueprint_id: 0,
+			refunded: 100,
 		})]);
 
 		// try to deregister when not registered
@@ -353,6 +380,7 @@ fn request_service() {
 			vec![USDC, WETH],
 			100,
 			0,
+			Default::default(),
 		));
 
 		assert_eq!(ServiceRequests::<Runtime>::iter_keys().collect::<Vec<_>>().len(), 1);
@@ -452,6 +480,7 @@ fn request_service_with_no_assets() {
 				vec![], // no assets
 				100,
 				0,
+				Default::default(),
 			),
 			Error::<Runtime>::NoAssetsProvided
 		);
@@ -459,7 +488,7 @@ fn request_service_with_no_assets() {
 }
 
 #[test]
-fn job_calls() {
+fn request_service_expires_and_refunds() {
 	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
 		System::set_block_number(1);
 		let alice = mock_pub_key(ALICE);
@@ -473,207 +502,2468 @@ fn job_calls() {
 			Default::default(),
 			0,
 		));
-		let charlie = mock_pub_key(CHARLIE);
+		let eve = mock_pub_key(EVE);
+		let eve_balance = Balances::free_balance(&eve);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone()],
+			Default::default(),
+			vec![USDC, WETH],
+			100,
+			500,
+			Default::default(),
+		));
+		assert_eq!(Balances::free_balance(&eve), eve_balance - 500);
+		assert_eq!(Services::request_escrow(0), 500);
+
+		let expiry = Services::service_requests(0).unwrap().expiry;
+		assert_eq!(expiry, 1 + <Runtime as Config>::ServiceRequestTimeout::get());
+
+		// Nothing happens before the request expires.
+		System::set_block_number(expiry - 1);
+		Services::on_idle(expiry - 1, Weight::MAX);
+		assert!(ServiceRequests::<Runtime>::contains_key(0));
+
+		System::set_block_number(expiry);
+		Services::on_idle(expiry, Weight::MAX);
+
+		// The request is gone and the escrowed value is back with the owner.
+		assert!(!ServiceRequests::<Runtime>::contains_key(0));
+		assert_eq!(Services::request_escrow(0), 0);
+		assert_eq!(Balances::free_balance(&eve), eve_balance);
+
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceRequestExpired {
+			owner: eve,
+			request_id: 0,
+			blueprint_id: 0,
+			refunded: 500,
+		})]);
+
+		// An expired request can no longer be approved.
+		assert_err!(
+			Services::approve(RuntimeOrigin::signed(bob.clone()), 0, Percent::from_percent(10)),
+			Error::<Runtime>::ServiceRequestNotFound
+		);
+	});
+}
+
+#[test]
+fn service_expires_after_its_lifetime() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let Deployment { blueprint_id, service_id, .. } = deploy();
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+
+		let service = Services::services(service_id).unwrap();
+		assert_eq!(service.start_block, 1);
+		assert_eq!(service.end_block, 1 + service.ttl);
+
+		// The service is still alive right before its lifetime ends.
+		System::set_block_number(service.end_block - 1);
+		Services::on_idle(service.end_block - 1, Weight::MAX);
+		assert!(Instances::<Runtime>::contains_key(service_id));
+
+		System::set_block_number(service.end_block);
+		Services::on_idle(service.end_block, Weight::MAX);
+
+		assert!(!Instances::<Runtime>::contains_key(service_id));
+		assert!(!Services::user_services(&eve).contains(&service_id));
+		let profile = OperatorsProfile::<Runtime>::get(&bob).unwrap();
+		assert!(!profile.services.contains(&service_id));
+
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceTerminated {
+			owner: eve,
+			service_id,
+			blueprint_id,
+			reason: ServiceTerminationReason::Expired,
+			refunded: 0,
+		})]);
+	});
+}
+
+#[test]
+fn reject_service_request_refunds_value() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint = cggmp21_blueprint();
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+		let bob = mock_pub_key(BOB);
 		assert_ok!(Services::register(
-			RuntimeOrigin::signed(charlie.clone()),
+			RuntimeOrigin::signed(bob.clone()),
 			0,
 			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
 			Default::default(),
 			0,
 		));
-		let dave = mock_pub_key(DAVE);
+		let eve = mock_pub_key(EVE);
+		let eve_balance = Balances::free_balance(&eve);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone()],
+			Default::default(),
+			vec![USDC, WETH],
+			100,
+			500,
+			Default::default(),
+		));
+		assert_eq!(Services::service_request_escrow(0).unwrap(), 500);
+
+		assert_ok!(Services::reject(RuntimeOrigin::signed(bob.clone()), 0));
+
+		assert!(!ServiceRequests::<Runtime>::contains_key(0));
+		assert_eq!(Balances::free_balance(&eve), eve_balance);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceRequestRejected {
+			operator: bob,
+			request_id: 0,
+			blueprint_id: 0,
+			refunded: 500,
+		})]);
+	});
+}
+
+#[test]
+fn cancel_service_request_refunds_value() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint = cggmp21_blueprint();
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+		let bob = mock_pub_key(BOB);
 		assert_ok!(Services::register(
-			RuntimeOrigin::signed(dave.clone()),
+			RuntimeOrigin::signed(bob.clone()),
 			0,
 			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
 			Default::default(),
 			0,
 		));
-
 		let eve = mock_pub_key(EVE);
+		let eve_balance = Balances::free_balance(&eve);
 		assert_ok!(Services::request(
 			RuntimeOrigin::signed(eve.clone()),
 			0,
 			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
+			vec![bob.clone()],
 			Default::default(),
 			vec![WETH],
 			100,
-			0,
+			500,
+			Default::default(),
 		));
 
-		assert_eq!(ServiceRequests::<Runtime>::iter_keys().collect::<Vec<_>>().len(), 1);
+		// only the owner can cancel the request.
+		assert_err!(
+			Services::cancel_request(RuntimeOrigin::signed(bob.clone()), 0),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Services::cancel_request(RuntimeOrigin::signed(eve.clone()), 0));
+
+		assert!(!ServiceRequests::<Runtime>::contains_key(0));
+		assert_eq!(Balances::free_balance(&eve), eve_balance);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceRequestCancelled {
+			owner: eve.clone(),
+			request_id: 0,
+			blueprint_id: 0,
+			refunded: 500,
+		})]);
+
+		// the operator can not approve a cancelled request.
+		assert_err!(
+			Services::approve(RuntimeOrigin::signed(bob), 0, Percent::from_percent(10)),
+			crate::Error::<Runtime>::ServiceRequestNotFound
+		);
+	});
+}
+
+#[test]
+fn service_value_is_paid_out_to_operators() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint = cggmp21_blueprint();
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		for operator in [&bob, &charlie] {
+			assert_ok!(Services::register(
+				RuntimeOrigin::signed(operator.clone()),
+				0,
+				OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+				Default::default(),
+				0,
+			));
+		}
+		let eve = mock_pub_key(EVE);
+		let eve_balance = Balances::free_balance(&eve);
+		let bob_balance = Balances::free_balance(&bob);
+		let charlie_balance = Balances::free_balance(&charlie);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone(), charlie.clone()],
+			Default::default(),
+			vec![USDC, WETH],
+			100,
+			1000,
+			Default::default(),
+		));
 		assert_ok!(Services::approve(
 			RuntimeOrigin::signed(bob.clone()),
 			0,
-			Percent::from_percent(10)
+			Percent::from_percent(20)
 		));
-
 		assert_ok!(Services::approve(
 			RuntimeOrigin::signed(charlie.clone()),
 			0,
-			Percent::from_percent(10)
+			Percent::from_percent(30)
 		));
+		assert_eq!(
+			Services::service_escrow(0).unwrap(),
+			ServiceEscrow { value: 1000, paid_out: 0 }
+		);
 
-		assert_ok!(Services::approve(
-			RuntimeOrigin::signed(dave.clone()),
-			0,
-			Percent::from_percent(10)
-		));
-		assert!(Instances::<Runtime>::contains_key(0));
-		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceInitiated {
-			owner: eve.clone(),
-			request_id: 0,
+		// Half of the lifetime has passed, so half of the value is paid out, split by the
+		// restaking percentage of each operator.
+		System::set_block_number(51);
+		assert_ok!(Services::payout(RuntimeOrigin::signed(alice.clone()), 0));
+		assert_eq!(Balances::free_balance(&bob), bob_balance + 200);
+		assert_eq!(Balances::free_balance(&charlie), charlie_balance + 300);
+		assert_eq!(
+			Services::service_escrow(0).unwrap(),
+			ServiceEscrow { value: 1000, paid_out: 500 }
+		);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServicePaidOut {
 			service_id: 0,
 			blueprint_id: 0,
-			assets: vec![WETH],
+			payouts: vec![(bob.clone(), 200), (charlie.clone(), 300)],
 		})]);
 
-		// now we can call the jobs
-		let job_call_id = 0;
-		assert_ok!(Services::call(
-			RuntimeOrigin::signed(eve.clone()),
-			0,
-			0,
-			bounded_vec![Field::Uint8(2)],
-		));
-
-		assert!(JobCalls::<Runtime>::contains_key(0, job_call_id));
-		assert_events(vec![RuntimeEvent::Services(crate::Event::JobCalled {
-			caller: eve,
+		// Terminating the service pays out what vested since, and refunds the rest to the owner.
+		System::set_block_number(76);
+		assert_ok!(Services::terminate(RuntimeOrigin::signed(eve.clone()), 0));
+		assert_eq!(Balances::free_balance(&bob), bob_balance + 300);
+		assert_eq!(Balances::free_balance(&charlie), charlie_balance + 450);
+		assert_eq!(Balances::free_balance(&eve), eve_balance - 750);
+		assert!(Services::service_escrow(0).is_err());
+		let paid_out = RuntimeEvent::Services(crate::Event::ServicePaidOut {
 			service_id: 0,
-			job: 0,
-			call_id: job_call_id,
-			args: vec![Field::Uint8(2)],
+			blueprint_id: 0,
+			payouts: vec![(bob.clone(), 100), (charlie.clone(), 150)],
+		});
+		assert!(System::events().iter().any(|record| record.event == paid_out));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceTerminated {
+			owner: eve,
+			service_id: 0,
+			blueprint_id: 0,
+			reason: ServiceTerminationReason::Owner,
+			refunded: 250,
 		})]);
 	});
 }
 
 #[test]
-fn job_result() {
+fn update_blueprint_keeps_services_pinned() {
 	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
 		System::set_block_number(1);
+		let Deployment { blueprint_id, service_id, .. } = deploy();
 		let alice = mock_pub_key(ALICE);
-		let blueprint = cggmp21_blueprint();
-		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
 		let bob = mock_pub_key(BOB);
-		assert_ok!(Services::register(
+		let eve = mock_pub_key(EVE);
+
+		let mut updated = cggmp21_blueprint();
+		updated.metadata.name = "CGGMP21 TSS v2".try_into().unwrap();
+
+		// only the owner can update the blueprint.
+		assert_err!(
+			Services::update_blueprint(
+				RuntimeOrigin::signed(bob.clone()),
+				blueprint_id,
+				updated.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Services::update_blueprint(
+			RuntimeOrigin::signed(alice.clone()),
+			blueprint_id,
+			updated.clone()
+		));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::BlueprintUpdated {
+			owner: alice.clone(),
+			blueprint_id,
+			revision: 1,
+		})]);
+
+		assert_eq!(Services::latest_blueprint_revision(blueprint_id), 1);
+		assert_eq!(Services::blueprints(blueprint_id).unwrap().1, updated);
+		assert_eq!(
+			Services::blueprint_revisions_of(blueprint_id).unwrap(),
+			vec![(0, cggmp21_blueprint()), (1, updated.clone())]
+		);
+
+		// the existing service stays pinned to the revision it was instantiated with.
+		let service = Services::services(service_id).unwrap();
+		assert_eq!(service.blueprint_revision, 0);
+		assert_eq!(Services::service_blueprint(&service).unwrap(), cggmp21_blueprint());
+
+		// bob did not opt in to the new revision yet.
+		assert_err!(
+			Services::request(
+				RuntimeOrigin::signed(eve.clone()),
+				blueprint_id,
+				vec![],
+				vec![bob.clone()],
+				Default::default(),
+				vec![WETH],
+				100,
+				0,
+				Default::default(),
+			),
+			crate::Error::<Runtime>::OperatorNotOnLatestRevision
+		);
+
+		assert_ok!(Services::update_operator_revision(
 			RuntimeOrigin::signed(bob.clone()),
-			0,
-			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			blueprint_id
+		));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::OperatorRevisionUpdated {
+			operator: bob.clone(),
+			blueprint_id,
+			revision: 1,
+		})]);
+
+		let request_id = Services::next_service_request_id();
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			blueprint_id,
+			vec![],
+			vec![bob.clone()],
 			Default::default(),
+			vec![WETH],
+			100,
 			0,
+			Default::default(),
 		));
+		assert_eq!(Services::service_requests(request_id).unwrap().blueprint_revision, 1);
+	});
+}
+
+#[test]
+fn transfer_blueprint_ownership() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
 		let charlie = mock_pub_key(CHARLIE);
-		assert_ok!(Services::register(
-			RuntimeOrigin::signed(charlie.clone()),
-			0,
-			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
-			Default::default(),
-			0,
+		let blueprint_id = Services::next_blueprint_id();
+		assert_ok!(Services::create_blueprint(
+			RuntimeOrigin::signed(alice.clone()),
+			cggmp21_blueprint()
 		));
-		let dave = mock_pub_key(DAVE);
-		assert_ok!(Services::register(
-			RuntimeOrigin::signed(dave.clone()),
-			0,
-			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
-			Default::default(),
-			0,
+
+		assert_err!(
+			Services::transfer_blueprint_ownership(
+				RuntimeOrigin::signed(charlie.clone()),
+				blueprint_id,
+				charlie.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Services::transfer_blueprint_ownership(
+			RuntimeOrigin::signed(alice.clone()),
+			blueprint_id,
+			charlie.clone()
+		));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::BlueprintOwnershipTransferred {
+			blueprint_id,
+			from: alice.clone(),
+			to: charlie.clone(),
+		})]);
+		assert_eq!(Services::blueprints(blueprint_id).unwrap().0, charlie);
+
+		// the previous owner lost the control over the blueprint.
+		assert_err!(
+			Services::deprecate_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint_id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Services::deprecate_blueprint(RuntimeOrigin::signed(charlie), blueprint_id));
+	});
+}
+
+#[test]
+fn deprecated_blueprint_keeps_running_services() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let Deployment { blueprint_id, service_id, .. } = deploy();
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let eve = mock_pub_key(EVE);
+
+		assert_ok!(Services::deprecate_blueprint(
+			RuntimeOrigin::signed(alice.clone()),
+			blueprint_id
+		));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::BlueprintDeprecated {
+			owner: alice.clone(),
+			blueprint_id,
+		})]);
+		assert_eq!(Services::blueprint_state(blueprint_id), BlueprintState::Deprecated);
+
+		assert_err!(
+			Services::deprecate_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint_id),
+			crate::Error::<Runtime>::BlueprintDeprecated
+		);
+		assert_err!(
+			Services::register(
+				RuntimeOrigin::signed(charlie),
+				blueprint_id,
+				OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+				Default::default(),
+				0,
+			),
+			crate::Error::<Runtime>::BlueprintDeprecated
+		);
+		assert_err!(
+			Services::request(
+				RuntimeOrigin::signed(eve.clone()),
+				blueprint_id,
+				vec![],
+				vec![bob.clone()],
+				Default::default(),
+				vec![WETH],
+				100,
+				0,
+				Default::default(),
+			),
+			crate::Error::<Runtime>::BlueprintDeprecated
+		);
+
+		// the running service is not affected.
+		assert!(Instances::<Runtime>::contains_key(service_id));
+		let services = Services::services_with_blueprints_by_operator(bob).unwrap();
+		assert_eq!(services.len(), 1);
+		assert_eq!(services[0].state, BlueprintState::Deprecated);
+		assert_eq!(services[0].services[0].id, service_id);
+
+		assert_ok!(Services::terminate(RuntimeOrigin::signed(eve), service_id));
+	});
+}
+
+#[test]
+fn deprecated_blueprint_rejects_pending_approvals() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint = cggmp21_blueprint();
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+		let bob = mock_pub_key(BOB);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+		let eve = mock_pub_key(EVE);
+		let eve_balance = Balances::free_balance(&eve);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![],
+			vec![bob.clone()],
+			Default::default(),
+			vec![WETH],
+			100,
+			500,
+			Default::default(),
+		));
+
+		assert_ok!(Services::deprecate_blueprint(RuntimeOrigin::signed(alice), 0));
+
+		assert_err!(
+			Services::pre_register(RuntimeOrigin::signed(mock_pub_key(CHARLIE)), 0),
+			crate::Error::<Runtime>::BlueprintDeprecated
+		);
+		// the pending request can not be approved anymore, only cancelled.
+		assert_err!(
+			Services::approve(RuntimeOrigin::signed(bob), 0, Percent::from_percent(10)),
+			crate::Error::<Runtime>::BlueprintDeprecated
+		);
+		assert_eq!(Services::next_instance_id(), 0);
+
+		assert_ok!(Services::cancel_request(RuntimeOrigin::signed(eve.clone()), 0));
+		assert_eq!(Balances::free_balance(&eve), eve_balance);
+	});
+}
+
+#[test]
+fn job_calls() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint = cggmp21_blueprint();
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+		let bob = mock_pub_key(BOB);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+		let charlie = mock_pub_key(CHARLIE);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(charlie.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+		let dave = mock_pub_key(DAVE);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(dave.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone(), charlie.clone(), dave.clone()],
+			Default::default(),
+			vec![WETH],
+			100,
+			0,
+			Default::default(),
+		));
+
+		assert_eq!(ServiceRequests::<Runtime>::iter_keys().collect::<Vec<_>>().len(), 1);
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			Percent::from_percent(10)
+		));
+
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(charlie.clone()),
+			0,
+			Percent::from_percent(10)
+		));
+
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(dave.clone()),
+			0,
+			Percent::from_percent(10)
+		));
+		assert!(Instances::<Runtime>::contains_key(0));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceInitiated {
+			owner: eve.clone(),
+			request_id: 0,
+			service_id: 0,
+			blueprint_id: 0,
+			assets: vec![WETH],
+		})]);
+
+		// now we can call the jobs
+		let job_call_id = 0;
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			0,
+			bounded_vec![Field::Uint8(2)],
+		));
+
+		assert!(JobCalls::<Runtime>::contains_key(0, job_call_id));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::JobCalled {
+			caller: eve,
+			service_id: 0,
+			job: 0,
+			call_id: job_call_id,
+			args: vec![Field::Uint8(2)],
+		})]);
+	});
+}
+
+#[test]
+fn job_result() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint = cggmp21_blueprint();
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+		let bob = mock_pub_key(BOB);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+		let charlie = mock_pub_key(CHARLIE);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(charlie.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+		let dave = mock_pub_key(DAVE);
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(dave.clone()),
+			0,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone(), charlie.clone(), dave.clone()],
+			Default::default(),
+			vec![WETH],
+			100,
+			0,
+			Default::default(),
+		));
+
+		assert_eq!(ServiceRequests::<Runtime>::iter_keys().collect::<Vec<_>>().len(), 1);
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			Percent::from_percent(10)
+		));
+
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(charlie.clone()),
+			0,
+			Percent::from_percent(10)
+		));
+
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(dave.clone()),
+			0,
+			Percent::from_percent(10)
+		));
+		assert!(Instances::<Runtime>::contains_key(0));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceInitiated {
+			owner: eve.clone(),
+			request_id: 0,
+			service_id: 0,
+			blueprint_id: 0,
+			assets: vec![WETH],
+		})]);
+
+		// now we can call the jobs
+		let keygen_job_call_id = 0;
+
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			0,
+			bounded_vec![Field::Uint8(2)]
+		));
+
+		assert!(JobCalls::<Runtime>::contains_key(0, keygen_job_call_id));
+		// now we can set the job result
+		let key_type = KeyTypeId(*b"mdkg");
+		let dkg = sp_io::crypto::ecdsa_generate(key_type, None);
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			keygen_job_call_id,
+			bounded_vec![Field::Bytes(dkg.to_raw_vec().try_into().unwrap())],
+		));
+
+		// submit signing job
+		let _signing_job_call_id = 1;
+		let data_hash = sp_core::keccak_256(&[1; 32]);
+
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			SIGN_JOB_ID,
+			bounded_vec![
+				Field::Uint64(keygen_job_call_id),
+				Field::Bytes(data_hash.to_vec().try_into().unwrap())
+			],
+		));
+
+		// now we can set the job result
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &dkg, &data_hash).unwrap();
+		let mut signature_bytes = signature.to_raw_vec();
+		// fix the v value (it should be 27 or 28).
+		signature_bytes[64] += 27u8;
+		// For some reason, the signature is not being verified.
+		// in EVM, ecrecover is used to verify the signature, but it returns
+		// 0x000000000000000000000000000000000000000 as the address of the signer.
+		// even though the signature is correct, and we have the precomiles in the runtime.
+		//
+		// assert_ok!(Services::submit_result(
+		// 	RuntimeOrigin::signed(bob.clone()),
+		// 	0,
+		// 	signing_job_call_id,
+		// 	bounded_vec![Field::Bytes(signature_bytes.try_into().unwrap())],
+		// ));
+	});
+}
+
+#[test]
+fn job_result_threshold_policy() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].result_policy = JobResultPolicy::Threshold { count: 2 };
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let dave = mock_pub_key(DAVE);
+		for operator in [&bob, &charlie, &dave] {
+			assert_ok!(Services::register(
+				RuntimeOrigin::signed(operator.clone()),
+				0,
+				OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+				Default::default(),
+				0,
+			));
+		}
+
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone(), charlie.clone(), dave.clone()],
+			Default::default(),
+			vec![WETH],
+			100,
+			0,
+			Default::default(),
+		));
+		for operator in [&bob, &charlie, &dave] {
+			assert_ok!(Services::approve(
+				RuntimeOrigin::signed(operator.clone()),
+				0,
+				Percent::from_percent(10)
+			));
+		}
+
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			0,
+			bounded_vec![Field::Uint8(2)]
+		));
+
+		let key = Field::Bytes(vec![1; 33].try_into().unwrap());
+		let forged_key = Field::Bytes(vec![2; 33].try_into().unwrap());
+
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			0,
+			vec![key.clone()],
+		));
+		assert!(!JobResults::<Runtime>::contains_key(0, 0));
+
+		// an operator can only submit a single result.
+		assert_err!(
+			Services::submit_result(RuntimeOrigin::signed(bob.clone()), 0, 0, vec![key.clone()]),
+			crate::Error::<Runtime>::JobResultAlreadySubmitted
+		);
+
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(charlie.clone()),
+			0,
+			0,
+			vec![forged_key],
+		));
+		assert!(!JobResults::<Runtime>::contains_key(0, 0));
+
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(dave.clone()),
+			0,
+			0,
+			vec![key.clone()],
+		));
+		assert_events(vec![
+			RuntimeEvent::Services(crate::Event::JobResultSubmitted {
+				operator: dave.clone(),
+				service_id: 0,
+				call_id: 0,
+				job: 0,
+				result: vec![key.clone()],
+			}),
+			RuntimeEvent::Services(crate::Event::JobResultFinalized {
+				service_id: 0,
+				call_id: 0,
+				job: 0,
+				result: vec![key.clone()],
+			}),
+		]);
+		assert_eq!(Services::job_results(0, 0).unwrap().result.into_inner(), vec![key]);
+		assert_eq!(Services::job_result_submissions(0, 0).len(), 3);
+	});
+}
+
+#[test]
+fn job_call_times_out() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].result_policy = JobResultPolicy::All;
+		blueprint.jobs[0].response_window = Some(5);
+		assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		for operator in [&bob, &charlie] {
+			assert_ok!(Services::register(
+				RuntimeOrigin::signed(operator.clone()),
+				0,
+				OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+				Default::default(),
+				0,
+			));
+		}
+
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone(), charlie.clone()],
+			Default::default(),
+			vec![WETH],
+			100,
+			0,
+			Default::default(),
+		));
+		for operator in [&bob, &charlie] {
+			assert_ok!(Services::approve(
+				RuntimeOrigin::signed(operator.clone()),
+				0,
+				Percent::from_percent(10)
+			));
+		}
+
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			0,
+			bounded_vec![Field::Uint8(2)]
+		));
+		let key = Field::Bytes(vec![1; 33].try_into().unwrap());
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			0,
+			0,
+			vec![key.clone()],
+		));
+
+		// the call is still pending right before its deadline.
+		System::set_block_number(5);
+		Services::on_idle(5, Weight::MAX);
+		assert!(Services::timed_out_job_calls(0, 0).is_none());
+
+		// the response window is over, even though the call did not get swept yet.
+		System::set_block_number(6);
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(charlie.clone()),
+				0,
+				0,
+				vec![key.clone()]
+			),
+			crate::Error::<Runtime>::JobCallTimedOut
+		);
+
+		Services::on_idle(6, Weight::MAX);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::JobCallTimedOut {
+			service_id: 0,
+			call_id: 0,
+			job: 0,
+			missed: vec![charlie.clone()],
+		})]);
+		assert_eq!(
+			Services::timed_out_job_calls(0, 0).unwrap().into_inner(),
+			vec![charlie.clone()]
+		);
+		assert_eq!(Services::missed_job_calls(0, &charlie), 1);
+		assert_eq!(Services::missed_job_calls(0, &bob), 0);
+
+		assert_err!(
+			Services::submit_result(RuntimeOrigin::signed(charlie.clone()), 0, 0, vec![key]),
+			crate::Error::<Runtime>::JobCallTimedOut
+		);
+	});
+}
+
+#[test]
+fn job_call_fee_is_paid_to_operators() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].fee = Some(JobFee::Fixed { asset: FeeAsset::Native, amount: 101 });
+		let alice = mock_pub_key(ALICE);
+		assert_err!(
+			Services::create_blueprint(RuntimeOrigin::signed(alice), blueprint.clone()),
+			crate::Error::<Runtime>::JobFeeWithoutResponseWindow
+		);
+
+		blueprint.jobs[0].response_window = Some(100);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone(), charlie.clone()]);
+
+		let eve = mock_pub_key(EVE);
+		let eve_balance = Balances::free_balance(&eve);
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			bounded_vec![Field::Uint8(2)]
+		));
+		assert_eq!(Balances::free_balance(&eve), eve_balance - 101);
+		assert_eq!(Services::job_call_fees(service_id, 0).unwrap().amount, 101);
+
+		let bob_balance = Balances::free_balance(&bob);
+		let charlie_balance = Balances::free_balance(&charlie);
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			0,
+			vec![Field::Bytes(vec![1; 33].try_into().unwrap())],
+		));
+
+		// only bob submitted the accepted result.
+		assert_eq!(Balances::free_balance(&bob), bob_balance + 101);
+		assert_eq!(Balances::free_balance(&charlie), charlie_balance);
+		assert!(Services::job_call_fees(service_id, 0).is_none());
+		assert!(System::events().iter().any(|r| r.event
+			== RuntimeEvent::Services(crate::Event::JobFeePaid {
+				service_id,
+				call_id: 0,
+				asset: None,
+				payouts: vec![(bob.clone(), 101)],
+			})));
+	});
+}
+
+#[test]
+fn job_call_fee_that_fails_to_pay_out_stays_escrowed() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].fee = Some(JobFee::Fixed { asset: FeeAsset::Native, amount: 101 });
+		blueprint.jobs[0].response_window = Some(100);
+		let bob = mock_pub_key(BOB);
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			bounded_vec![Field::Uint8(2)]
+		));
+		// the pallet holds none of the asset, so the payout fails.
+		JobCallFees::<Runtime>::mutate(service_id, 0, |fee| {
+			fee.as_mut().unwrap().asset = Some(99);
+		});
+
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			0,
+			vec![Field::Bytes(vec![1; 33].try_into().unwrap())],
+		));
+
+		assert_eq!(Services::job_call_fees(service_id, 0).unwrap().amount, 101);
+		assert!(!System::events().iter().any(|r| matches!(
+			r.event,
+			RuntimeEvent::Services(crate::Event::JobFeePaid { .. })
+		)));
+	});
+}
+
+#[test]
+fn job_call_fee_is_refunded_on_timeout() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let eve = mock_pub_key(EVE);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), WETH, alice.clone(), true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(alice.clone()), WETH, eve.clone(), 1_000));
+
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].response_window = Some(5);
+		blueprint.jobs[0].fee =
+			Some(JobFee::PerByte { asset: FeeAsset::Custom(WETH.into()), base: 10, per_byte: 5 });
+		let bob = mock_pub_key(BOB);
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+
+		let args: Vec<Field<ConstraintsOf<Runtime>, AccountId>> = vec![Field::Uint8(2)];
+		let fee = 10 + 5 * args.encoded_size() as u128;
+		assert_ok!(Services::call(RuntimeOrigin::signed(eve.clone()), service_id, 0, args));
+		assert_eq!(Assets::balance(WETH, &eve), 1_000 - fee);
+
+		System::set_block_number(6);
+		Services::on_idle(6, Weight::MAX);
+
+		assert_eq!(Assets::balance(WETH, &eve), 1_000);
+		assert!(Services::job_call_fees(service_id, 0).is_none());
+		assert!(System::events().iter().any(|r| r.event
+			== RuntimeEvent::Services(crate::Event::JobFeeRefunded {
+				service_id,
+				call_id: 0,
+				payer: eve.clone(),
+				asset: Some(WETH),
+				amount: fee,
+			})));
+	});
+}
+
+#[test]
+fn operator_leaves_service_after_notice_period() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.exit_notice_period = 5;
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone(), charlie.clone()]);
+		let blueprint_id = Services::services(service_id).unwrap().blueprint;
+
+		// the operator can not unregister while serving.
+		assert_err!(
+			Services::unregister(RuntimeOrigin::signed(bob.clone()), blueprint_id),
+			crate::Error::<Runtime>::OperatorStillServing
+		);
+		// only the operators of the service can leave it.
+		assert_err!(
+			Services::leave_service(RuntimeOrigin::signed(mock_pub_key(DAVE)), service_id),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Services::leave_service(RuntimeOrigin::signed(bob.clone()), service_id));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::OperatorLeaving {
+			operator: bob.clone(),
+			service_id,
+			exit_block: 6,
+		})]);
+		assert_err!(
+			Services::leave_service(RuntimeOrigin::signed(bob.clone()), service_id),
+			crate::Error::<Runtime>::AlreadyLeavingService
+		);
+		// the last operator can not leave the service.
+		assert_err!(
+			Services::leave_service(RuntimeOrigin::signed(charlie.clone()), service_id),
+			crate::Error::<Runtime>::LastServiceOperator
+		);
+
+		// the operator keeps serving until the end of its notice period.
+		Services::on_idle(5, Weight::MAX);
+		let service = Services::services(service_id).unwrap();
+		assert!(service.operators.iter().any(|(v, _)| v == &bob));
+
+		System::set_block_number(6);
+		Services::on_idle(6, Weight::MAX);
+		let service = Services::services(service_id).unwrap();
+		assert!(!service.operators.iter().any(|(v, _)| v == &bob));
+		assert!(!Services::operator_profile(&bob).unwrap().services.contains(&service_id));
+		assert!(Services::operator_exits(service_id, &bob).is_none());
+		assert!(System::events().iter().any(|r| r.event
+			== RuntimeEvent::Services(crate::Event::OperatorLeftService {
+				operator: bob.clone(),
+				service_id,
+				blueprint_id,
+			})));
+
+		assert_ok!(Services::unregister(RuntimeOrigin::signed(bob), blueprint_id));
+	});
+}
+
+#[test]
+fn operator_change_rotates_service_operators() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let dave = mock_pub_key(DAVE);
+		let eve = mock_pub_key(EVE);
+		let service_id = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone()]);
+		let blueprint_id = Services::services(service_id).unwrap().blueprint;
+		for operator in [&charlie, &dave] {
+			assert_ok!(Services::register(
+				RuntimeOrigin::signed(operator.clone()),
+				blueprint_id,
+				OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+				Default::default(),
+				0,
+			));
+		}
+
+		// only the owner of the service or the manager of its blueprint can propose a change.
+		for account in [bob.clone(), mock_pub_key(ALICE)] {
+			assert_err!(
+				Services::propose_operator_change(
+					RuntimeOrigin::signed(account),
+					service_id,
+					vec![charlie.clone()],
+					vec![],
+				),
+				DispatchError::BadOrigin
+			);
+		}
+		// an operator already serving the service can not join it again.
+		assert_err!(
+			Services::propose_operator_change(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				vec![bob.clone()],
+				vec![],
+			),
+			crate::Error::<Runtime>::InvalidOperatorChange
+		);
+		// the last operator can not leave the service.
+		assert_err!(
+			Services::propose_operator_change(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				vec![],
+				vec![bob.clone()],
+			),
+			crate::Error::<Runtime>::LastServiceOperator
+		);
+
+		// a rejected change gets dropped, the manager contract can propose changes through both
+		// the account its address maps to and the native account mapping to its address.
+		let manager_accounts = [
+			PalletEVMAddressMapping::into_account_id(CGGMP21_BLUEPRINT),
+			AccountId::new([0x21; 32]),
+		];
+		for manager in manager_accounts {
+			assert_ok!(Services::propose_operator_change(
+				RuntimeOrigin::signed(manager),
+				service_id,
+				vec![dave.clone()],
+				vec![],
+			));
+			assert_ok!(Services::reject_operator_change(
+				RuntimeOrigin::signed(dave.clone()),
+				service_id
+			));
+			assert!(Services::operator_change_requests(service_id).is_err());
+		}
+
+		assert_ok!(Services::propose_operator_change(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			vec![charlie.clone()],
+			vec![bob.clone()],
+		));
+		assert_err!(
+			Services::propose_operator_change(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				vec![dave.clone()],
+				vec![],
+			),
+			crate::Error::<Runtime>::OperatorChangeAlreadyProposed
+		);
+		assert_err!(
+			Services::approve_operator_change(
+				RuntimeOrigin::signed(dave.clone()),
+				service_id,
+				Percent::from_percent(10),
+				2,
+			),
+			crate::Error::<Runtime>::ApprovalNotRequested
+		);
+		// the witness must cover the operators joining and leaving the service.
+		assert_err!(
+			Services::approve_operator_change(
+				RuntimeOrigin::signed(charlie.clone()),
+				service_id,
+				Percent::from_percent(20),
+				1,
+			),
+			crate::Error::<Runtime>::InvalidOperatorChangeWitness
+		);
+		// the service keeps its operators until the change is approved.
+		assert_eq!(Services::services(service_id).unwrap().operators.len(), 1);
+
+		assert_ok!(Services::approve_operator_change(
+			RuntimeOrigin::signed(charlie.clone()),
+			service_id,
+			Percent::from_percent(20),
+			2,
+		));
+
+		let service = Services::services(service_id).unwrap();
+		assert_eq!(service.operators.to_vec(), vec![(charlie.clone(), Percent::from_percent(20))]);
+		assert!(!Services::operator_profile(&bob).unwrap().services.contains(&service_id));
+		assert!(Services::operator_profile(&charlie).unwrap().services.contains(&service_id));
+		assert!(Services::operator_change_requests(service_id).is_err());
+		assert!(System::events().iter().any(|r| r.event
+			== RuntimeEvent::Services(crate::Event::OperatorLeftService {
+				operator: bob.clone(),
+				service_id,
+				blueprint_id,
+			})));
+		assert!(System::events().iter().any(|r| r.event
+			== RuntimeEvent::Services(crate::Event::OperatorJoinedService {
+				operator: charlie.clone(),
+				service_id,
+				blueprint_id,
+				restaking_percent: Percent::from_percent(20),
+			})));
+
+		// the joining operators must run the revision the service is pinned to.
+		assert_ok!(Services::update_blueprint(
+			RuntimeOrigin::signed(mock_pub_key(ALICE)),
+			blueprint_id,
+			cggmp21_blueprint()
+		));
+		assert_ok!(Services::update_operator_revision(
+			RuntimeOrigin::signed(dave.clone()),
+			blueprint_id
+		));
+		assert_err!(
+			Services::propose_operator_change(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				vec![dave.clone()],
+				vec![],
+			),
+			crate::Error::<Runtime>::OperatorNotOnServiceRevision
+		);
+
+		// no operator can join the services of a deprecated blueprint.
+		assert_ok!(Services::deprecate_blueprint(
+			RuntimeOrigin::signed(mock_pub_key(ALICE)),
+			blueprint_id
+		));
+		assert_err!(
+			Services::propose_operator_change(
+				RuntimeOrigin::signed(eve),
+				service_id,
+				vec![dave],
+				vec![],
+			),
+			crate::Error::<Runtime>::BlueprintDeprecated
+		);
+	});
+}
+
+#[test]
+fn operator_change_is_checked_again_when_applied() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let eve = mock_pub_key(EVE);
+		let service_id = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone()]);
+		let blueprint_id = Services::services(service_id).unwrap().blueprint;
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(charlie.clone()),
+			blueprint_id,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+		assert_ok!(Services::propose_operator_change(
+			RuntimeOrigin::signed(eve),
+			service_id,
+			vec![charlie.clone()],
+			vec![],
+		));
+
+		// the joining operator is no longer registered when its approval applies the change.
+		assert_ok!(Services::unregister(RuntimeOrigin::signed(charlie.clone()), blueprint_id));
+		assert_err!(
+			Services::approve_operator_change(
+				RuntimeOrigin::signed(charlie.clone()),
+				service_id,
+				Percent::from_percent(20),
+				1,
+			),
+			crate::Error::<Runtime>::NotRegistered
+		);
+		assert_eq!(Services::services(service_id).unwrap().operators.len(), 1);
+
+		// the invalid change can still be rejected.
+		assert_ok!(Services::reject_operator_change(RuntimeOrigin::signed(charlie), service_id));
+		assert!(Services::operator_change_requests(service_id).is_err());
+	});
+}
+
+#[test]
+fn job_caller_policies() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let dave = mock_pub_key(DAVE);
+		let eve = mock_pub_key(EVE);
+		let service_id = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone()]);
+		let blueprint_id = Services::services(service_id).unwrap().blueprint;
+		let call = |who: &AccountId| {
+			Services::call(RuntimeOrigin::signed(who.clone()), service_id, 0, vec![Field::Uint8(2)])
+		};
+
+		// the policies can only be set for the jobs of the blueprint.
+		assert_err!(
+			Services::request(
+				RuntimeOrigin::signed(eve.clone()),
+				blueprint_id,
+				vec![],
+				vec![bob.clone()],
+				Default::default(),
+				vec![WETH],
+				100,
+				0,
+				vec![(42, JobCallerPolicy::Anyone)],
+			),
+			crate::Error::<Runtime>::JobDefinitionNotFound
+		);
+
+		// by default, the owner and the permitted callers can call the jobs.
+		assert_err!(call(&charlie), DispatchError::BadOrigin);
+		assert_err!(
+			Services::add_permitted_caller(
+				RuntimeOrigin::signed(charlie.clone()),
+				service_id,
+				charlie.clone(),
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Services::add_permitted_caller(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			charlie.clone(),
+		));
+		assert_err!(
+			Services::add_permitted_caller(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				charlie.clone(),
+			),
+			crate::Error::<Runtime>::AlreadyPermittedCaller
+		);
+		assert_ok!(call(&charlie));
+		assert_ok!(call(&eve));
+
+		assert_ok!(Services::set_job_caller_policy(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			JobCallerPolicy::OwnerOnly,
+		));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::JobCallerPolicyUpdated {
+			service_id,
+			job: 0,
+			policy: JobCallerPolicy::OwnerOnly,
+		})]);
+		assert_err!(call(&charlie), DispatchError::BadOrigin);
+		assert_ok!(call(&eve));
+
+		assert_ok!(Services::set_job_caller_policy(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			JobCallerPolicy::Accounts(bounded_vec![dave.clone()]),
+		));
+		assert_ok!(call(&dave));
+		assert_err!(call(&eve), DispatchError::BadOrigin);
+
+		assert_ok!(Services::set_job_caller_policy(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			JobCallerPolicy::Anyone,
+		));
+		assert_ok!(call(&alice));
+		assert_err!(
+			Services::set_job_caller_policy(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				42,
+				JobCallerPolicy::Anyone,
+			),
+			crate::Error::<Runtime>::JobDefinitionNotFound
+		);
+
+		assert_ok!(Services::set_job_caller_policy(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			JobCallerPolicy::PermittedCallers,
+		));
+		assert_ok!(Services::remove_permitted_caller(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			charlie.clone(),
+		));
+		assert_err!(call(&charlie), DispatchError::BadOrigin);
+		assert_err!(
+			Services::remove_permitted_caller(RuntimeOrigin::signed(eve), service_id, charlie),
+			crate::Error::<Runtime>::NotPermittedCaller
+		);
+	});
+}
+
+#[test]
+fn batched_job_calls_and_results() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let eve = mock_pub_key(EVE);
+		let service_id = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone()]);
+
+		let calls = (0..3).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		// the batch fails as a whole.
+		assert_err!(
+			Services::call_batch(RuntimeOrigin::signed(charlie.clone()), service_id, calls.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_err!(
+			Services::call_batch(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				vec![(0, vec![Field::Uint8(2)]), (42, vec![])],
+			),
+			crate::Error::<Runtime>::JobDefinitionNotFound
+		);
+		assert!(!JobCalls::<Runtime>::contains_key(service_id, 0));
+		let too_large = (0..11).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		assert_err!(
+			Services::call_batch(RuntimeOrigin::signed(eve.clone()), service_id, too_large),
+			crate::Error::<Runtime>::JobBatchTooLarge
+		);
+
+		assert_ok!(Services::call_batch(RuntimeOrigin::signed(eve.clone()), service_id, calls));
+		for call_id in 0..3 {
+			assert!(JobCalls::<Runtime>::contains_key(service_id, call_id));
+		}
+		assert_eq!(Services::next_job_call_id(), 3);
+
+		let key = Field::Bytes(vec![1; 33].try_into().unwrap());
+		let results = (0..3).map(|call_id| (call_id, vec![key.clone()])).collect::<Vec<_>>();
+		assert_err!(
+			Services::submit_results_batch(
+				RuntimeOrigin::signed(charlie),
+				service_id,
+				results.clone(),
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Services::submit_results_batch(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			results,
+		));
+		for call_id in 0..3 {
+			assert!(JobResults::<Runtime>::contains_key(service_id, call_id));
+		}
+		assert!(System::events().iter().any(|r| r.event
+			== RuntimeEvent::Services(crate::Event::JobResultSubmitted {
+				operator: bob.clone(),
+				service_id,
+				call_id: 2,
+				job: 0,
+				result: vec![key.clone()],
+			})));
+	});
+}
+
+#[test]
+fn full_job_batches_fit_in_an_extrinsic() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let service_id = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone()]);
+		let max_extrinsic = <Runtime as frame_system::Config>::BlockWeights::get()
+			.get(DispatchClass::Normal)
+			.max_extrinsic
+			.unwrap();
+
+		let batch_size = MaxJobBatchSize::get() as u64;
+		let calls = (0..batch_size).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		let call = crate::Call::<Runtime>::call_batch { service_id, calls: calls.clone() };
+		assert!(call.get_dispatch_info().weight.all_lte(max_extrinsic));
+		assert_ok!(Services::call_batch(RuntimeOrigin::signed(eve.clone()), service_id, calls));
+
+		let key = Field::Bytes(vec![1; 33].try_into().unwrap());
+		let results =
+			(0..batch_size).map(|call_id| (call_id, vec![key.clone()])).collect::<Vec<_>>();
+		let call =
+			crate::Call::<Runtime>::submit_results_batch { service_id, results: results.clone() };
+		assert!(call.get_dispatch_info().weight.all_lte(max_extrinsic));
+		assert_ok!(Services::submit_results_batch(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			results,
+		));
+		for call_id in 0..batch_size {
+			assert!(JobResults::<Runtime>::contains_key(service_id, call_id));
+		}
+	});
+}
+
+#[test]
+fn job_batches_are_bounded_by_the_hook_gas_budget() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.hook_gas_limit = MaxHookGasLimit::get();
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+
+		// each job call can trigger two hooks, using up to the hook gas limit each, on top of the
+		// ERC-165 check of the batched hooks.
+		let probe_gas = Services::SUPPORTS_INTERFACE_GAS_LIMIT;
+		let max_calls = (MaxBatchHookGas::get() - probe_gas) / MaxHookGasLimit::get() / 2;
+		let calls = (0..=max_calls).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		assert_err!(
+			Services::call_batch(RuntimeOrigin::signed(eve.clone()), service_id, calls),
+			crate::Error::<Runtime>::BatchHookGasExceeded
+		);
+		let calls = (0..max_calls).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		assert_ok!(Services::call_batch(RuntimeOrigin::signed(eve.clone()), service_id, calls));
+	});
+}
+
+#[test]
+fn job_call_data_is_pruned() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].response_window = Some(100);
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+		let key = Field::Bytes(vec![1; 33].try_into().unwrap());
+		for call_id in 0..3 {
+			assert_ok!(Services::call(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				0,
+				vec![Field::Uint8(2)],
+			));
+			if call_id < 2 {
+				assert_ok!(Services::submit_result(
+					RuntimeOrigin::signed(bob.clone()),
+					service_id,
+					call_id,
+					vec![key.clone()],
+				));
+			}
+		}
+
+		// the consumers of a result can acknowledge it to prune it right away, which is the
+		// account that called the job or the owner of the service.
+		for account in [charlie, mock_pub_key(ALICE)] {
+			assert_err!(
+				Services::acknowledge_job_result(RuntimeOrigin::signed(account), service_id, 0),
+				DispatchError::BadOrigin
+			);
+		}
+		assert_err!(
+			Services::acknowledge_job_result(RuntimeOrigin::signed(eve.clone()), service_id, 2),
+			crate::Error::<Runtime>::JobCallResultNotFound
+		);
+		assert_ok!(Services::acknowledge_job_result(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+		));
+		assert!(!JobCalls::<Runtime>::contains_key(service_id, 0));
+		assert!(!JobResults::<Runtime>::contains_key(service_id, 0));
+		assert_events(vec![RuntimeEvent::Services(crate::Event::JobResultAcknowledged {
+			caller: eve.clone(),
+			service_id,
+			call_id: 0,
+		})]);
+
+		// the other results are kept until the end of the retention period.
+		Services::on_idle(20, Weight::MAX);
+		assert!(JobResults::<Runtime>::contains_key(service_id, 1));
+		System::set_block_number(21);
+		Services::on_idle(21, Weight::MAX);
+		assert!(!JobCalls::<Runtime>::contains_key(service_id, 1));
+		assert!(!JobResults::<Runtime>::contains_key(service_id, 1));
+		assert!(JobResultSubmissions::<Runtime>::get(service_id, 1).is_empty());
+		// along with the deadline of the call.
+		assert!(!JobCallResponseDeadlines::<Runtime>::contains_key(service_id, 1));
+		assert!(!JobCallDeadlines::<Runtime>::contains_key(101, (service_id, 1)));
+
+		// the pending calls are cleared in the background once the service terminates.
+		assert!(JobCalls::<Runtime>::contains_key(service_id, 2));
+		assert_ok!(Services::terminate(RuntimeOrigin::signed(eve), service_id));
+		assert!(ServiceCleanupQueue::<Runtime>::contains_key(service_id));
+		assert!(JobCalls::<Runtime>::contains_key(service_id, 2));
+		Services::on_idle(21, Weight::MAX);
+		assert!(!JobCalls::<Runtime>::contains_key(service_id, 2));
+		assert!(!JobCallDeadlines::<Runtime>::contains_key(101, (service_id, 2)));
+		assert!(!ServiceCleanupQueue::<Runtime>::contains_key(service_id));
+		assert_eq!(ServiceCleanupCursor::<Runtime>::get(), None);
+	});
+}
+
+#[test]
+fn paginated_queries() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let eve = mock_pub_key(EVE);
+		let first = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone()]);
+		let second = deploy_blueprint(cggmp21_blueprint(), vec![bob.clone(), charlie.clone()]);
+
+		let blueprints = Services::blueprints_page(Some(alice.clone()), 1, 10);
+		assert_eq!(blueprints.len(), 1);
+		assert_eq!(blueprints[0].0, 1);
+		assert!(Services::blueprints_page(Some(bob.clone()), 0, 10).is_empty());
+
+		let operators = Services::blueprint_operators_page(1, 0, 10).unwrap();
+		assert_eq!(operators.len(), 2);
+		assert_err!(
+			Services::blueprint_operators_page(2, 0, 10),
+			crate::Error::<Runtime>::BlueprintNotFound
+		);
+
+		let services = Services::services_by_owner_page(eve.clone(), 0, 1);
+		assert_eq!(services.len(), 1);
+		assert_eq!(services[0].id, first);
+		assert_eq!(Services::services_by_owner_page(eve.clone(), 1, 10)[0].id, second);
+
+		assert_ok!(Services::request(
+			RuntimeOrigin::signed(eve.clone()),
+			0,
+			vec![alice.clone()],
+			vec![bob.clone()],
+			Default::default(),
+			vec![WETH],
+			100,
+			0,
+			Default::default(),
+		));
+		let requests = Services::pending_requests_by_operator_page(bob.clone(), 0, 10);
+		assert_eq!(requests.len(), 1);
+		assert!(Services::pending_requests_by_operator_page(charlie, 0, 10).is_empty());
+
+		for _ in 0..2 {
+			assert_ok!(Services::call(
+				RuntimeOrigin::signed(eve.clone()),
+				first,
+				0,
+				vec![Field::Uint8(2)],
+			));
+		}
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			first,
+			0,
+			vec![Field::Bytes(vec![1; 33].try_into().unwrap())],
+		));
+		let calls = Services::job_calls_page(first, 0, 10).unwrap();
+		assert_eq!(calls.len(), 2);
+		assert!(calls[0].2.is_some());
+		assert!(calls[1].2.is_none());
+		assert_eq!(Services::job_calls_page(first, 1, 10).unwrap()[0].0, 1);
+
+		assert!(Services::unapplied_slashes_by_operator_page(bob, 0, 10).is_empty());
+	});
+}
+
+#[test]
+fn operator_heartbeats() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let dave = mock_pub_key(DAVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.heartbeat_interval = 10;
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone(), charlie.clone()]);
+		assert_eq!(Services::operator_heartbeats(service_id, &charlie), Some(1));
+
+		assert_err!(
+			Services::heartbeat(RuntimeOrigin::signed(dave), service_id),
+			DispatchError::BadOrigin
+		);
+		System::set_block_number(5);
+		assert_ok!(Services::heartbeat(RuntimeOrigin::signed(bob.clone()), service_id));
+		assert_eq!(Services::operator_heartbeats(service_id, &bob), Some(5));
+		// at most 4 heartbeats are accepted per interval.
+		System::set_block_number(6);
+		assert_err!(
+			Services::heartbeat(RuntimeOrigin::signed(bob.clone()), service_id),
+			crate::Error::<Runtime>::HeartbeatTooFrequent
+		);
+
+		// charlie missed its first heartbeat.
+		System::set_block_number(11);
+		Services::on_idle(11, Weight::MAX);
+		assert_eq!(Services::unresponsive_operators(service_id, &charlie), Some(1));
+		assert_eq!(Services::unresponsive_operators(service_id, &bob), None);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::OperatorUnresponsive {
+			operator: charlie.clone(),
+			service_id,
+			blueprint_id: 0,
+			last_seen: 1,
+		})]);
+
+		// its gadget sends a heartbeat signed with its operator key.
+		let key_type = KeyTypeId(*b"mdkg");
+		let key = sp_io::crypto::ecdsa_generate(key_type, None);
+		Operators::<Runtime>::insert(
+			0,
+			&charlie,
+			OperatorPreferences { key, price_targets: Default::default() },
+		);
+		System::set_block_number(12);
+		let heartbeat =
+			OperatorHeartbeat { service_id, operator: charlie.clone(), block_number: 12 };
+		let signature =
+			sp_io::crypto::ecdsa_sign_prehashed(key_type, &key, &heartbeat.signing_hash()).unwrap();
+		let call = crate::Call::<Runtime>::submit_heartbeat {
+			heartbeat: heartbeat.clone(),
+			signature: signature.clone(),
+		};
+		assert_ok!(<Services as ValidateUnsigned>::validate_unsigned(
+			TransactionSource::External,
+			&call,
+		));
+		assert_ok!(Services::submit_heartbeat(
+			RuntimeOrigin::none(),
+			heartbeat.clone(),
+			signature.clone(),
+		));
+		assert_eq!(Services::unresponsive_operators(service_id, &charlie), None);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::OperatorResponsive {
+			operator: charlie.clone(),
+			service_id,
+		})]);
+
+		// a heartbeat cannot be replayed, nor signed with another key.
+		assert_err!(
+			Services::submit_heartbeat(RuntimeOrigin::none(), heartbeat, signature.clone()),
+			crate::Error::<Runtime>::StaleHeartbeat
+		);
+		System::set_block_number(13);
+		let heartbeat =
+			OperatorHeartbeat { service_id, operator: charlie.clone(), block_number: 13 };
+		let too_early =
+			sp_io::crypto::ecdsa_sign_prehashed(key_type, &key, &heartbeat.signing_hash()).unwrap();
+		assert_err!(
+			Services::submit_heartbeat(RuntimeOrigin::none(), heartbeat, too_early),
+			crate::Error::<Runtime>::HeartbeatTooFrequent
+		);
+		System::set_block_number(14);
+		let heartbeat =
+			OperatorHeartbeat { service_id, operator: charlie.clone(), block_number: 14 };
+		assert_err!(
+			Services::submit_heartbeat(RuntimeOrigin::none(), heartbeat, signature),
+			crate::Error::<Runtime>::InvalidHeartbeatSignature
+		);
+
+		// the heartbeats are no longer tracked once the service terminates.
+		assert_ok!(Services::terminate(RuntimeOrigin::signed(mock_pub_key(EVE)), service_id));
+		Services::on_idle(22, Weight::MAX);
+		assert_eq!(Services::operator_heartbeats(service_id, &bob), None);
+		assert_eq!(Services::unresponsive_operators(service_id, &charlie), None);
+	});
+}
+
+#[test]
+fn offchain_worker_sends_heartbeats() {
+	let mut ext = new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]);
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(TransactionPoolExt::new(pool));
+	let (offchain, _) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.heartbeat_interval = 10;
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone(), charlie.clone()]);
+
+		// only the key of bob is in the local keystore.
+		let key = sp_io::crypto::ecdsa_generate(tangle_crypto_primitives::ROLE_KEY_TYPE, None);
+		Operators::<Runtime>::insert(
+			0,
+			&bob,
+			OperatorPreferences { key, price_targets: Default::default() },
+		);
+
+		// the heartbeat is due halfway through the interval, minus the period of the worker.
+		System::set_block_number(2);
+		Services::offchain_worker(2);
+		assert!(pool_state.read().transactions.is_empty());
+
+		// the worker only runs every few blocks.
+		System::set_block_number(3);
+		Services::offchain_worker(3);
+		assert!(pool_state.read().transactions.is_empty());
+
+		System::set_block_number(5);
+		Services::offchain_worker(5);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		let RuntimeCall::Services(call) = tx.call else {
+			panic!("unexpected call");
+		};
+		assert_ok!(<Services as ValidateUnsigned>::validate_unsigned(
+			TransactionSource::Local,
+			&call,
+		));
+		let crate::Call::submit_heartbeat { heartbeat, signature } = call else {
+			panic!("unexpected call");
+		};
+		assert_eq!(heartbeat.operator, bob);
+		assert_eq!(heartbeat.block_number, 5);
+		assert_ok!(Services::submit_heartbeat(RuntimeOrigin::none(), heartbeat, signature));
+		assert_eq!(Services::operator_heartbeats(service_id, &bob), Some(5));
+	});
+}
+
+#[test]
+fn hook_weights_are_refunded() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.hook_gas_limit = MaxHookGasLimit::get() + 1;
+		assert_err!(
+			Services::create_blueprint(RuntimeOrigin::signed(alice), blueprint.clone()),
+			crate::Error::<Runtime>::HookGasLimitExceeded
+		);
+
+		blueprint.hook_gas_limit = 500_000;
+		assert_eq!(Services::hook_gas_limit(&blueprint), 500_000);
+		let service_id = deploy_blueprint(blueprint, vec![bob]);
+
+		// the worst case is charged up front, and the unused gas of the hooks is refunded.
+		let args = vec![Field::Uint8(2)];
+		let charged = crate::Call::<Runtime>::call { service_id, job: 0, args: args.clone() }
+			.get_dispatch_info()
+			.weight;
+		let post_info = Services::call(RuntimeOrigin::signed(eve), service_id, 0, args).unwrap();
+		let actual = post_info.actual_weight.unwrap();
+		assert!(actual.ref_time() < charged.ref_time());
+		assert!(actual.ref_time() > <() as crate::WeightInfo>::call().ref_time());
+	});
+}
+
+#[test]
+fn native_blueprint_manager() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.manager = BlueprintManager::Native(RecordingManagerId::get() + 1);
+		assert_err!(
+			Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint.clone()),
+			crate::Error::<Runtime>::NativeManagerNotFound
+		);
+
+		let create_blueprint = |manager| {
+			let mut blueprint = cggmp21_blueprint();
+			blueprint.manager = BlueprintManager::Native(manager);
+			let blueprint_id = Services::next_blueprint_id();
+			assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+			assert_ok!(Services::register(
+				RuntimeOrigin::signed(bob.clone()),
+				blueprint_id,
+				OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+				Default::default(),
+				0,
+			));
+			blueprint_id
+		};
+		let request = |blueprint_id, value| {
+			Services::request(
+				RuntimeOrigin::signed(eve.clone()),
+				blueprint_id,
+				vec![alice.clone()],
+				vec![bob.clone()],
+				Default::default(),
+				vec![WETH],
+				100,
+				value,
+				Default::default(),
+			)
+		};
+
+		// the requester is charged the fee of the manager, on top of the escrowed value.
+		let blueprint_id = create_blueprint(PermissiveManagerId::get());
+		let fee = PermissiveManagerFee::get();
+		let beneficiary = PermissiveManagerBeneficiary::get();
+		let eve_balance = Balances::free_balance(&eve);
+		assert!(request(blueprint_id, eve_balance - fee).is_err());
+		assert_eq!(Balances::free_balance(&eve), eve_balance);
+		assert_eq!(Balances::free_balance(&beneficiary), 0);
+
+		let service_id = Services::next_instance_id();
+		assert_ok!(request(blueprint_id, 50));
+		assert_eq!(Balances::free_balance(&eve), eve_balance - 50 - fee);
+		assert_eq!(Balances::free_balance(&beneficiary), fee);
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			Percent::from_percent(10),
+		));
+		assert!(Instances::<Runtime>::contains_key(service_id));
+		// only the value of the request is escrowed for the operators.
+		assert_eq!(Services::service_escrow(service_id).map(|escrow| escrow.value), Ok(50));
+
+		// everything else is allowed, without calling into the EVM.
+		let post_info = Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			vec![Field::Uint8(2)],
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as crate::WeightInfo>::call()));
+		let service = Services::services(service_id).unwrap();
+		assert_eq!(Services::query_slashing_origin(&service).unwrap().0, None);
+
+		// the hooks of the other manager are routed to it, which requires no deposit.
+		let blueprint_id = create_blueprint(RecordingManagerId::get());
+		let (request_id, service_id) =
+			(Services::next_service_request_id(), Services::next_instance_id());
+		assert_ok!(request(blueprint_id, 0));
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(bob.clone()),
+			request_id,
+			Percent::from_percent(10),
+		));
+
+		// the batches are passed to the manager at once.
+		let first_call_id = Services::next_job_call_id();
+		let calls = (0..3).map(|_| (0, vec![Field::Uint8(2)])).collect::<Vec<_>>();
+		assert_ok!(Services::call_batch(RuntimeOrigin::signed(eve.clone()), service_id, calls));
+		let key = Field::Bytes(vec![1; 33].try_into().unwrap());
+		let results = (first_call_id + 1..first_call_id + 3)
+			.map(|call_id| (call_id, vec![key.clone()]))
+			.collect::<Vec<_>>();
+		assert_err!(
+			Services::submit_results_batch(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				vec![(first_call_id, vec![key.clone()]), (first_call_id, vec![key.clone()])],
+			),
+			crate::Error::<Runtime>::JobResultAlreadySubmitted
+		);
+		assert_ok!(Services::submit_results_batch(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			results,
+		));
+		assert_eq!(NATIVE_MANAGER_BATCHES.with(|batches| batches.borrow().clone()), vec![3, 2]);
+
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Services(crate::Event::EvmLog { .. } | crate::Event::EvmReverted { .. })
+		)));
+	});
+}
+
+#[test]
+fn groth16_job_results() {
+	use ark_bn254::{Bn254, Fr};
+	use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+	use ark_groth16::Groth16;
+	use ark_relations::{
+		lc,
+		r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+	};
+	use ark_serialize::CanonicalSerialize;
+
+	/// Proves the knowledge of two factors of a public product.
+	#[derive(Clone, Copy)]
+	struct Factors {
+		a: Fr,
+		b: Fr,
+	}
+
+	impl ConstraintSynthesizer<Fr> for Factors {
+		fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+			let a = cs.new_witness_variable(|| Ok(self.a))?;
+			let b = cs.new_witness_variable(|| Ok(self.b))?;
+			let c = cs.new_input_variable(|| Ok(self.a * self.b))?;
+			cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + c)
+		}
+	}
+
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].result = bounded_vec![FieldType::Bytes, FieldType::Bytes];
+		blueprint.jobs[0].verifier = JobResultVerifier::Groth16 {
+			verifier: Groth16Verifier::Arkworks,
+			public_inputs: 0,
+			proof: 2,
+		};
+		assert_err!(
+			Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint.clone()),
+			crate::Error::<Runtime>::InvalidResultVerifier
+		);
+
+		blueprint.jobs[0].verifier = JobResultVerifier::Groth16 {
+			verifier: Groth16Verifier::Arkworks,
+			public_inputs: 0,
+			proof: 1,
+		};
+		let blueprint_id = Services::next_blueprint_id();
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+		let call_id = Services::next_job_call_id();
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			0,
+			vec![Field::Uint8(2)],
+		));
+
+		let mut rng = ark_std::test_rng();
+		let circuit = Factors { a: Fr::from(3u8), b: Fr::from(7u8) };
+		let (pk, vk) = Groth16::<Bn254>::setup(circuit, &mut rng).unwrap();
+		let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+		let mut key = Vec::new();
+		vk.serialize_compressed(&mut key).unwrap();
+		let mut proof_bytes = Vec::new();
+		proof.serialize_compressed(&mut proof_bytes).unwrap();
+		let result = |product: u8| {
+			let public_inputs =
+				tangle_primitives::verifier::from_field_elements(&[Fr::from(product)]).unwrap();
+			vec![
+				Field::Bytes(public_inputs.try_into().unwrap()),
+				Field::Bytes(proof_bytes.clone().try_into().unwrap()),
+			]
+		};
+
+		// the proof can not be checked until the blueprint owner stores the verifying key.
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				call_id,
+				result(21)
+			),
+			crate::Error::<Runtime>::VerifyingKeyNotFound
+		);
+		assert_err!(
+			Services::set_verifying_key(
+				RuntimeOrigin::signed(eve.clone()),
+				blueprint_id,
+				0,
+				key.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_err!(
+			Services::set_verifying_key(
+				RuntimeOrigin::signed(alice.clone()),
+				blueprint_id,
+				0,
+				vec![0; MaxVerifyingKeyLength::get() as usize + 1]
+			),
+			crate::Error::<Runtime>::MaxVerifyingKeyLengthExceeded
+		);
+		// the key must deserialize, without any trailing bytes.
+		assert_err!(
+			Services::set_verifying_key(
+				RuntimeOrigin::signed(alice.clone()),
+				blueprint_id,
+				0,
+				vec![0; 64]
+			),
+			crate::Error::<Runtime>::InvalidVerifyingKey
+		);
+		assert_err!(
+			Services::set_verifying_key(
+				RuntimeOrigin::signed(alice.clone()),
+				blueprint_id,
+				0,
+				key.iter().copied().chain([0]).collect()
+			),
+			crate::Error::<Runtime>::InvalidVerifyingKey
+		);
+		assert_ok!(Services::set_verifying_key(
+			RuntimeOrigin::signed(alice.clone()),
+			blueprint_id,
+			0,
+			key
+		));
+
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				call_id,
+				result(20)
+			),
+			crate::Error::<Runtime>::InvalidJobResultProof
+		);
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			call_id,
+			result(21)
+		));
+		assert!(JobResults::<Runtime>::contains_key(service_id, call_id));
+	});
+}
+
+#[test]
+fn group_signature_job_results() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[1].verifier = JobResultVerifier::GroupSignature { signature: 1 };
+		assert_err!(
+			Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint.clone()),
+			crate::Error::<Runtime>::InvalidResultVerifier
+		);
+
+		blueprint.jobs[1].verifier = JobResultVerifier::GroupSignature { signature: 0 };
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+		let keygen_call_id = Services::next_job_call_id();
+		assert_ok!(Services::call(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			KEYGEN_JOB_ID,
+			vec![Field::Uint8(1)],
+		));
+		let key_type = KeyTypeId(*b"mdkg");
+		let group_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			keygen_call_id,
+			vec![Field::Bytes(group_key.to_raw_vec().try_into().unwrap())],
 		));
 
-		let eve = mock_pub_key(EVE);
-		assert_ok!(Services::request(
+		let sign_call_id = Services::next_job_call_id();
+		assert_ok!(Services::call(
 			RuntimeOrigin::signed(eve.clone()),
-			0,
-			vec![alice.clone()],
-			vec![bob.clone(), charlie.clone(), dave.clone()],
-			Default::default(),
-			vec![WETH],
-			100,
-			0,
+			service_id,
+			SIGN_JOB_ID,
+			vec![
+				Field::Uint64(keygen_call_id),
+				Field::Bytes(b"hello".to_vec().try_into().unwrap())
+			],
 		));
-
-		assert_eq!(ServiceRequests::<Runtime>::iter_keys().collect::<Vec<_>>().len(), 1);
-		assert_ok!(Services::approve(
-			RuntimeOrigin::signed(bob.clone()),
+		let job_call = Services::job_calls(service_id, sign_call_id).unwrap();
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(
+			key_type,
+			&group_key,
+			&job_call.signing_hash(sign_call_id),
+		)
+		.unwrap();
+		let result = |signature: &[u8]| vec![Field::Bytes(signature.to_vec().try_into().unwrap())];
+
+		// the signature can not be checked until the service owner registers the group key.
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				sign_call_id,
+				result(&signature.0)
+			),
+			crate::Error::<Runtime>::GroupKeyNotFound
+		);
+		assert_err!(
+			Services::register_group_key(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				keygen_call_id,
+				0,
+				SignatureScheme::EcdsaSecp256k1
+			),
+			DispatchError::BadOrigin
+		);
+		assert_err!(
+			Services::register_group_key(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				keygen_call_id,
+				0,
+				SignatureScheme::SchnorrEd25519
+			),
+			crate::Error::<Runtime>::InvalidGroupKey
+		);
+		assert_ok!(Services::register_group_key(
+			RuntimeOrigin::signed(eve.clone()),
+			service_id,
+			keygen_call_id,
 			0,
-			Percent::from_percent(10)
+			SignatureScheme::EcdsaSecp256k1
 		));
+		assert_eq!(
+			Services::service_group_key(service_id).unwrap(),
+			GroupKey::EcdsaSecp256k1(group_key)
+		);
+		// the key can not be swapped once registered.
+		assert_err!(
+			Services::register_group_key(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				keygen_call_id,
+				0,
+				SignatureScheme::EcdsaSecp256k1
+			),
+			crate::Error::<Runtime>::GroupKeyAlreadyRegistered
+		);
 
-		assert_ok!(Services::approve(
-			RuntimeOrigin::signed(charlie.clone()),
-			0,
-			Percent::from_percent(10)
-		));
+		// the signing hash commits to the call id, so the signature can not be replayed.
+		let replayed = sp_io::crypto::ecdsa_sign_prehashed(
+			key_type,
+			&group_key,
+			&job_call.signing_hash(keygen_call_id),
+		)
+		.unwrap();
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				sign_call_id,
+				result(&replayed.0)
+			),
+			crate::Error::<Runtime>::InvalidJobResultSignature
+		);
 
-		assert_ok!(Services::approve(
-			RuntimeOrigin::signed(dave.clone()),
-			0,
-			Percent::from_percent(10)
+		let mut forged = signature.0;
+		forged[0] ^= 1;
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				sign_call_id,
+				result(&forged)
+			),
+			crate::Error::<Runtime>::InvalidJobResultSignature
+		);
+		assert_ok!(Services::submit_result(
+			RuntimeOrigin::signed(bob.clone()),
+			service_id,
+			sign_call_id,
+			result(&signature.0)
 		));
-		assert!(Instances::<Runtime>::contains_key(0));
-		assert_events(vec![RuntimeEvent::Services(crate::Event::ServiceInitiated {
-			owner: eve.clone(),
-			request_id: 0,
-			service_id: 0,
-			blueprint_id: 0,
-			assets: vec![WETH],
-		})]);
-
-		// now we can call the jobs
-		let keygen_job_call_id = 0;
+		assert!(JobResults::<Runtime>::contains_key(service_id, sign_call_id));
+	});
+}
 
-		assert_ok!(Services::call(
-			RuntimeOrigin::signed(eve.clone()),
-			0,
-			0,
-			bounded_vec![Field::Uint8(2)]
+#[test]
+fn invalid_field_types() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let blueprint_id = Services::next_blueprint_id();
+		assert_ok!(Services::create_blueprint(
+			RuntimeOrigin::signed(alice.clone()),
+			cggmp21_blueprint()
 		));
 
-		assert!(JobCalls::<Runtime>::contains_key(0, keygen_job_call_id));
-		// now we can set the job result
-		let key_type = KeyTypeId(*b"mdkg");
-		let dkg = sp_io::crypto::ecdsa_generate(key_type, None);
-		assert_ok!(Services::submit_result(
-			RuntimeOrigin::signed(bob.clone()),
-			0,
-			keygen_job_call_id,
-			bounded_vec![Field::Bytes(dkg.to_raw_vec().try_into().unwrap())],
+		// fixed bytes are between 1 and 32 bytes long, wherever they are nested.
+		let invalid_types = [
+			FieldType::FixedBytes(0),
+			FieldType::FixedBytes(33),
+			FieldType::List(Box::new(FieldType::FixedBytes(33))),
+			FieldType::Enum(bounded_vec![
+				Box::new(FieldType::Void),
+				Box::new(FieldType::FixedBytes(0))
+			]),
+			FieldType::Map(Box::new(FieldType::String), Box::new(FieldType::FixedBytes(64))),
+		];
+		for ty in invalid_types {
+			let mut blueprint = cggmp21_blueprint();
+			blueprint.jobs[1].result = bounded_vec![ty.clone()];
+			assert_err!(
+				Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint.clone()),
+				crate::Error::<Runtime>::InvalidFieldType
+			);
+			assert_err!(
+				Services::update_blueprint(
+					RuntimeOrigin::signed(alice.clone()),
+					blueprint_id,
+					blueprint
+				),
+				crate::Error::<Runtime>::InvalidFieldType
+			);
+
+			let mut blueprint = cggmp21_blueprint();
+			blueprint.request_params = bounded_vec![ty];
+			assert_err!(
+				Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint),
+				crate::Error::<Runtime>::InvalidFieldType
+			);
+		}
+
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[1].params =
+			bounded_vec![FieldType::FixedBytes(1), FieldType::FixedBytes(32)];
+		assert_ok!(Services::update_blueprint(
+			RuntimeOrigin::signed(alice.clone()),
+			blueprint_id,
+			blueprint
 		));
+	});
+}
 
-		// submit signing job
-		let _signing_job_call_id = 1;
-		let data_hash = sp_core::keccak_256(&[1; 32]);
+#[test]
+fn type_check_error_paths() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let bob = mock_pub_key(BOB);
+		let eve = mock_pub_key(EVE);
+		let mut blueprint = cggmp21_blueprint();
+		blueprint.jobs[0].params = bounded_vec![FieldType::List(Box::new(FieldType::Struct(
+			Box::new(FieldType::String),
+			bounded_vec![
+				(Box::new(FieldType::String), Box::new(FieldType::String)),
+				(Box::new(FieldType::String), Box::new(FieldType::Uint64)),
+			],
+		)))];
+		let service_id = deploy_blueprint(blueprint, vec![bob.clone()]);
+
+		let transfer = |amount: Field<ConstraintsOf<Runtime>, AccountId>| {
+			Field::Struct(
+				"Transfer".try_into().unwrap(),
+				bounded_vec![
+					("to".try_into().unwrap(), Box::new(Field::String("bob".try_into().unwrap()))),
+					("amount".try_into().unwrap(), Box::new(amount)),
+				],
+			)
+		};
+		let valid = vec![Field::List(bounded_vec![transfer(Field::Uint64(1))])];
+		let invalid =
+			vec![Field::List(bounded_vec![transfer(Field::Uint64(1)), transfer(Field::Uint32(2))])];
+		let error = TypeCheckError::ArgumentTypeMismatch {
+			index: 0,
+			expected: FieldKind::Uint64,
+			actual: FieldKind::Uint32,
+			path: bounded_vec![
+				TypeCheckPathSegment::Index(1),
+				TypeCheckPathSegment::Field("amount".try_into().unwrap()),
+			],
+		};
+
+		assert_err!(
+			Services::call(
+				RuntimeOrigin::signed(eve.clone()),
+				service_id,
+				KEYGEN_JOB_ID,
+				invalid.clone()
+			),
+			crate::Error::<Runtime>::TypeCheck(TypeCheckErrorSummary::ArgumentTypeMismatch {
+				index: 0
+			})
+		);
+		// the dry run reports the full error.
+		assert_eq!(
+			Services::type_check_job_call(service_id, KEYGEN_JOB_ID, invalid),
+			Ok(Some(error))
+		);
+		assert_eq!(
+			Services::type_check_job_call(service_id, KEYGEN_JOB_ID, vec![valid[0].clone(); 2]),
+			Ok(Some(TypeCheckError::TooManyArguments { expected: 1, actual: 2 }))
+		);
+		assert_eq!(
+			Services::type_check_job_call(service_id, KEYGEN_JOB_ID, valid.clone()),
+			Ok(None)
+		);
 
+		let call_id = Services::next_job_call_id();
 		assert_ok!(Services::call(
 			RuntimeOrigin::signed(eve.clone()),
-			0,
-			SIGN_JOB_ID,
-			bounded_vec![
-				Field::Uint64(keygen_job_call_id),
-				Field::Bytes(data_hash.to_vec().try_into().unwrap())
-			],
+			service_id,
+			KEYGEN_JOB_ID,
+			valid
 		));
-
-		// now we can set the job result
-		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &dkg, &data_hash).unwrap();
-		let mut signature_bytes = signature.to_raw_vec();
-		// fix the v value (it should be 27 or 28).
-		signature_bytes[64] += 27u8;
-		// For some reason, the signature is not being verified.
-		// in EVM, ecrecover is used to verify the signature, but it returns
-		// 0x000000000000000000000000000000000000000 as the address of the signer.
-		// even though the signature is correct, and we have the precomiles in the runtime.
-		//
-		// assert_ok!(Services::submit_result(
-		// 	RuntimeOrigin::signed(bob.clone()),
-		// 	0,
-		// 	signing_job_call_id,
-		// 	bounded_vec![Field::Bytes(signature_bytes.try_into().unwrap())],
-		// ));
+		assert_eq!(
+			Services::type_check_job_result(service_id, call_id, vec![Field::Uint8(1)]),
+			Ok(Some(TypeCheckError::ResultTypeMismatch {
+				index: 0,
+				expected: FieldKind::Bytes,
+				actual: FieldKind::Uint8,
+				path: Default::default(),
+			}))
+		);
+		assert_err!(
+			Services::submit_result(
+				RuntimeOrigin::signed(bob.clone()),
+				service_id,
+				call_id,
+				vec![Field::Uint8(1)],
+			),
+			crate::Error::<Runtime>::TypeCheck(TypeCheckErrorSummary::ResultTypeMismatch {
+				index: 0
+			})
+		);
 	});
 }
 
+#[test]
+fn job_result_policies() {
+	let submissions = [1, 2, 2, 1];
+	assert_eq!(JobResultPolicy::First.canonical_result(4, &submissions[..0]), None);
+	assert_eq!(JobResultPolicy::First.canonical_result(4, &submissions[..1]), Some(0));
+	assert_eq!(JobResultPolicy::All.canonical_result(2, &[1, 2]), None);
+	assert_eq!(JobResultPolicy::All.canonical_result(2, &[1, 1]), Some(0));
+	assert_eq!(JobResultPolicy::All.canonical_result(3, &[1, 1]), None);
+	let threshold = JobResultPolicy::Threshold { count: 2 };
+	assert_eq!(threshold.canonical_result(4, &submissions[..2]), None);
+	assert_eq!(threshold.canonical_result(4, &submissions[..3]), Some(1));
+	let majority = JobResultPolicy::MajorityVote { threshold: 3 };
+	assert_eq!(majority.canonical_result(4, &submissions[..2]), None);
+	assert_eq!(majority.canonical_result(4, &submissions[..3]), Some(1));
+	// ties are resolved in favor of the earliest submission.
+	assert_eq!(majority.canonical_result(4, &submissions), Some(0));
+}
+
 struct Deployment {
 	blueprint_id: u64,
 	service_id: u64,
@@ -707,6 +2997,7 @@ fn deploy() -> Deployment {
 		vec![WETH],
 		100,
 		0,
+		Default::default(),
 	));
 
 	assert_eq!(ServiceRequests::<Runtime>::iter_keys().collect::<Vec<_>>().len(), 1);
@@ -723,6 +3014,51 @@ fn deploy() -> Deployment {
 	Deployment { blueprint_id, service_id, bob_exposed_restake_percentage }
 }
 
+/// A Helper function that creates the given blueprint and a service instance of it run by the
+/// given operators.
+fn deploy_blueprint(
+	blueprint: ServiceBlueprint<ConstraintsOf<Runtime>>,
+	operators: Vec<AccountId>,
+) -> u64 {
+	let alice = mock_pub_key(ALICE);
+	let blueprint_id = Services::next_blueprint_id();
+	assert_ok!(Services::create_blueprint(RuntimeOrigin::signed(alice.clone()), blueprint));
+
+	for operator in &operators {
+		assert_ok!(Services::register(
+			RuntimeOrigin::signed(operator.clone()),
+			blueprint_id,
+			OperatorPreferences { key: zero_key(), price_targets: Default::default() },
+			Default::default(),
+			0,
+		));
+	}
+
+	let eve = mock_pub_key(EVE);
+	let service_id = Services::next_instance_id();
+	assert_ok!(Services::request(
+		RuntimeOrigin::signed(eve.clone()),
+		blueprint_id,
+		vec![alice.clone()],
+		operators.clone(),
+		Default::default(),
+		vec![WETH],
+		100,
+		0,
+		Default::default(),
+	));
+	for operator in &operators {
+		assert_ok!(Services::approve(
+			RuntimeOrigin::signed(operator.clone()),
+			service_id,
+			Percent::from_percent(10),
+		));
+	}
+
+	assert!(Instances::<Runtime>::contains_key(service_id));
+	service_id
+}
+
 #[test]
 fn unapplied_slash() {
 	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
@@ -778,6 +3114,103 @@ fn unapplied_slash() {
 	});
 }
 
+#[test]
+fn unapplied_slash_is_applied_after_defer_duration() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let Deployment { service_id, bob_exposed_restake_percentage, .. } = deploy();
+		let bob = mock_pub_key(BOB);
+		let slash_percent = Percent::from_percent(50);
+		let service = Services::services(service_id).unwrap();
+		let slashing_origin =
+			Services::query_slashing_origin(&service).map(|(o, _)| o.unwrap()).unwrap();
+
+		assert_ok!(Services::slash(
+			RuntimeOrigin::signed(slashing_origin.clone()),
+			bob.clone(),
+			service_id,
+			slash_percent
+		));
+		let bob_stake = <Runtime as Config>::OperatorDelegationManager::get_operator_stake(&bob);
+		let expected_slash_amount =
+			(slash_percent * bob_exposed_restake_percentage).mul_floor(bob_stake);
+
+		// The slash is still deferred.
+		let defer_duration = <Runtime as Config>::SlashDeferDuration::get();
+		CurrentRound::set(&(defer_duration - 1));
+		Services::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(UnappliedSlashes::<Runtime>::iter_keys().count(), 1);
+		assert!(AppliedSlashes::get().is_empty());
+
+		CurrentRound::set(&defer_duration);
+		Services::on_idle(System::block_number(), Weight::MAX);
+
+		assert_eq!(UnappliedSlashes::<Runtime>::iter_keys().count(), 0);
+		assert_eq!(AppliedSlashes::get(), vec![(bob.clone(), expected_slash_amount, vec![])]);
+		assert_events(vec![RuntimeEvent::Services(crate::Event::SlashApplied {
+			index: 0,
+			operator: bob,
+			amount: expected_slash_amount,
+			service_id,
+			era: 0,
+		})]);
+
+		// An applied slash can no longer be disputed.
+		assert_err!(
+			Services::dispute(RuntimeOrigin::signed(slashing_origin), 0, 0),
+			Error::<Runtime>::UnappliedSlashNotFound
+		);
+	});
+}
+
+#[test]
+fn oversized_slash_is_applied_across_blocks() {
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(1);
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+		let charlie = mock_pub_key(CHARLIE);
+		let dave = mock_pub_key(DAVE);
+		CurrentRound::set(&<Runtime as Config>::SlashDeferDuration::get());
+
+		let others =
+			vec![(alice.clone(), USDC, 1), (charlie.clone(), WETH, 2), (dave.clone(), USDC, 3)];
+		UnappliedSlashes::<Runtime>::insert(
+			0,
+			0,
+			UnappliedSlash {
+				service_id: 0,
+				operator: bob.clone(),
+				own: 10,
+				others: others.clone(),
+				reporters: vec![],
+				payout: 0,
+			},
+		);
+
+		// only the operator and two of the delegators fit in the weight.
+		let db = <Runtime as frame_system::Config>::DbWeight::get();
+		let weight = db
+			.reads_writes(2, 1)
+			.saturating_add(db.reads(1))
+			.saturating_add(db.reads_writes(2, 3))
+			.saturating_add(db.reads_writes(2, 2).saturating_mul(2));
+		assert_eq!(Services::apply_deferred_slashes(weight), weight);
+		assert_eq!(AppliedSlashes::get(), vec![(bob.clone(), 10, others[..2].to_vec())]);
+		let rest = Services::unapplied_slashes(0, 0).unwrap();
+		assert_eq!((rest.own, rest.others), (0, others[2..].to_vec()));
+		assert_eq!(UnappliedSlashesCursor::<Runtime>::get(), 0);
+
+		// the rest of the slash is applied with the next weight.
+		Services::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(
+			AppliedSlashes::get(),
+			vec![(bob.clone(), 10, others[..2].to_vec()), (bob, 0, others[2..].to_vec())]
+		);
+		assert_eq!(UnappliedSlashes::<Runtime>::iter_keys().count(), 0);
+	});
+}
+
 #[test]
 fn unapplied_slash_with_invalid_origin() {
 	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
@@ -941,3 +3374,113 @@ fn dispute_an_already_applied_slash() {
 		);
 	});
 }
+
+#[test]
+fn migrate_v0_to_v1() {
+	use crate::migrations::v1::*;
+	use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+	new_test_ext(vec![ALICE, BOB, CHARLIE, DAVE, EVE]).execute_with(|| {
+		System::set_block_number(5);
+		StorageVersion::new(0).put::<Services>();
+		let alice = mock_pub_key(ALICE);
+		let bob = mock_pub_key(BOB);
+
+		let blueprint = cggmp21_blueprint();
+		#[allow(deprecated)]
+		let old_blueprint = OldServiceBlueprint {
+			metadata: blueprint.metadata.clone(),
+			jobs: blueprint
+				.jobs
+				.iter()
+				.map(|job| OldJobDefinition {
+					metadata: job.metadata.clone(),
+					params: job.params.clone(),
+					result: job.result.clone(),
+					verifier: job.verifier.clone(),
+				})
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap(),
+			registration_hook: blueprint.registration_hook,
+			registration_params: blueprint.registration_params.clone(),
+			request_hook: blueprint.request_hook,
+			request_params: blueprint.request_params.clone(),
+			manager: blueprint.manager,
+			gadget: blueprint.gadget.clone(),
+		};
+		unhashed::put(&Blueprints::<Runtime>::hashed_key_for(0), &(alice.clone(), old_blueprint));
+		let old_request = OldServiceRequest::<ConstraintsOf<Runtime>, AccountId, u64, AssetId> {
+			blueprint: 0,
+			owner: alice.clone(),
+			permitted_callers: bounded_vec![],
+			assets: bounded_vec![USDC],
+			ttl: 100,
+			args: bounded_vec![],
+			operators_with_approval_state: bounded_vec![(bob.clone(), ApprovalState::Pending)],
+		};
+		unhashed::put(&ServiceRequests::<Runtime>::hashed_key_for(3), &old_request);
+		let old_service = OldService::<ConstraintsOf<Runtime>, AccountId, u64, AssetId> {
+			id: 7,
+			blueprint: 0,
+			owner: alice.clone(),
+			permitted_callers: bounded_vec![],
+			operators: bounded_vec![(bob.clone(), Percent::from_percent(50))],
+			assets: bounded_vec![USDC],
+			ttl: 100,
+		};
+		unhashed::put(&Instances::<Runtime>::hashed_key_for(7), &old_service);
+		let old_call = OldJobCall::<ConstraintsOf<Runtime>, AccountId> {
+			service_id: 7,
+			job: 0,
+			args: bounded_vec![Field::Uint8(2)],
+		};
+		unhashed::put(&JobCalls::<Runtime>::hashed_key_for(7, 0), &old_call);
+		unhashed::put(&JobCalls::<Runtime>::hashed_key_for(8, 0), &old_call);
+		let old_slash = OldUnappliedSlash::<AccountId, Balance> {
+			service_id: 7,
+			operator: bob.clone(),
+			own: 10,
+			others: vec![(alice.clone(), 5)],
+			reporters: vec![alice.clone()],
+			payout: 0,
+		};
+		unhashed::put(&UnappliedSlashes::<Runtime>::hashed_key_for(0, 0), &old_slash);
+
+		MigrateV0ToV1::<Runtime>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Services>(), 1);
+
+		assert_eq!(Services::blueprints(0), Ok((alice.clone(), blueprint.clone())));
+		assert_eq!(Services::blueprint_at_revision(0, 0), Ok(blueprint));
+		assert_eq!(Services::blueprint_revisions_of(0).unwrap().len(), 1);
+
+		let request = Services::service_requests(3).unwrap();
+		assert_eq!(request.blueprint_revision, 0);
+		assert_eq!(request.expiry, 5 + <Runtime as Config>::ServiceRequestTimeout::get());
+		assert!(ServiceRequestExpiries::<Runtime>::contains_key(request.expiry, 3));
+		assert_eq!(
+			request.operators_with_approval_state,
+			old_request.operators_with_approval_state
+		);
+
+		let service = Services::services(7).unwrap();
+		assert_eq!((service.start_block, service.end_block), (5, 105));
+		assert!(ServiceExpiries::<Runtime>::contains_key(105, 7));
+		assert_eq!(service.operators, old_service.operators);
+		assert_eq!(Services::service_escrow(7).unwrap().value, 0);
+
+		// the job calls are attributed to the owners of their services.
+		assert_eq!(Services::job_calls(7, 0).unwrap().caller, alice.clone());
+		assert!(!JobCalls::<Runtime>::contains_key(8, 0));
+
+		// the delegators of bob have no bonds anymore, so their slashes are dropped.
+		let slash = Services::unapplied_slashes(0, 0).unwrap();
+		assert_eq!((slash.operator, slash.own), (bob.clone(), 10));
+		assert!(slash.others.is_empty());
+
+		// the migration only runs once.
+		System::set_block_number(6);
+		MigrateV0ToV1::<Runtime>::on_runtime_upgrade();
+		assert_eq!(Services::services(7).unwrap().start_block, 5);
+	});
+}
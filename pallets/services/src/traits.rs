@@ -1,7 +1,10 @@
-use crate::Weight;
+use crate::{types::BalanceOf, Weight};
 use fp_evm::CallInfo;
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::{H160, U256};
+use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
+use tangle_primitives::services::{Field, OperatorPreferences};
 
 #[derive(Debug)]
 pub struct RunnerError<E: Into<sp_runtime::DispatchError>> {
@@ -40,3 +43,553 @@ pub trait EvmAddressMapping<A> {
 	/// Convert an account id to an address.
 	fn into_address(account_id: A) -> H160;
 }
+
+/// A blueprint manager implemented natively by the runtime, used by the blueprints whose manager
+/// is [`BlueprintManager::Native`](tangle_primitives::services::BlueprintManager::Native).
+///
+/// Each hook mirrors the function of the same name in the EVM manager interface, and receives
+/// the ID of the native manager the blueprint selected. The hooks return whether the action is
+/// allowed and the weight they consumed. By default, every action is allowed at no cost.
+#[allow(clippy::too_many_arguments)]
+pub trait BlueprintServiceManager<T: crate::Config> {
+	/// Returns whether a native manager with the given ID exists.
+	fn exists(manager: u32) -> bool;
+
+	/// Called upon a new operator registration on a blueprint.
+	fn on_register(
+		_manager: u32,
+		_preferences: &OperatorPreferences,
+		_registration_args: &[Field<T::Constraints, T::AccountId>],
+		_value: BalanceOf<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator unregistration on a blueprint.
+	fn on_unregister(
+		_manager: u32,
+		_preferences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator joining a running service.
+	fn on_operator_joined(
+		_manager: u32,
+		_service_id: u64,
+		_preferences: &OperatorPreferences,
+		_restaking_percent: u8,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator leaving a service.
+	fn on_operator_left(
+		_manager: u32,
+		_service_id: u64,
+		_preferences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator missing its heartbeat for a service.
+	fn on_operator_unresponsive(
+		_manager: u32,
+		_service_id: u64,
+		_preferences: &OperatorPreferences,
+		_last_seen: u64,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator updating its price targets on a blueprint.
+	fn on_update_price_targets(
+		_manager: u32,
+		_preferences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator approving a service request.
+	fn on_approve(
+		_manager: u32,
+		_preferences: &OperatorPreferences,
+		_request_id: u64,
+		_restaking_percent: u8,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon an operator rejecting a service request.
+	fn on_reject(
+		_manager: u32,
+		_preferences: &OperatorPreferences,
+		_request_id: u64,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon the owner cancelling a pending service request.
+	fn on_request_cancelled(
+		_manager: u32,
+		_request_id: u64,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon a new service request.
+	fn on_request(
+		_manager: u32,
+		_requester: &T::AccountId,
+		_request_id: u64,
+		_operators: &[OperatorPreferences],
+		_request_args: &[Field<T::Constraints, T::AccountId>],
+		_permitted_callers: &[T::AccountId],
+		_assets: &[T::AssetId],
+		_ttl: BlockNumberFor<T>,
+		_value: BalanceOf<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called when a service is initialized.
+	fn on_service_init(
+		_manager: u32,
+		_request_id: u64,
+		_service_id: u64,
+		_owner: &T::AccountId,
+		_permitted_callers: &[T::AccountId],
+		_assets: &[T::AssetId],
+		_ttl: BlockNumberFor<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called when a service is terminated.
+	fn on_service_termination(
+		_manager: u32,
+		_service_id: u64,
+		_owner: &T::AccountId,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called to check whether an account may call a job whose caller policy is
+	/// [`JobCallerPolicy::Manager`](tangle_primitives::services::JobCallerPolicy::Manager).
+	fn can_call(
+		_manager: u32,
+		_service_id: u64,
+		_job: u8,
+		_caller: &T::AccountId,
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon a job call.
+	fn on_job_call(
+		_manager: u32,
+		_service_id: u64,
+		_job: u8,
+		_job_call_id: u64,
+		_inputs: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon a job result submission.
+	fn on_job_result(
+		_manager: u32,
+		_service_id: u64,
+		_job: u8,
+		_job_call_id: u64,
+		_preferences: &OperatorPreferences,
+		_inputs: &[Field<T::Constraints, T::AccountId>],
+		_outputs: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<(bool, Weight), DispatchError> {
+		Ok((true, Weight::zero()))
+	}
+
+	/// Called upon a batch of job calls, with the job, the call ID and the inputs of each call.
+	///
+	/// By default, calls [`on_job_call`](Self::on_job_call) for each call of the batch.
+	fn on_job_call_batch(
+		manager: u32,
+		service_id: u64,
+		calls: &[(u8, u64, &[Field<T::Constraints, T::AccountId>])],
+	) -> Result<(bool, Weight), DispatchError> {
+		let mut weight = Weight::zero();
+		for (job, job_call_id, inputs) in calls {
+			let (allowed, hook_weight) =
+				Self::on_job_call(manager, service_id, *job, *job_call_id, inputs)?;
+			weight.saturating_accrue(hook_weight);
+			if !allowed {
+				return Ok((false, weight));
+			}
+		}
+		Ok((true, weight))
+	}
+
+	/// Called upon a batch of job results submitted by an operator, with the job, the call ID,
+	/// the inputs and the outputs of each result.
+	///
+	/// By default, calls [`on_job_result`](Self::on_job_result) for each result of the batch.
+	fn on_job_result_batch(
+		manager: u32,
+		service_id: u64,
+		preferences: &OperatorPreferences,
+		results: &[(
+			u8,
+			u64,
+			&[Field<T::Constraints, T::AccountId>],
+			&[Field<T::Constraints, T::AccountId>],
+		)],
+	) -> Result<(bool, Weight), DispatchError> {
+		let mut weight = Weight::zero();
+		for (job, job_call_id, inputs, outputs) in results {
+			let (allowed, hook_weight) = Self::on_job_result(
+				manager,
+				service_id,
+				*job,
+				*job_call_id,
+				preferences,
+				inputs,
+				outputs,
+			)?;
+			weight.saturating_accrue(hook_weight);
+			if !allowed {
+				return Ok((false, weight));
+			}
+		}
+		Ok((true, weight))
+	}
+
+	/// Returns the account allowed to slash the operators of a service, if any.
+	fn query_slashing_origin(
+		_manager: u32,
+		_service_id: u64,
+	) -> Result<(Option<T::AccountId>, Weight), DispatchError> {
+		Ok((None, Weight::zero()))
+	}
+
+	/// Returns the account allowed to dispute the slashes of a service, if any.
+	fn query_dispute_origin(
+		_manager: u32,
+		_service_id: u64,
+	) -> Result<(Option<T::AccountId>, Weight), DispatchError> {
+		Ok((None, Weight::zero()))
+	}
+}
+
+/// Composes several native blueprint managers, each hook being routed to the first manager of
+/// the tuple that [`exists`](BlueprintServiceManager::exists) with the ID the blueprint selected.
+///
+/// The hooks fail with [`NativeManagerNotFound`](crate::Error::NativeManagerNotFound) when no
+/// manager of the tuple has that ID.
+#[impl_trait_for_tuples::impl_for_tuples(1, 8)]
+#[allow(clippy::too_many_arguments)]
+impl<T: crate::Config> BlueprintServiceManager<T> for Tuple {
+	fn exists(manager: u32) -> bool {
+		for_tuples!( #( if Tuple::exists(manager) { return true; } )* );
+		false
+	}
+
+	fn on_register(
+		manager: u32,
+		preferences: &OperatorPreferences,
+		registration_args: &[Field<T::Constraints, T::AccountId>],
+		value: BalanceOf<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_register(manager, preferences, registration_args, value);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_unregister(
+		manager: u32,
+		preferences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_unregister(manager, preferences);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_operator_joined(
+		manager: u32,
+		service_id: u64,
+		preferences: &OperatorPreferences,
+		restaking_percent: u8,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_operator_joined(
+					manager,
+					service_id,
+					preferences,
+					restaking_percent,
+				);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_operator_left(
+		manager: u32,
+		service_id: u64,
+		preferences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_operator_left(manager, service_id, preferences);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_operator_unresponsive(
+		manager: u32,
+		service_id: u64,
+		preferences: &OperatorPreferences,
+		last_seen: u64,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_operator_unresponsive(manager, service_id, preferences, last_seen);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_update_price_targets(
+		manager: u32,
+		preferences: &OperatorPreferences,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_update_price_targets(manager, preferences);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_approve(
+		manager: u32,
+		preferences: &OperatorPreferences,
+		request_id: u64,
+		restaking_percent: u8,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_approve(manager, preferences, request_id, restaking_percent);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_reject(
+		manager: u32,
+		preferences: &OperatorPreferences,
+		request_id: u64,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_reject(manager, preferences, request_id);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_request_cancelled(
+		manager: u32,
+		request_id: u64,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_request_cancelled(manager, request_id);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_request(
+		manager: u32,
+		requester: &T::AccountId,
+		request_id: u64,
+		operators: &[OperatorPreferences],
+		request_args: &[Field<T::Constraints, T::AccountId>],
+		permitted_callers: &[T::AccountId],
+		assets: &[T::AssetId],
+		ttl: BlockNumberFor<T>,
+		value: BalanceOf<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_request(
+					manager,
+					requester,
+					request_id,
+					operators,
+					request_args,
+					permitted_callers,
+					assets,
+					ttl,
+					value,
+				);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_service_init(
+		manager: u32,
+		request_id: u64,
+		service_id: u64,
+		owner: &T::AccountId,
+		permitted_callers: &[T::AccountId],
+		assets: &[T::AssetId],
+		ttl: BlockNumberFor<T>,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_service_init(
+					manager,
+					request_id,
+					service_id,
+					owner,
+					permitted_callers,
+					assets,
+					ttl,
+				);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_service_termination(
+		manager: u32,
+		service_id: u64,
+		owner: &T::AccountId,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_service_termination(manager, service_id, owner);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn can_call(
+		manager: u32,
+		service_id: u64,
+		job: u8,
+		caller: &T::AccountId,
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::can_call(manager, service_id, job, caller);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_job_call(
+		manager: u32,
+		service_id: u64,
+		job: u8,
+		job_call_id: u64,
+		inputs: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_job_call(manager, service_id, job, job_call_id, inputs);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_job_result(
+		manager: u32,
+		service_id: u64,
+		job: u8,
+		job_call_id: u64,
+		preferences: &OperatorPreferences,
+		inputs: &[Field<T::Constraints, T::AccountId>],
+		outputs: &[Field<T::Constraints, T::AccountId>],
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_job_result(
+					manager,
+					service_id,
+					job,
+					job_call_id,
+					preferences,
+					inputs,
+					outputs,
+				);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_job_call_batch(
+		manager: u32,
+		service_id: u64,
+		calls: &[(u8, u64, &[Field<T::Constraints, T::AccountId>])],
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_job_call_batch(manager, service_id, calls);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn on_job_result_batch(
+		manager: u32,
+		service_id: u64,
+		preferences: &OperatorPreferences,
+		results: &[(
+			u8,
+			u64,
+			&[Field<T::Constraints, T::AccountId>],
+			&[Field<T::Constraints, T::AccountId>],
+		)],
+	) -> Result<(bool, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::on_job_result_batch(manager, service_id, preferences, results);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn query_slashing_origin(
+		manager: u32,
+		service_id: u64,
+	) -> Result<(Option<T::AccountId>, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::query_slashing_origin(manager, service_id);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+
+	fn query_dispute_origin(
+		manager: u32,
+		service_id: u64,
+	) -> Result<(Option<T::AccountId>, Weight), DispatchError> {
+		for_tuples!( #(
+			if Tuple::exists(manager) {
+				return Tuple::query_dispute_origin(manager, service_id);
+			}
+		)* );
+		Err(crate::Error::<T>::NativeManagerNotFound.into())
+	}
+}
@@ -15,9 +15,8 @@
 // along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use parity_scale_codec::HasCompact;
-use sp_std::prelude::*;
 use tangle_primitives::services::Constraints;
+pub use tangle_primitives::services::UnappliedSlash;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -34,6 +33,8 @@ pub type MaxOperatorsPerServiceOf<T> = <ConstraintsFor<T> as Constraints>::MaxOp
 
 pub type MaxAssetsPerServiceOf<T> = <ConstraintsFor<T> as Constraints>::MaxAssetsPerService;
 
+pub type MaxJobsPerServiceOf<T> = <ConstraintsFor<T> as Constraints>::MaxJobsPerService;
+
 /// Extract the constraints from the runtime.
 #[derive(RuntimeDebugNoBound, CloneNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
@@ -43,21 +44,14 @@ pub type MaxAssetsPerServiceOf<T> = <ConstraintsFor<T> as Constraints>::MaxAsset
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstraintsOf<T>(sp_std::marker::PhantomData<T>);
 
-/// A pending slash record. The value of the slash has been computed but not applied yet,
-/// rather deferred for several eras.
-#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+/// The fee escrowed by a job call, until the call gets a final result or fails.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
-pub struct UnappliedSlash<AccountId, Balance: HasCompact> {
-	/// The Service Instance Id on which the slash is applied.
-	pub service_id: u64,
-	/// The account ID of the offending operator.
-	pub operator: AccountId,
-	/// The operator's own slash.
-	pub own: Balance,
-	/// All other slashed restakers and amounts.
-	pub others: Vec<(AccountId, Balance)>,
-	/// Reporters of the offence; bounty payout recipients.
-	pub reporters: Vec<AccountId>,
-	/// The amount of payout.
-	pub payout: Balance,
+pub struct JobCallFee<AccountId, AssetId, Balance> {
+	/// The account that called the job and paid the fee.
+	pub payer: AccountId,
+	/// The asset the fee is paid in, `None` for the native currency.
+	pub asset: Option<AssetId>,
+	/// The escrowed amount.
+	pub amount: Balance,
 }
@@ -48,6 +48,30 @@ pub trait WeightInfo {
 	fn terminate() -> Weight;
 	fn call() -> Weight;
 	fn submit_result() -> Weight;
+	fn payout() -> Weight;
+	fn update_blueprint() -> Weight;
+	fn update_operator_revision() -> Weight;
+	fn transfer_blueprint_ownership() -> Weight;
+	fn deprecate_blueprint() -> Weight;
+	fn approve_operator_change(n: u32, ) -> Weight;
+	fn reject_operator_change() -> Weight;
+	fn set_job_caller_policy() -> Weight;
+	fn add_permitted_caller() -> Weight;
+	fn remove_permitted_caller() -> Weight;
+	fn call_batch(n: u32, ) -> Weight;
+	fn submit_results_batch(n: u32, ) -> Weight;
+	fn acknowledge_job_result() -> Weight;
+	fn heartbeat() -> Weight;
+	fn submit_heartbeat() -> Weight;
+	fn slash() -> Weight;
+	fn dispute() -> Weight;
+	fn cancel_request() -> Weight;
+	fn leave_service() -> Weight;
+	fn propose_operator_change(n: u32, ) -> Weight;
+	fn verify_result_proof(k: u32, ) -> Weight;
+	fn set_verifying_key(k: u32, ) -> Weight;
+	fn verify_result_signature() -> Weight;
+	fn register_group_key() -> Weight;
 }
 
 /// Weights for `pallet_services` using the Substrate node and recommended hardware.
@@ -66,12 +90,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn pre_register() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 5_460_000 picoseconds.
-		Weight::from_parts(5_670_000, 0)
+		Weight::from_parts(8_120_000, 3486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
 	/// Storage: `Services::Blueprints` (r:1 w:0)
 	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -87,6 +110,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Services::OperatorsProfile` (r:1 w:1)
 	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::RegistrationEscrow` (r:0 w:1)
+	/// Proof: `Services::RegistrationEscrow` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `569`
@@ -94,7 +119,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 44_980_000 picoseconds.
 		Weight::from_parts(45_741_000, 6509)
 			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Services::Blueprints` (r:1 w:0)
 	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -102,14 +127,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Services::OperatorsProfile` (r:1 w:1)
 	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::RegistrationEscrow` (r:1 w:1)
+	/// Proof: `Services::RegistrationEscrow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `326`
 		//  Estimated: `3791`
 		// Minimum execution time: 18_080_000 picoseconds.
 		Weight::from_parts(18_770_000, 3791)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Services::Blueprints` (r:1 w:0)
 	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -194,6 +223,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Services::UserServices` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Services::OperatorsProfile` (r:3 w:3)
 	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceCleanupQueue` (r:0 w:1)
+	/// Proof: `Services::ServiceCleanupQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn terminate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `671`
@@ -201,7 +232,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 32_630_000 picoseconds.
 		Weight::from_parts(33_430_000, 9086)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Services::Instances` (r:1 w:0)
 	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -255,6 +286,395 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout() -> Weight {
+		Weight::from_parts(58_240_000, 11350)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:1)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:1)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintRevisions` (r:0 w:1)
+	/// Proof: `Services::BlueprintRevisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_blueprint() -> Weight {
+		Weight::from_parts(24_870_000, 4062)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorBlueprintRevision` (r:0 w:1)
+	/// Proof: `Services::OperatorBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_operator_revision() -> Weight {
+		Weight::from_parts(14_520_000, 3807)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:1)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_blueprint_ownership() -> Weight {
+		Weight::from_parts(16_310_000, 4062)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:1)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deprecate_blueprint() -> Weight {
+		Weight::from_parts(15_940_000, 4062)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::OperatorChangeRequests` (r:1 w:1)
+	/// Proof: `Services::OperatorChangeRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:32 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:32 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorBlueprintRevision` (r:32 w:0)
+	/// Proof: `Services::OperatorBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorsProfile` (r:32 w:32)
+	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorExits` (r:32 w:32)
+	/// Proof: `Services::OperatorExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:32 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::OperatorHeartbeats` (r:0 w:32)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 32]`.
+	fn approve_operator_change(n: u32, ) -> Weight {
+		Weight::from_parts(23_610_000, 4386)
+			.saturating_add(Weight::from_parts(21_140_000, 2603).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::OperatorChangeRequests` (r:1 w:1)
+	/// Proof: `Services::OperatorChangeRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_operator_change() -> Weight {
+		Weight::from_parts(14_210_000, 3960)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_job_caller_policy() -> Weight {
+		Weight::from_parts(19_730_000, 5379)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_permitted_caller() -> Weight {
+		Weight::from_parts(14_860_000, 4212)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_permitted_caller() -> Weight {
+		Weight::from_parts(14_690_000, 4244)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::NextJobCallId` (r:1 w:1)
+	/// Proof: `Services::NextJobCallId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `BaseFee::BaseFeePerGas` (r:1 w:0)
+	/// Proof: `BaseFee::BaseFeePerGas` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:0)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Digest` (r:1 w:0)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobCalls` (r:0 w:256)
+	/// Proof: `Services::JobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 256]`.
+	fn call_batch(n: u32, ) -> Weight {
+		Weight::from_parts(12_410_000, 6509)
+			.saturating_add(Weight::from_parts(38_360_000, 5379).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCalls` (r:256 w:0)
+	/// Proof: `Services::JobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobResults` (r:256 w:256)
+	/// Proof: `Services::JobResults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::TimedOutJobCalls` (r:256 w:0)
+	/// Proof: `Services::TimedOutJobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `BaseFee::BaseFeePerGas` (r:1 w:0)
+	/// Proof: `BaseFee::BaseFeePerGas` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:0)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Digest` (r:1 w:0)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobResultSubmissions` (r:256 w:256)
+	/// Proof: `Services::JobResultSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobPruningCursor` (r:1 w:1)
+	/// Proof: `Services::JobPruningCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallFees` (r:256 w:256)
+	/// Proof: `Services::JobCallFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobPruningSchedule` (r:0 w:256)
+	/// Proof: `Services::JobPruningSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 256]`.
+	fn submit_results_batch(n: u32, ) -> Weight {
+		Weight::from_parts(16_830_000, 6509)
+			.saturating_add(Weight::from_parts(43_950_000, 5379).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCalls` (r:1 w:1)
+	/// Proof: `Services::JobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobResults` (r:1 w:1)
+	/// Proof: `Services::JobResults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallFees` (r:1 w:1)
+	/// Proof: `Services::JobCallFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobResultSubmissions` (r:0 w:1)
+	/// Proof: `Services::JobResultSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::TimedOutJobCalls` (r:0 w:1)
+	/// Proof: `Services::TimedOutJobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallResponseDeadlines` (r:1 w:1)
+	/// Proof: `Services::JobCallResponseDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallDeadlines` (r:0 w:1)
+	/// Proof: `Services::JobCallDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn acknowledge_job_result() -> Weight {
+		Weight::from_parts(41_980_000, 6218)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorHeartbeats` (r:1 w:1)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlines` (r:0 w:2)
+	/// Proof: `Services::HeartbeatDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlineCursor` (r:1 w:1)
+	/// Proof: `Services::HeartbeatDeadlineCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:1 w:1)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn heartbeat() -> Weight {
+		Weight::from_parts(24_310_000, 4726)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorHeartbeats` (r:1 w:1)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlines` (r:0 w:2)
+	/// Proof: `Services::HeartbeatDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlineCursor` (r:1 w:1)
+	/// Proof: `Services::HeartbeatDeadlineCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:1 w:1)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit_heartbeat() -> Weight {
+		Weight::from_parts(71_840_000, 5118)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::CurrentRound` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::CurrentRound` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::NextUnappliedSlashIndex` (r:1 w:1)
+	/// Proof: `Services::NextUnappliedSlashIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnappliedSlashes` (r:0 w:1)
+	/// Proof: `Services::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn slash() -> Weight {
+		Weight::from_parts(38_620_000, 6014)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Services::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `Services::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn dispute() -> Weight {
+		Weight::from_parts(24_970_000, 5483)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::ServiceRequests` (r:1 w:1)
+	/// Proof: `Services::ServiceRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceRequestExpiries` (r:0 w:1)
+	/// Proof: `Services::ServiceRequestExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::RequestEscrow` (r:1 w:1)
+	/// Proof: `Services::RequestEscrow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_request() -> Weight {
+		Weight::from_parts(52_180_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorExits` (r:2 w:1)
+	/// Proof: `Services::OperatorExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::OperatorHeartbeats` (r:0 w:1)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:0 w:1)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorsProfile` (r:1 w:1)
+	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn leave_service() -> Weight {
+		Weight::from_parts(96_740_000, 8829)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorChangeRequests` (r:1 w:0)
+	/// Proof: `Services::OperatorChangeRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorExits` (r:32 w:32)
+	/// Proof: `Services::OperatorExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:32 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::OperatorHeartbeats` (r:0 w:32)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:0 w:32)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorsProfile` (r:32 w:32)
+	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:32 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 32]`.
+	fn propose_operator_change(n: u32, ) -> Weight {
+		Weight::from_parts(28_410_000, 4919)
+			.saturating_add(Weight::from_parts(31_270_000, 2603).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::VerifyingKeys` (r:1 w:0)
+	/// Proof: `Services::VerifyingKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `k` is `[264, 8192]`.
+	fn verify_result_proof(k: u32, ) -> Weight {
+		Weight::from_parts(12_000_000_000, 3589)
+			.saturating_add(Weight::from_parts(2_500_000, 1).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::VerifyingKeys` (r:0 w:1)
+	/// Proof: `Services::VerifyingKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `k` is `[264, 8192]`.
+	fn set_verifying_key(k: u32, ) -> Weight {
+		Weight::from_parts(48_500_000, 4062)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::ServiceGroupKeys` (r:1 w:0)
+	/// Proof: `Services::ServiceGroupKeys` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn verify_result_signature() -> Weight {
+		Weight::from_parts(95_000_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceGroupKeys` (r:1 w:1)
+	/// Proof: `Services::ServiceGroupKeys` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobResults` (r:1 w:0)
+	/// Proof: `Services::JobResults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_group_key() -> Weight {
+		Weight::from_parts(32_000_000, 4589)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -272,12 +692,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn pre_register() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 5_460_000 picoseconds.
-		Weight::from_parts(5_670_000, 0)
+		Weight::from_parts(8_120_000, 3486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
 	/// Storage: `Services::Blueprints` (r:1 w:0)
 	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -293,6 +712,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Services::OperatorsProfile` (r:1 w:1)
 	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::RegistrationEscrow` (r:0 w:1)
+	/// Proof: `Services::RegistrationEscrow` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `569`
@@ -300,7 +721,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 44_980_000 picoseconds.
 		Weight::from_parts(45_741_000, 6509)
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Services::Blueprints` (r:1 w:0)
 	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -308,14 +729,18 @@ impl WeightInfo for () {
 	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Services::OperatorsProfile` (r:1 w:1)
 	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::RegistrationEscrow` (r:1 w:1)
+	/// Proof: `Services::RegistrationEscrow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `326`
 		//  Estimated: `3791`
 		// Minimum execution time: 18_080_000 picoseconds.
 		Weight::from_parts(18_770_000, 3791)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Services::Blueprints` (r:1 w:0)
 	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -400,6 +825,8 @@ impl WeightInfo for () {
 	/// Proof: `Services::UserServices` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Services::OperatorsProfile` (r:3 w:3)
 	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceCleanupQueue` (r:0 w:1)
+	/// Proof: `Services::ServiceCleanupQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn terminate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `671`
@@ -407,7 +834,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 32_630_000 picoseconds.
 		Weight::from_parts(33_430_000, 9086)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Services::Instances` (r:1 w:0)
 	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -461,4 +888,393 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout() -> Weight {
+		Weight::from_parts(58_240_000, 11350)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:1)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:1)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintRevisions` (r:0 w:1)
+	/// Proof: `Services::BlueprintRevisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_blueprint() -> Weight {
+		Weight::from_parts(24_870_000, 4062)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorBlueprintRevision` (r:0 w:1)
+	/// Proof: `Services::OperatorBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_operator_revision() -> Weight {
+		Weight::from_parts(14_520_000, 3807)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:1)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_blueprint_ownership() -> Weight {
+		Weight::from_parts(16_310_000, 4062)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:1)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deprecate_blueprint() -> Weight {
+		Weight::from_parts(15_940_000, 4062)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::OperatorChangeRequests` (r:1 w:1)
+	/// Proof: `Services::OperatorChangeRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:32 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:32 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorBlueprintRevision` (r:32 w:0)
+	/// Proof: `Services::OperatorBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorsProfile` (r:32 w:32)
+	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorExits` (r:32 w:32)
+	/// Proof: `Services::OperatorExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:32 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::OperatorHeartbeats` (r:0 w:32)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 32]`.
+	fn approve_operator_change(n: u32, ) -> Weight {
+		Weight::from_parts(23_610_000, 4386)
+			.saturating_add(Weight::from_parts(21_140_000, 2603).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::OperatorChangeRequests` (r:1 w:1)
+	/// Proof: `Services::OperatorChangeRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_operator_change() -> Weight {
+		Weight::from_parts(14_210_000, 3960)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_job_caller_policy() -> Weight {
+		Weight::from_parts(19_730_000, 5379)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_permitted_caller() -> Weight {
+		Weight::from_parts(14_860_000, 4212)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_permitted_caller() -> Weight {
+		Weight::from_parts(14_690_000, 4244)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::NextJobCallId` (r:1 w:1)
+	/// Proof: `Services::NextJobCallId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `BaseFee::BaseFeePerGas` (r:1 w:0)
+	/// Proof: `BaseFee::BaseFeePerGas` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:0)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Digest` (r:1 w:0)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobCalls` (r:0 w:256)
+	/// Proof: `Services::JobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 256]`.
+	fn call_batch(n: u32, ) -> Weight {
+		Weight::from_parts(12_410_000, 6509)
+			.saturating_add(Weight::from_parts(38_360_000, 5379).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCalls` (r:256 w:0)
+	/// Proof: `Services::JobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobResults` (r:256 w:256)
+	/// Proof: `Services::JobResults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::TimedOutJobCalls` (r:256 w:0)
+	/// Proof: `Services::TimedOutJobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `BaseFee::BaseFeePerGas` (r:1 w:0)
+	/// Proof: `BaseFee::BaseFeePerGas` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:0)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Digest` (r:1 w:0)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobResultSubmissions` (r:256 w:256)
+	/// Proof: `Services::JobResultSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobPruningCursor` (r:1 w:1)
+	/// Proof: `Services::JobPruningCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallFees` (r:256 w:256)
+	/// Proof: `Services::JobCallFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobPruningSchedule` (r:0 w:256)
+	/// Proof: `Services::JobPruningSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 256]`.
+	fn submit_results_batch(n: u32, ) -> Weight {
+		Weight::from_parts(16_830_000, 6509)
+			.saturating_add(Weight::from_parts(43_950_000, 5379).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCalls` (r:1 w:1)
+	/// Proof: `Services::JobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobResults` (r:1 w:1)
+	/// Proof: `Services::JobResults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallFees` (r:1 w:1)
+	/// Proof: `Services::JobCallFees` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobResultSubmissions` (r:0 w:1)
+	/// Proof: `Services::JobResultSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::TimedOutJobCalls` (r:0 w:1)
+	/// Proof: `Services::TimedOutJobCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallResponseDeadlines` (r:1 w:1)
+	/// Proof: `Services::JobCallResponseDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::JobCallDeadlines` (r:0 w:1)
+	/// Proof: `Services::JobCallDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn acknowledge_job_result() -> Weight {
+		Weight::from_parts(41_980_000, 6218)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorHeartbeats` (r:1 w:1)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlines` (r:0 w:2)
+	/// Proof: `Services::HeartbeatDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlineCursor` (r:1 w:1)
+	/// Proof: `Services::HeartbeatDeadlineCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:1 w:1)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn heartbeat() -> Weight {
+		Weight::from_parts(24_310_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorHeartbeats` (r:1 w:1)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlines` (r:0 w:2)
+	/// Proof: `Services::HeartbeatDeadlines` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::HeartbeatDeadlineCursor` (r:1 w:1)
+	/// Proof: `Services::HeartbeatDeadlineCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:1 w:1)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit_heartbeat() -> Weight {
+		Weight::from_parts(71_840_000, 5118)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::Operators` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MultiAssetDelegation::CurrentRound` (r:1 w:0)
+	/// Proof: `MultiAssetDelegation::CurrentRound` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::NextUnappliedSlashIndex` (r:1 w:1)
+	/// Proof: `Services::NextUnappliedSlashIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnappliedSlashes` (r:0 w:1)
+	/// Proof: `Services::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn slash() -> Weight {
+		Weight::from_parts(38_620_000, 6014)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Services::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `Services::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn dispute() -> Weight {
+		Weight::from_parts(24_970_000, 5483)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::ServiceRequests` (r:1 w:1)
+	/// Proof: `Services::ServiceRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceRequestExpiries` (r:0 w:1)
+	/// Proof: `Services::ServiceRequestExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::RequestEscrow` (r:1 w:1)
+	/// Proof: `Services::RequestEscrow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_request() -> Weight {
+		Weight::from_parts(52_180_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorExits` (r:2 w:1)
+	/// Proof: `Services::OperatorExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::OperatorHeartbeats` (r:0 w:1)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:0 w:1)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorsProfile` (r:1 w:1)
+	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:1 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn leave_service() -> Weight {
+		Weight::from_parts(96_740_000, 8829)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:1)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::LatestBlueprintRevision` (r:1 w:0)
+	/// Proof: `Services::LatestBlueprintRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorChangeRequests` (r:1 w:0)
+	/// Proof: `Services::OperatorChangeRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceEscrows` (r:1 w:1)
+	/// Proof: `Services::ServiceEscrows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorExits` (r:32 w:32)
+	/// Proof: `Services::OperatorExits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:32 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Services::OperatorHeartbeats` (r:0 w:32)
+	/// Proof: `Services::OperatorHeartbeats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::UnresponsiveOperators` (r:0 w:32)
+	/// Proof: `Services::UnresponsiveOperators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::OperatorsProfile` (r:32 w:32)
+	/// Proof: `Services::OperatorsProfile` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::Operators` (r:32 w:0)
+	/// Proof: `Services::Operators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 32]`.
+	fn propose_operator_change(n: u32, ) -> Weight {
+		Weight::from_parts(28_410_000, 4919)
+			.saturating_add(Weight::from_parts(31_270_000, 2603).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Services::VerifyingKeys` (r:1 w:0)
+	/// Proof: `Services::VerifyingKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `k` is `[264, 8192]`.
+	fn verify_result_proof(k: u32, ) -> Weight {
+		Weight::from_parts(12_000_000_000, 3589)
+			.saturating_add(Weight::from_parts(2_500_000, 1).saturating_mul(k.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Services::Blueprints` (r:1 w:0)
+	/// Proof: `Services::Blueprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::BlueprintStates` (r:1 w:0)
+	/// Proof: `Services::BlueprintStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::VerifyingKeys` (r:0 w:1)
+	/// Proof: `Services::VerifyingKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `k` is `[264, 8192]`.
+	fn set_verifying_key(k: u32, ) -> Weight {
+		Weight::from_parts(48_500_000, 4062)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(k.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Services::ServiceGroupKeys` (r:1 w:0)
+	/// Proof: `Services::ServiceGroupKeys` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn verify_result_signature() -> Weight {
+		Weight::from_parts(95_000_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Services::Instances` (r:1 w:0)
+	/// Proof: `Services::Instances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Services::ServiceGroupKeys` (r:1 w:1)
+	/// Proof: `Services::ServiceGroupKeys` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Services::JobResults` (r:1 w:0)
+	/// Proof: `Services::JobResults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_group_key() -> Weight {
+		Weight::from_parts(32_000_000, 4589)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -11,7 +11,7 @@ interface ServicesPrecompile {
     /// @notice Register an operator for a specific blueprint
     /// @param blueprint_id The ID of the blueprint to register for
     /// @param preferences The operator's preferences encoded as bytes
-    /// @param registration_args The registration arguments encoded as bytes
+    /// @param registration_args The registration arguments, `abi.encode`d in the order of the blueprint registration params
     function registerOperator(
         uint256 blueprint_id,
         bytes calldata preferences,
@@ -27,13 +27,15 @@ interface ServicesPrecompile {
     /// @param assets The list of assets to use for the service
     /// @param permitted_callers_data The permitted callers for the service encoded as bytes
     /// @param service_providers_data The service providers encoded as bytes
-    /// @param request_args_data The request arguments encoded as bytes
+    /// @param request_args_data The request arguments, `abi.encode`d in the order of the blueprint request params
+    /// @param caller_policies_data The caller policies of the jobs of the service encoded as bytes
     function requestService(
         uint256 blueprint_id,
         uint256[] calldata assets,
         bytes calldata permitted_callers_data,
         bytes calldata service_providers_data,
-        bytes calldata request_args_data
+        bytes calldata request_args_data,
+        bytes calldata caller_policies_data
     ) external;
 
     /// @notice Terminate a service
@@ -52,7 +54,7 @@ interface ServicesPrecompile {
     /// @notice Call a job in the service
     /// @param service_id The ID of the service
     /// @param job The job index (as uint8)
-    /// @param args_data The arguments of the job encoded as bytes
+    /// @param args_data The arguments of the job, `abi.encode`d in the order of the job params
     function callJob(
         uint256 service_id,
         uint8 job,
@@ -62,7 +64,7 @@ interface ServicesPrecompile {
     /// @notice Submit the result of a job call
     /// @param service_id The ID of the service
     /// @param call_id The ID of the call
-    /// @param result_data The result data encoded as bytes
+    /// @param result_data The result of the job, `abi.encode`d in the order of the job result fields
     function submitResult(
         uint256 service_id,
         uint256 call_id,
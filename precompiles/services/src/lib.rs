@@ -10,7 +10,9 @@ use sp_core::U256;
 use sp_runtime::traits::Dispatchable;
 use sp_runtime::Percent;
 use sp_std::{marker::PhantomData, vec::Vec};
-use tangle_primitives::services::{Field, OperatorPreferences, ServiceBlueprint};
+use tangle_primitives::services::{
+	Field, FieldType, JobCallerPolicy, OperatorPreferences, ServiceBlueprint,
+};
 
 #[cfg(test)]
 mod mock;
@@ -22,6 +24,26 @@ mod tests;
 /// Precompile for the `Services` pallet.
 pub struct ServicesPrecompile<Runtime>(PhantomData<Runtime>);
 
+type FieldOf<Runtime> = Field<
+	<Runtime as pallet_services::Config>::Constraints,
+	<Runtime as frame_system::Config>::AccountId,
+>;
+
+type JobCallerPolicyOf<Runtime> = JobCallerPolicy<
+	<Runtime as pallet_services::Config>::Constraints,
+	<Runtime as frame_system::Config>::AccountId,
+>;
+
+/// Decodes the ABI encoded fields of the given types, as built with `abi.encode` in Solidity.
+fn decode_fields<Runtime: pallet_services::Config>(
+	types: &[FieldType],
+	data: UnboundedBytes,
+	message: &'static str,
+) -> EvmResult<Vec<FieldOf<Runtime>>> {
+	let data: Vec<u8> = data.into();
+	Field::decode_from_ethabi(types, &data).map_err(|_| revert(message))
+}
+
 #[precompile_utils::precompile]
 impl<Runtime> ServicesPrecompile<Runtime>
 where
@@ -66,17 +88,18 @@ where
 
 		let blueprint_id: u64 = blueprint_id.as_u64();
 		let preferences: Vec<u8> = preferences.into();
-		let registration_args: Vec<u8> = registration_args.into();
 		let preferences: OperatorPreferences = Decode::decode(&mut &preferences[..])
 			.map_err(|_| revert("Invalid preferences data"))?;
 
-		let registration_args: Vec<Field<Runtime::Constraints, Runtime::AccountId>> =
-			if registration_args.is_empty() {
-				Vec::new()
-			} else {
-				Decode::decode(&mut &registration_args[..])
-					.map_err(|_| revert("Invalid registration arguments"))?
-			};
+		// Reading the blueprint, to decode the arguments against its registration params.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let (_, blueprint) = pallet_services::Pallet::<Runtime>::blueprints(blueprint_id)
+			.map_err(|_| revert("Blueprint not found"))?;
+		let registration_args = decode_fields::<Runtime>(
+			&blueprint.registration_params,
+			registration_args,
+			"Invalid registration arguments",
+		)?;
 		let value_bytes = {
 			let mut value_bytes = [0u8; core::mem::size_of::<U256>()];
 			value.to_little_endian(&mut value_bytes);
@@ -112,7 +135,7 @@ where
 	}
 
 	/// Request a new service.
-	#[precompile::public("requestService(uint256,uint256[],bytes,bytes,bytes)")]
+	#[precompile::public("requestService(uint256,uint256[],bytes,bytes,bytes,bytes)")]
 	fn request_service(
 		handle: &mut impl PrecompileHandle,
 		blueprint_id: U256,
@@ -120,6 +143,7 @@ where
 		permitted_callers_data: UnboundedBytes,
 		service_providers_data: UnboundedBytes,
 		request_args_data: UnboundedBytes,
+		caller_policies_data: UnboundedBytes,
 	) -> EvmResult {
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
@@ -127,7 +151,7 @@ where
 		let blueprint_id: u64 = blueprint_id.as_u64();
 		let permitted_callers_data: Vec<u8> = permitted_callers_data.into();
 		let service_providers_data: Vec<u8> = service_providers_data.into();
-		let request_args_data: Vec<u8> = request_args_data.into();
+		let caller_policies_data: Vec<u8> = caller_policies_data.into();
 
 		let permitted_callers: Vec<Runtime::AccountId> =
 			Decode::decode(&mut &permitted_callers_data[..])
@@ -137,9 +161,19 @@ where
 			Decode::decode(&mut &service_providers_data[..])
 				.map_err(|_| revert("Invalid service providers data"))?;
 
-		let request_args: Vec<Field<Runtime::Constraints, Runtime::AccountId>> =
-			Decode::decode(&mut &request_args_data[..])
-				.map_err(|_| revert("Invalid request arguments data"))?;
+		let caller_policies: Vec<(u8, JobCallerPolicyOf<Runtime>)> =
+			Decode::decode(&mut &caller_policies_data[..])
+				.map_err(|_| revert("Invalid caller policies data"))?;
+
+		// Reading the blueprint, to decode the arguments against its request params.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let (_, blueprint) = pallet_services::Pallet::<Runtime>::blueprints(blueprint_id)
+			.map_err(|_| revert("Blueprint not found"))?;
+		let request_args = decode_fields::<Runtime>(
+			&blueprint.request_params,
+			request_args_data,
+			"Invalid request arguments data",
+		)?;
 		let assets: Vec<Runtime::AssetId> =
 			assets.into_iter().map(|asset| asset.as_u32().into()).collect();
 
@@ -159,6 +193,7 @@ where
 			assets,
 			request_args,
 			value,
+			caller_policies,
 		};
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
@@ -239,13 +274,23 @@ where
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let service_id: u64 = service_id.as_u64();
-		let args: Vec<u8> = args_data.into();
-
-		let decoded_args: Vec<Field<Runtime::Constraints, Runtime::AccountId>> =
-			Decode::decode(&mut &args[..])
-				.map_err(|_| revert("Invalid job call arguments data"))?;
 
-		let call = pallet_services::Call::<Runtime>::call { service_id, job, args: decoded_args };
+		// Reading the service, its blueprint revision and its blueprint, to decode the arguments
+		// against the job params.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+		let service = pallet_services::Pallet::<Runtime>::services(service_id)
+			.map_err(|_| revert("Service not found"))?;
+		let blueprint = pallet_services::Pallet::<Runtime>::service_blueprint(&service)
+			.map_err(|_| revert("Blueprint not found"))?;
+		let job_def =
+			blueprint.jobs.get(usize::from(job)).ok_or_else(|| revert("Job not found"))?;
+		let args = decode_fields::<Runtime>(
+			&job_def.params,
+			args_data,
+			"Invalid job call arguments data",
+		)?;
+
+		let call = pallet_services::Call::<Runtime>::call { service_id, job, args };
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
@@ -264,16 +309,24 @@ where
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let service_id: u64 = service_id.as_u64();
 		let call_id: u64 = call_id.as_u64();
-		let result: Vec<u8> = result_data.into();
 
-		let decoded_result: Vec<Field<Runtime::Constraints, Runtime::AccountId>> =
-			Decode::decode(&mut &result[..]).map_err(|_| revert("Invalid job result data"))?;
-
-		let call = pallet_services::Call::<Runtime>::submit_result {
-			service_id,
-			call_id,
-			result: decoded_result,
-		};
+		// Reading the job call, the service, its blueprint revision and its blueprint, to decode
+		// the result against the job result types.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(4))?;
+		let job_call = pallet_services::Pallet::<Runtime>::job_calls(service_id, call_id)
+			.map_err(|_| revert("Job call not found"))?;
+		let service = pallet_services::Pallet::<Runtime>::services(service_id)
+			.map_err(|_| revert("Service not found"))?;
+		let blueprint = pallet_services::Pallet::<Runtime>::service_blueprint(&service)
+			.map_err(|_| revert("Blueprint not found"))?;
+		let job_def = blueprint
+			.jobs
+			.get(usize::from(job_call.job))
+			.ok_or_else(|| revert("Job not found"))?;
+		let result =
+			decode_fields::<Runtime>(&job_def.result, result_data, "Invalid job result data")?;
+
+		let call = pallet_services::Call::<Runtime>::submit_result { service_id, call_id, result };
 
 		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
 
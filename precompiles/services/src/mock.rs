@@ -24,7 +24,7 @@ use frame_support::pallet_prelude::Hooks;
 use frame_support::pallet_prelude::Weight;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU128, OneSessionHandler},
+	traits::{AsEnsureOriginWithArg, ConstU128, OneSessionHandler},
 };
 use mock_evm::MockedEvmRunner;
 use pallet_evm::GasWeightMapping;
@@ -37,7 +37,9 @@ use serde::Serialize;
 use sp_core::{self, sr25519, sr25519::Public as sr25519Public, ConstU32, RuntimeDebug, H160};
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt, KeystorePtr};
 use sp_runtime::{
-	testing::UintAuthorityId, traits::ConvertInto, AccountId32, BuildStorage, Perbill,
+	testing::{TestXt, UintAuthorityId},
+	traits::ConvertInto,
+	AccountId32, BuildStorage, Perbill,
 };
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -211,6 +213,16 @@ impl pallet_staking::Config for Runtime {
 	type DisablingStrategy = pallet_staking::UpToLimitDisablingStrategy;
 }
 
+type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
 parameter_types! {
 	pub const ServicesEVMAddress: H160 = H160([0x11; 20]);
 }
@@ -352,6 +364,27 @@ impl From<TestAccount> for sp_core::sr25519::Public {
 
 pub type AssetId = u32;
 
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type AssetIdParameter = AssetId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<10>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+}
+
 pub struct MockDelegationManager;
 impl tangle_primitives::traits::MultiAssetDelegationInfo<AccountId, Balance>
 	for MockDelegationManager
@@ -390,6 +423,14 @@ impl tangle_primitives::traits::MultiAssetDelegationInfo<AccountId, Balance>
 	) -> Vec<(AccountId, Balance, Self::AssetId)> {
 		Default::default()
 	}
+
+	fn slash_operator(
+		_operator: &AccountId,
+		_own: Balance,
+		_others: &[(AccountId, Self::AssetId, Balance)],
+	) -> Balance {
+		Default::default()
+	}
 }
 
 parameter_types! {
@@ -478,10 +519,23 @@ parameter_types! {
 	pub const SlashDeferDuration: u32 = 7;
 }
 
+parameter_types! {
+	pub const ServiceRequestTimeout: u64 = 10;
+	pub const MaxJobBatchSize: u32 = 10;
+	pub const JobRetentionPeriod: u64 = 20;
+	pub const MaxHookGasLimit: u64 = 1_000_000;
+	pub const MaxBatchHookGas: u64 = 10_000_000;
+	pub const PermissiveManagerId: u32 = 0;
+	pub const PermissiveManagerFee: Balance = 100;
+	pub PermissiveManagerBeneficiary: AccountId = mock_pub_key(100);
+	pub const MaxVerifyingKeyLength: u32 = 1024;
+}
+
 impl pallet_services::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Currency = Balances;
+	type Fungibles = Assets;
 	type AssetId = AssetId;
 	type PalletEVMAddress = ServicesEVMAddress;
 	type EvmRunner = MockedEvmRunner;
@@ -511,6 +565,17 @@ impl pallet_services::Config for Runtime {
 	type OperatorDelegationManager = MockDelegationManager;
 	type SlashDeferDuration = SlashDeferDuration;
 	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type ServiceRequestTimeout = ServiceRequestTimeout;
+	type MaxJobBatchSize = MaxJobBatchSize;
+	type JobRetentionPeriod = JobRetentionPeriod;
+	type MaxHookGasLimit = MaxHookGasLimit;
+	type MaxBatchHookGas = MaxBatchHookGas;
+	type MaxVerifyingKeyLength = MaxVerifyingKeyLength;
+	type NativeManager = pallet_services::PermissiveWithFee<
+		PermissiveManagerId,
+		PermissiveManagerFee,
+		PermissiveManagerBeneficiary,
+	>;
 	type WeightInfo = ();
 }
 
@@ -522,6 +587,7 @@ construct_runtime!(
 		System: frame_system,
 		Timestamp: pallet_timestamp,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Services: pallet_services,
 		EVM: pallet_evm,
 		Ethereum: pallet_ethereum,
@@ -3,6 +3,8 @@ use crate::mock_evm::PCall;
 use crate::mock_evm::PrecompilesValue;
 use pallet_services::types::ConstraintsOf;
 use pallet_services::Instances;
+use pallet_services::JobCalls;
+use pallet_services::JobResults;
 use pallet_services::Operators;
 use pallet_services::OperatorsProfile;
 use parity_scale_codec::Encode;
@@ -13,9 +15,12 @@ use sp_core::{H160, U256};
 use sp_runtime::bounded_vec;
 use sp_runtime::AccountId32;
 use tangle_primitives::services::BlueprintManager;
+use tangle_primitives::services::Field;
 use tangle_primitives::services::FieldType;
+use tangle_primitives::services::JobCallerPolicy;
 use tangle_primitives::services::JobDefinition;
 use tangle_primitives::services::JobMetadata;
+use tangle_primitives::services::JobResultPolicy;
 use tangle_primitives::services::JobResultVerifier;
 use tangle_primitives::services::PriceTargets;
 use tangle_primitives::services::ServiceMetadata;
@@ -71,6 +76,9 @@ fn cggmp21_blueprint() -> ServiceBlueprint<ConstraintsOf<Runtime>> {
 				params: bounded_vec![FieldType::Uint8],
 				result: bounded_vec![FieldType::Bytes],
 				verifier: JobResultVerifier::Evm(CGGMP21_BLUEPRINT),
+				result_policy: JobResultPolicy::First,
+				response_window: None,
+				fee: None,
 			},
 			JobDefinition {
 				metadata: JobMetadata { name: "sign".try_into().unwrap(), ..Default::default() },
@@ -78,6 +86,9 @@ fn cggmp21_blueprint() -> ServiceBlueprint<ConstraintsOf<Runtime>> {
 				result: bounded_vec![FieldType::Bytes],
 				#[allow(deprecated)]
 				verifier: JobResultVerifier::Evm(CGGMP21_BLUEPRINT),
+				result_policy: JobResultPolicy::First,
+				response_window: None,
+				fee: None,
 			},
 		],
 		registration_hook: ServiceRegistrationHook::Evm(CGGMP21_BLUEPRINT),
@@ -85,6 +96,9 @@ fn cggmp21_blueprint() -> ServiceBlueprint<ConstraintsOf<Runtime>> {
 		request_hook: ServiceRequestHook::Evm(CGGMP21_BLUEPRINT),
 		request_params: bounded_vec![],
 		gadget: Default::default(),
+		exit_notice_period: 0,
+		heartbeat_interval: 0,
+		hook_gas_limit: 0,
 	}
 }
 #[test]
@@ -179,7 +193,7 @@ fn test_request_service() {
 				PCall::register_operator {
 					blueprint_id: U256::from(0),
 					preferences: UnboundedBytes::from(preferences_data),
-					registration_args: UnboundedBytes::from(vec![0u8]),
+					registration_args: UnboundedBytes::from(Vec::new()),
 				},
 			)
 			.execute_returns(());
@@ -187,7 +201,9 @@ fn test_request_service() {
 		// Finally, request the service
 		let permitted_callers_data: Vec<AccountId32> = vec![TestAccount::Alex.into()];
 		let service_providers_data: Vec<AccountId32> = vec![TestAccount::Bob.into()];
-		let request_args_data = vec![0u8];
+		let request_args_data = Vec::new();
+		let caller_policies_data: Vec<(u8, JobCallerPolicy<ConstraintsOf<Runtime>, AccountId32>)> =
+			vec![(1, JobCallerPolicy::OwnerOnly)];
 
 		PrecompilesValue::get()
 			.prepare_test(
@@ -198,6 +214,7 @@ fn test_request_service() {
 					permitted_callers_data: UnboundedBytes::from(permitted_callers_data.encode()),
 					service_providers_data: UnboundedBytes::from(service_providers_data.encode()),
 					request_args_data: UnboundedBytes::from(request_args_data),
+					caller_policies_data: UnboundedBytes::from(caller_policies_data.encode()),
 					assets: [WETH].into_iter().map(Into::into).collect(),
 				},
 			)
@@ -214,6 +231,9 @@ fn test_request_service() {
 
 		// Ensure the service instance is created
 		assert!(Instances::<Runtime>::contains_key(0));
+		let service = Instances::<Runtime>::get(0).unwrap();
+		assert_eq!(service.caller_policy(0), JobCallerPolicy::PermittedCallers);
+		assert_eq!(service.caller_policy(1), JobCallerPolicy::OwnerOnly);
 	});
 }
 
@@ -246,7 +266,7 @@ fn test_unregister_operator() {
 				PCall::register_operator {
 					blueprint_id: U256::from(0),
 					preferences: UnboundedBytes::from(preferences_data),
-					registration_args: UnboundedBytes::from(vec![0u8]),
+					registration_args: UnboundedBytes::from(Vec::new()),
 				},
 			)
 			.execute_returns(());
@@ -295,14 +315,14 @@ fn test_terminate_service() {
 				PCall::register_operator {
 					blueprint_id: U256::from(0),
 					preferences: UnboundedBytes::from(preferences_data),
-					registration_args: UnboundedBytes::from(vec![0u8]),
+					registration_args: UnboundedBytes::from(Vec::new()),
 				},
 			)
 			.execute_returns(());
 
 		let permitted_callers_data: Vec<AccountId32> = vec![TestAccount::Alex.into()];
 		let service_providers_data: Vec<AccountId32> = vec![TestAccount::Bob.into()];
-		let request_args_data = vec![0u8];
+		let request_args_data = Vec::new();
 
 		PrecompilesValue::get()
 			.prepare_test(
@@ -313,6 +333,7 @@ fn test_terminate_service() {
 					permitted_callers_data: UnboundedBytes::from(permitted_callers_data.encode()),
 					service_providers_data: UnboundedBytes::from(service_providers_data.encode()),
 					request_args_data: UnboundedBytes::from(request_args_data),
+					caller_policies_data: UnboundedBytes::from(Vec::<u8>::new().encode()),
 					assets: [WETH].into_iter().map(Into::into).collect(),
 				},
 			)
@@ -342,3 +363,118 @@ fn test_terminate_service() {
 		assert!(!Instances::<Runtime>::contains_key(0));
 	});
 }
+
+#[test]
+fn test_call_job_and_submit_result() {
+	ExtBuilder.build().execute_with(|| {
+		let blueprint_data = cggmp21_blueprint();
+
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::create_blueprint {
+					blueprint_data: UnboundedBytes::from(blueprint_data.encode()),
+				},
+			)
+			.execute_returns(());
+
+		let preferences_data = OperatorPreferences {
+			key: zero_key(),
+			price_targets: price_targets(MachineKind::Large),
+		}
+		.encode();
+
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Bob,
+				H160::from_low_u64_be(1),
+				PCall::register_operator {
+					blueprint_id: U256::from(0),
+					preferences: UnboundedBytes::from(preferences_data),
+					registration_args: UnboundedBytes::from(Vec::new()),
+				},
+			)
+			.execute_returns(());
+
+		let permitted_callers_data: Vec<AccountId32> = vec![TestAccount::Alex.into()];
+		let service_providers_data: Vec<AccountId32> = vec![TestAccount::Bob.into()];
+
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::request_service {
+					blueprint_id: U256::from(0),
+					permitted_callers_data: UnboundedBytes::from(permitted_callers_data.encode()),
+					service_providers_data: UnboundedBytes::from(service_providers_data.encode()),
+					request_args_data: UnboundedBytes::from(Vec::new()),
+					caller_policies_data: UnboundedBytes::from(Vec::<u8>::new().encode()),
+					assets: [WETH].into_iter().map(Into::into).collect(),
+				},
+			)
+			.execute_returns(());
+
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Bob,
+				H160::from_low_u64_be(1),
+				PCall::approve { request_id: U256::from(0), restaking_percent: 10 },
+			)
+			.execute_returns(());
+
+		// the arguments are checked against the job params, here a single `uint8`.
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::call_job {
+					service_id: U256::from(0),
+					job: 0,
+					args_data: UnboundedBytes::from(ethabi::encode(&[ethabi::Token::Uint(
+						ethabi::Uint::from(256),
+					)])),
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid job call arguments data");
+
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Alex,
+				H160::from_low_u64_be(1),
+				PCall::call_job {
+					service_id: U256::from(0),
+					job: 0,
+					args_data: UnboundedBytes::from(ethabi::encode(&[ethabi::Token::Uint(
+						ethabi::Uint::from(2),
+					)])),
+				},
+			)
+			.execute_returns(());
+
+		assert_eq!(
+			JobCalls::<Runtime>::get(0, 0).unwrap().args.into_inner(),
+			vec![Field::Uint8(2)]
+		);
+
+		let key = vec![1u8; 33];
+		PrecompilesValue::get()
+			.prepare_test(
+				TestAccount::Bob,
+				H160::from_low_u64_be(1),
+				PCall::submit_result {
+					service_id: U256::from(0),
+					call_id: U256::from(0),
+					result_data: UnboundedBytes::from(ethabi::encode(&[ethabi::Token::Bytes(
+						key.clone(),
+					)])),
+				},
+			)
+			.execute_returns(());
+
+		assert_eq!(
+			JobResults::<Runtime>::get(0, 0).unwrap().result.into_inner(),
+			vec![Field::Bytes(key.try_into().unwrap())]
+		);
+	});
+}
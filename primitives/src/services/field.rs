@@ -17,9 +17,10 @@
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, string::ToString, vec::Vec};
 use frame_support::pallet_prelude::*;
+use parity_scale_codec::DecodeAll;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_core::RuntimeDebug;
+use sp_core::{RuntimeDebug, U256};
 use sp_std::{boxed::Box, vec};
 
 use super::Constraints;
@@ -112,6 +113,29 @@ pub enum Field<C: Constraints, AccountId> {
 		BoundedString<C::MaxFieldsSize>,
 		BoundedVec<(BoundedString<C::MaxFieldsSize>, Box<Field<C, AccountId>>), C::MaxFieldsSize>,
 	),
+	/// Represents a u128 Number.
+	#[codec(index = 15)]
+	Uint128(u128),
+	/// Represents a i128 Number.
+	#[codec(index = 16)]
+	Int128(i128),
+	/// Represents a u256 Number.
+	#[codec(index = 17)]
+	Uint256(U256),
+	/// Represents a fixed-size byte array of at most 32 bytes, like a `H160` or a `H256`.
+	#[codec(index = 18)]
+	FixedBytes(BoundedVec<u8, ConstU32<32>>),
+	/// Represents a variant of an enum
+	///
+	/// The variant is represented by its index and its value, which is [`Field::None`] for
+	/// variants without a value.
+	#[codec(index = 19)]
+	Enum(u8, Box<Field<C, AccountId>>),
+	/// Represents a map of keys to values
+	///
+	/// The map is represented as a list of entries, in the order in which they were supplied.
+	#[codec(index = 20)]
+	Map(BoundedVec<(Field<C, AccountId>, Field<C, AccountId>), C::MaxFieldsSize>),
 	// NOTE: Special types starts from 100
 	/// A special type for AccountId
 	#[codec(index = 100)]
@@ -139,6 +163,12 @@ impl<C: Constraints, AccountId: core::fmt::Debug> core::fmt::Debug for Field<C,
 			Self::Bytes(arg0) => f.debug_tuple("bytes").field(arg0).finish(),
 			Self::Array(arg0) => f.debug_tuple("array").field(arg0).finish(),
 			Self::List(arg0) => f.debug_tuple("list").field(arg0).finish(),
+			Self::Uint128(arg0) => f.debug_tuple("uint128").field(arg0).finish(),
+			Self::Int128(arg0) => f.debug_tuple("int128").field(arg0).finish(),
+			Self::Uint256(arg0) => f.debug_tuple("uint256").field(arg0).finish(),
+			Self::FixedBytes(arg0) => f.debug_tuple("fixed_bytes").field(arg0).finish(),
+			Self::Enum(arg0, arg1) => f.debug_tuple("enum").field(arg0).field(arg1).finish(),
+			Self::Map(arg0) => f.debug_map().entries(arg0.iter().map(|(k, v)| (k, v))).finish(),
 			Self::AccountId(arg0) => f.debug_tuple("account").field(arg0).finish(),
 			Self::Struct(name, fields) => {
 				let mut debug_struct = f.debug_struct(&format!("struct({})", name));
@@ -169,6 +199,12 @@ impl<C: Constraints, AccountId: PartialEq> PartialEq for Field<C, AccountId> {
 			(Self::Bytes(l0), Self::Bytes(r0)) => l0 == r0,
 			(Self::Array(l0), Self::Array(r0)) => l0 == r0,
 			(Self::List(l0), Self::List(r0)) => l0 == r0,
+			(Self::Uint128(l0), Self::Uint128(r0)) => l0 == r0,
+			(Self::Int128(l0), Self::Int128(r0)) => l0 == r0,
+			(Self::Uint256(l0), Self::Uint256(r0)) => l0 == r0,
+			(Self::FixedBytes(l0), Self::FixedBytes(r0)) => l0 == r0,
+			(Self::Enum(l0, l1), Self::Enum(r0, r1)) => l0 == r0 && l1 == r1,
+			(Self::Map(l0), Self::Map(r0)) => l0 == r0,
 			(Self::AccountId(l0), Self::AccountId(r0)) => l0 == r0,
 			(Self::Struct(l_name, l_fields), Self::Struct(r_name, r_fields)) => {
 				if l_name != r_name || l_fields.len() != r_fields.len() {
@@ -206,6 +242,12 @@ impl<C: Constraints, AccountId: Clone> Clone for Field<C, AccountId> {
 			Self::Array(arg0) => Self::Array(arg0.clone()),
 			Self::List(arg0) => Self::List(arg0.clone()),
 			Self::Struct(arg0, arg1) => Self::Struct(arg0.clone(), arg1.clone()),
+			Self::Uint128(arg0) => Self::Uint128(*arg0),
+			Self::Int128(arg0) => Self::Int128(*arg0),
+			Self::Uint256(arg0) => Self::Uint256(*arg0),
+			Self::FixedBytes(arg0) => Self::FixedBytes(arg0.clone()),
+			Self::Enum(arg0, arg1) => Self::Enum(*arg0, arg1.clone()),
+			Self::Map(arg0) => Self::Map(arg0.clone()),
 			Self::AccountId(arg0) => Self::AccountId(arg0.clone()),
 		}
 	}
@@ -228,6 +270,9 @@ impl_from! {
 	i32 => Int32,
 	u64 => Uint64,
 	i64 => Int64,
+	u128 => Uint128,
+	i128 => Int128,
+	U256 => Uint256,
 	BoundedVec<u8, C::MaxFieldsSize> => Bytes,
 	BoundedString<C::MaxFieldsSize> => String,
 	BoundedVec<Self, C::MaxFieldsSize> => List
@@ -286,12 +331,98 @@ pub enum FieldType {
 	/// A limit of 32 fields is set for the struct.
 	#[codec(index = 15)]
 	Struct(Box<FieldType>, BoundedVec<(Box<FieldType>, Box<FieldType>), ConstU32<32>>),
+	/// A Field of `u128` type.
+	#[codec(index = 16)]
+	Uint128,
+	/// A Field of `i128` type.
+	#[codec(index = 17)]
+	Int128,
+	/// A Field of `U256` type.
+	#[codec(index = 18)]
+	Uint256,
+	/// A Field of `[u8; N]` type, with N at most 32.
+	#[codec(index = 19)]
+	FixedBytes(u8),
+	/// An Enum whose variants hold values of type [`FieldType`].
+	/// Variants without a value are of type [`FieldType::Void`].
+	/// A limit of 32 variants is set for the enum.
+	#[codec(index = 20)]
+	Enum(BoundedVec<Box<FieldType>, ConstU32<32>>),
+	/// A Map of keys of type [`FieldType`] to values of type [`FieldType`].
+	#[codec(index = 21)]
+	Map(Box<FieldType>, Box<FieldType>),
 	// NOTE: Special types starts from 100
 	/// A special type for AccountId
 	#[codec(index = 100)]
 	AccountId,
 }
 
+impl FieldType {
+	/// Whether the type, and the types nested in it, can describe a field, i.e. the length of
+	/// every [`FieldType::FixedBytes`] is between 1 and 32.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			FieldType::FixedBytes(len) => (1..=32).contains(len),
+			FieldType::Optional(ty) | FieldType::Array(_, ty) | FieldType::List(ty) => {
+				ty.is_valid()
+			},
+			FieldType::Struct(name, fields) => {
+				name.is_valid() && fields.iter().all(|(key, ty)| key.is_valid() && ty.is_valid())
+			},
+			FieldType::Enum(variants) => variants.iter().all(|ty| ty.is_valid()),
+			FieldType::Map(key, value) => key.is_valid() && value.is_valid(),
+			_ => true,
+		}
+	}
+
+	/// Returns the ethabi ParamType of the fields of this type, matching their encoding with
+	/// [`Field::encode_to_ethabi`].
+	pub fn to_ethabi_param_type(&self) -> ethabi::ParamType {
+		use ethabi::ParamType;
+
+		match self {
+			FieldType::Void => ParamType::Tuple(Vec::new()),
+			FieldType::Bool => ParamType::Bool,
+			FieldType::Uint8 => ParamType::Uint(8),
+			FieldType::Int8 => ParamType::Int(8),
+			FieldType::Uint16 => ParamType::Uint(16),
+			FieldType::Int16 => ParamType::Int(16),
+			FieldType::Uint32 => ParamType::Uint(32),
+			FieldType::Int32 => ParamType::Int(32),
+			FieldType::Uint64 => ParamType::Uint(64),
+			FieldType::Int64 => ParamType::Int(64),
+			FieldType::Uint128 => ParamType::Uint(128),
+			FieldType::Int128 => ParamType::Int(128),
+			FieldType::Uint256 => ParamType::Uint(256),
+			FieldType::String => ParamType::String,
+			FieldType::Bytes => ParamType::Bytes,
+			FieldType::FixedBytes(len) => ParamType::FixedBytes(usize::from(*len)),
+			FieldType::Optional(ty) => ty.to_ethabi_param_type(),
+			FieldType::Array(len, ty) => {
+				ParamType::FixedArray(Box::new(ty.to_ethabi_param_type()), *len as usize)
+			},
+			FieldType::List(ty) => ParamType::Array(Box::new(ty.to_ethabi_param_type())),
+			// each field is a (name, value) pair.
+			FieldType::Struct(_, fields) => ParamType::Tuple(
+				fields
+					.iter()
+					.map(|(_, ty)| {
+						ParamType::Tuple(vec![ParamType::String, ty.to_ethabi_param_type()])
+					})
+					.collect(),
+			),
+			// the variant index, and the ethabi encoding of its value.
+			FieldType::Enum(_) => ParamType::Tuple(vec![ParamType::Uint(8), ParamType::Bytes]),
+			FieldType::Map(key, value) => ParamType::Array(Box::new(ParamType::Tuple(vec![
+				key.to_ethabi_param_type(),
+				value.to_ethabi_param_type(),
+			]))),
+			// the SCALE encoding of the account id.
+			FieldType::AccountId => ParamType::Bytes,
+		}
+	}
+}
+
 impl<C: Constraints, AccountId> PartialEq<FieldType> for Field<C, AccountId> {
 	fn eq(&self, other: &FieldType) -> bool {
 		match (self, other) {
@@ -319,14 +450,36 @@ impl<C: Constraints, AccountId> PartialEq<FieldType> for Field<C, AccountId> {
 						.zip(fields_b)
 						.all(|((_, v_a), (_, v_b))| v_a.as_ref().eq(v_b))
 			},
+			(Self::Uint128(_), FieldType::Uint128) => true,
+			(Self::Int128(_), FieldType::Int128) => true,
+			(Self::Uint256(_), FieldType::Uint256) => true,
+			(Self::FixedBytes(a), FieldType::FixedBytes(len)) => a.len() == usize::from(*len),
+			(Self::Enum(index, value), FieldType::Enum(variants)) => variants
+				.get(usize::from(*index))
+				.map_or(false, |ty| match (value.as_ref(), &**ty) {
+					(Self::None, FieldType::Void) => true,
+					(value, ty) => value.eq(ty),
+				}),
+			(Self::Map(entries), FieldType::Map(key, value)) => {
+				entries.iter().all(|(k, v)| k.eq(key.as_ref()) && v.eq(value.as_ref()))
+			},
 			_ => false,
 		}
 	}
 }
 
-impl<C: Constraints, AccountId: Clone> From<Field<C, AccountId>> for FieldType {
-	fn from(val: Field<C, AccountId>) -> Self {
-		match val {
+/// Infers the type of a field from its value.
+///
+/// Fails with the (nested) value that no [`FieldType`] can describe, i.e. a struct with more
+/// than 32 fields, or a variant past the 32 variants of an enum.
+impl<C: Constraints, AccountId: Clone> TryFrom<Field<C, AccountId>> for FieldType {
+	type Error = Field<C, AccountId>;
+
+	fn try_from(val: Field<C, AccountId>) -> Result<Self, Self::Error> {
+		let first_type = |values: &[Field<C, AccountId>]| {
+			values.first().cloned().map(FieldType::try_from).unwrap_or(Ok(FieldType::Void))
+		};
+		let ty = match val {
 			Field::None => FieldType::Optional(Box::new(FieldType::Void)),
 			Field::Bool(_) => FieldType::Bool,
 			Field::Uint8(_) => FieldType::Uint8,
@@ -339,29 +492,47 @@ impl<C: Constraints, AccountId: Clone> From<Field<C, AccountId>> for FieldType {
 			Field::Int64(_) => FieldType::Int64,
 			Field::String(_) => FieldType::String,
 			Field::Bytes(_) => FieldType::Bytes,
-			Field::Array(a) => FieldType::Array(
-				a.len() as u64,
-				Box::new(a.first().cloned().map(Into::into).unwrap_or(FieldType::Void)),
-			),
-			Field::List(a) => FieldType::List(Box::new(
-				a.first().cloned().map(Into::into).unwrap_or(FieldType::Void),
-			)),
+			Field::Array(ref a) => FieldType::Array(a.len() as u64, Box::new(first_type(a)?)),
+			Field::List(ref a) => FieldType::List(Box::new(first_type(a)?)),
 			Field::AccountId(_) => FieldType::AccountId,
-			Field::Struct(_, fields) => FieldType::Struct(
+			Field::Struct(_, ref fields) => FieldType::Struct(
 				Box::new(FieldType::String),
 				fields
 					.iter()
 					.map(|(_, field_value)| {
-						(
-							Box::new(FieldType::String),
-							Box::new(FieldType::from(field_value.as_ref().clone())),
-						)
+						let ty = FieldType::try_from(field_value.as_ref().clone())?;
+						Ok((Box::new(FieldType::String), Box::new(ty)))
 					})
-					.collect::<Vec<_>>()
+					.collect::<Result<Vec<_>, _>>()?
 					.try_into()
-					.expect("Field count should not exceed MaxFieldsSize"),
+					.map_err(|_| val.clone())?,
 			),
-		}
+			Field::Uint128(_) => FieldType::Uint128,
+			Field::Int128(_) => FieldType::Int128,
+			Field::Uint256(_) => FieldType::Uint256,
+			Field::FixedBytes(ref a) => FieldType::FixedBytes(a.len() as u8),
+			Field::Enum(index, ref value) => {
+				let ty = match value.as_ref() {
+					Field::None => FieldType::Void,
+					value => FieldType::try_from(value.clone())?,
+				};
+				FieldType::Enum(
+					(0..=index)
+						.map(|i| Box::new(if i == index { ty.clone() } else { FieldType::Void }))
+						.collect::<Vec<_>>()
+						.try_into()
+						.map_err(|_| val.clone())?,
+				)
+			},
+			Field::Map(ref entries) => {
+				let (key, value) = match entries.first().cloned() {
+					Some((k, v)) => (FieldType::try_from(k)?, FieldType::try_from(v)?),
+					None => (FieldType::Void, FieldType::Void),
+				};
+				FieldType::Map(Box::new(key), Box::new(value))
+			},
+		};
+		Ok(ty)
 	}
 }
 
@@ -373,19 +544,21 @@ impl<'a, C: Constraints, AccountId: Encode + Clone> From<&'a Field<C, AccountId>
 			Field::None => ethabi::Token::Tuple(Vec::new()),
 			Field::Bool(val) => ethabi::Token::Bool(*val),
 			Field::Uint8(val) => ethabi::Token::Uint((*val).into()),
-			Field::Int8(val) => ethabi::Token::Int((*val).into()),
+			Field::Int8(val) => ethabi::Token::Int(int_to_ethabi((*val).into())),
 			Field::Uint16(val) => ethabi::Token::Uint((*val).into()),
-			Field::Int16(val) => ethabi::Token::Int((*val).into()),
+			Field::Int16(val) => ethabi::Token::Int(int_to_ethabi((*val).into())),
 			Field::Uint32(val) => ethabi::Token::Uint((*val).into()),
-			Field::Int32(val) => ethabi::Token::Int((*val).into()),
+			Field::Int32(val) => ethabi::Token::Int(int_to_ethabi((*val).into())),
 			Field::Uint64(val) => ethabi::Token::Uint((*val).into()),
-			Field::Int64(val) => ethabi::Token::Int((*val).into()),
+			Field::Int64(val) => ethabi::Token::Int(int_to_ethabi((*val).into())),
 			Field::String(val) => ethabi::Token::String(val.to_string()),
-			Field::Bytes(val) => ethabi::Token::FixedBytes(val.to_vec()),
-			Field::Array(val) => ethabi::Token::Array(val.into_iter().map(Into::into).collect()),
+			Field::Bytes(val) => ethabi::Token::Bytes(val.to_vec()),
+			Field::Array(val) => {
+				ethabi::Token::FixedArray(val.into_iter().map(Into::into).collect())
+			},
 			Field::List(val) => ethabi::Token::Array(val.into_iter().map(Into::into).collect()),
 			Field::AccountId(val) => ethabi::Token::Bytes(val.encode()),
-			Field::Struct(_, fields) => ethabi::Token::Array(
+			Field::Struct(_, fields) => ethabi::Token::Tuple(
 				fields
 					.into_iter()
 					.map(|(field_name, field_value)| {
@@ -396,10 +569,34 @@ impl<'a, C: Constraints, AccountId: Encode + Clone> From<&'a Field<C, AccountId>
 					})
 					.collect(),
 			),
+			Field::Uint128(val) => ethabi::Token::Uint((*val).into()),
+			Field::Int128(val) => ethabi::Token::Int(int_to_ethabi(*val)),
+			Field::Uint256(val) => ethabi::Token::Uint(ethabi::Uint(val.0)),
+			Field::FixedBytes(val) => ethabi::Token::FixedBytes(val.to_vec()),
+			Field::Enum(index, value) => ethabi::Token::Tuple(vec![
+				ethabi::Token::Uint((*index).into()),
+				ethabi::Token::Bytes(Field::encode_to_ethabi(sp_std::slice::from_ref(&**value))),
+			]),
+			Field::Map(entries) => ethabi::Token::Array(
+				entries
+					.into_iter()
+					.map(|(key, value)| ethabi::Token::Tuple(vec![key.into(), value.into()]))
+					.collect(),
+			),
 		}
 	}
 }
 
+/// Converts a signed integer to its two's complement representation on 256 bits.
+fn int_to_ethabi(val: i128) -> ethabi::Int {
+	let abs = ethabi::Int::from(val.unsigned_abs());
+	if val.is_negative() {
+		abs.overflowing_neg().0
+	} else {
+		abs
+	}
+}
+
 impl<C: Constraints, AccountId: Clone + Encode> From<Field<C, AccountId>> for ethabi::Token {
 	fn from(value: Field<C, AccountId>) -> Self {
 		(&value).into()
@@ -434,6 +631,159 @@ impl<C: Constraints, AccountId: Clone + Encode> Field<C, AccountId> {
 	}
 }
 
+impl<C: Constraints, AccountId: Clone + Encode + Decode> Field<C, AccountId> {
+	/// Decode the fields of the given types from ethabi bytes.
+	///
+	/// This is the inverse of [`Self::encode_to_ethabi`], so that the arguments of a job can be
+	/// built with `abi.encode` in Solidity. An optional field is decoded as its inner value, as
+	/// a `None` value can not be told apart from a value in ethabi.
+	pub fn decode_from_ethabi(
+		types: &[FieldType],
+		data: &[u8],
+	) -> Result<Vec<Self>, ethabi::Error> {
+		if types.is_empty() {
+			return if data.is_empty() { Ok(Vec::new()) } else { Err(ethabi::Error::InvalidData) };
+		}
+		let params: Vec<_> = types.iter().map(FieldType::to_ethabi_param_type).collect();
+		let tokens = ethabi::decode(&params, data)?;
+		types
+			.iter()
+			.zip(tokens)
+			.map(|(ty, token)| Self::from_ethabi_token(ty, token))
+			.collect()
+	}
+
+	/// Converts a `ethabi::Token` to a field of the given type.
+	pub fn from_ethabi_token(ty: &FieldType, token: ethabi::Token) -> Result<Self, ethabi::Error> {
+		use ethabi::Token;
+
+		let field = match (ty, token) {
+			(FieldType::Void, Token::Tuple(values)) if values.is_empty() => Self::None,
+			(FieldType::Bool, Token::Bool(val)) => Self::Bool(val),
+			(FieldType::Uint8, Token::Uint(val)) => Self::Uint8(uint_from_ethabi(val)?),
+			(FieldType::Int8, Token::Int(val)) => Self::Int8(int_from_ethabi(val)?),
+			(FieldType::Uint16, Token::Uint(val)) => Self::Uint16(uint_from_ethabi(val)?),
+			(FieldType::Int16, Token::Int(val)) => Self::Int16(int_from_ethabi(val)?),
+			(FieldType::Uint32, Token::Uint(val)) => Self::Uint32(uint_from_ethabi(val)?),
+			(FieldType::Int32, Token::Int(val)) => Self::Int32(int_from_ethabi(val)?),
+			(FieldType::Uint64, Token::Uint(val)) => Self::Uint64(uint_from_ethabi(val)?),
+			(FieldType::Int64, Token::Int(val)) => Self::Int64(int_from_ethabi(val)?),
+			(FieldType::Uint128, Token::Uint(val)) => Self::Uint128(uint_from_ethabi(val)?),
+			(FieldType::Int128, Token::Int(val)) => Self::Int128(int_from_ethabi(val)?),
+			(FieldType::Uint256, Token::Uint(val)) => Self::Uint256(U256(val.0)),
+			(FieldType::String, Token::String(val)) => {
+				Self::String(val.try_into().map_err(|_| ethabi::Error::InvalidData)?)
+			},
+			(FieldType::Bytes, Token::Bytes(val)) => {
+				Self::Bytes(val.try_into().map_err(|_| ethabi::Error::InvalidData)?)
+			},
+			(FieldType::FixedBytes(len), Token::FixedBytes(val))
+				if val.len() == usize::from(*len) =>
+			{
+				Self::FixedBytes(val.try_into().map_err(|_| ethabi::Error::InvalidData)?)
+			},
+			(FieldType::Optional(ty), token) => Self::from_ethabi_token(ty, token)?,
+			(FieldType::Array(len, ty), Token::FixedArray(values))
+				if values.len() as u64 == *len =>
+			{
+				Self::Array(Self::from_ethabi_tokens(ty, values)?)
+			},
+			(FieldType::List(ty), Token::Array(values)) => {
+				Self::List(Self::from_ethabi_tokens(ty, values)?)
+			},
+			(FieldType::Struct(_, fields), Token::Tuple(values))
+				if values.len() == fields.len() =>
+			{
+				let fields = fields
+					.iter()
+					.zip(values)
+					.map(|((_, ty), value)| match value {
+						Token::Tuple(pair) => match <[Token; 2]>::try_from(pair) {
+							Ok([Token::String(name), value]) => Ok((
+								BoundedString::try_from(name)
+									.map_err(|_| ethabi::Error::InvalidData)?,
+								Box::new(Self::from_ethabi_token(ty, value)?),
+							)),
+							_ => Err(ethabi::Error::InvalidData),
+						},
+						_ => Err(ethabi::Error::InvalidData),
+					})
+					.collect::<Result<Vec<_>, _>>()?;
+				Self::Struct(
+					Default::default(),
+					fields.try_into().map_err(|_| ethabi::Error::InvalidData)?,
+				)
+			},
+			(FieldType::Enum(variants), Token::Tuple(values)) => {
+				let Ok([Token::Uint(index), Token::Bytes(value)]) = <[Token; 2]>::try_from(values)
+				else {
+					return Err(ethabi::Error::InvalidData);
+				};
+				let index: u8 = uint_from_ethabi(index)?;
+				let ty = variants.get(usize::from(index)).ok_or(ethabi::Error::InvalidData)?;
+				let value = match &**ty {
+					FieldType::Void if value.is_empty() => Self::None,
+					ty => {
+						let mut values =
+							Self::decode_from_ethabi(sp_std::slice::from_ref(ty), &value)?;
+						values.pop().ok_or(ethabi::Error::InvalidData)?
+					},
+				};
+				Self::Enum(index, Box::new(value))
+			},
+			(FieldType::Map(key, value), Token::Array(entries)) => {
+				let entries = entries
+					.into_iter()
+					.map(|entry| match entry {
+						Token::Tuple(pair) => match <[Token; 2]>::try_from(pair) {
+							Ok([k, v]) => Ok((
+								Self::from_ethabi_token(key, k)?,
+								Self::from_ethabi_token(value, v)?,
+							)),
+							Err(_) => Err(ethabi::Error::InvalidData),
+						},
+						_ => Err(ethabi::Error::InvalidData),
+					})
+					.collect::<Result<Vec<_>, _>>()?;
+				Self::Map(entries.try_into().map_err(|_| ethabi::Error::InvalidData)?)
+			},
+			(FieldType::AccountId, Token::Bytes(val)) => Self::AccountId(
+				AccountId::decode_all(&mut &val[..]).map_err(|_| ethabi::Error::InvalidData)?,
+			),
+			_ => return Err(ethabi::Error::InvalidData),
+		};
+		Ok(field)
+	}
+
+	/// Converts a list of `ethabi::Token`s to fields of the given type.
+	fn from_ethabi_tokens(
+		ty: &FieldType,
+		tokens: Vec<ethabi::Token>,
+	) -> Result<BoundedVec<Self, C::MaxFieldsSize>, ethabi::Error> {
+		tokens
+			.into_iter()
+			.map(|token| Self::from_ethabi_token(ty, token))
+			.collect::<Result<Vec<_>, _>>()?
+			.try_into()
+			.map_err(|_| ethabi::Error::InvalidData)
+	}
+}
+
+/// Converts a ethabi unsigned integer to a narrower unsigned integer.
+fn uint_from_ethabi<N: TryFrom<u128>>(val: ethabi::Uint) -> Result<N, ethabi::Error> {
+	let val = u128::try_from(val).map_err(|_| ethabi::Error::InvalidData)?;
+	N::try_from(val).map_err(|_| ethabi::Error::InvalidData)
+}
+
+/// Converts a ethabi signed integer, in two's complement on 256 bits, to a narrower signed
+/// integer.
+fn int_from_ethabi<N: TryFrom<i128>>(val: ethabi::Int) -> Result<N, ethabi::Error> {
+	let abs = if val.bit(255) { val.overflowing_neg().0 } else { val };
+	let abs = u128::try_from(abs).map_err(|_| ethabi::Error::InvalidData)?;
+	let val = if val.bit(255) { 0i128.checked_sub_unsigned(abs) } else { i128::try_from(abs).ok() };
+	N::try_from(val.ok_or(ethabi::Error::InvalidData)?).map_err(|_| ethabi::Error::InvalidData)
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(S))]
 #[cfg_attr(feature = "std", derive(Serialize), serde(transparent), serde(bound = ""))]
@@ -570,3 +920,117 @@ impl<'de, S: Get<u32>> serde::Deserialize<'de> for BoundedString<S> {
 		})?
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::tests::TestField;
+	use frame_support::bounded_vec;
+
+	#[test]
+	fn field_types_of_values() {
+		let variant = TestField::Enum(2, Box::new(TestField::Uint8(7)));
+		let ty = FieldType::try_from(variant.clone()).unwrap();
+		assert_eq!(
+			ty,
+			FieldType::Enum(bounded_vec![
+				Box::new(FieldType::Void),
+				Box::new(FieldType::Void),
+				Box::new(FieldType::Uint8),
+			])
+		);
+		assert!(variant == ty);
+		let variant = TestField::Enum(1, Box::new(TestField::None));
+		assert!(variant == FieldType::try_from(variant.clone()).unwrap());
+
+		// an enum declares at most 32 variants.
+		let variant = TestField::Enum(32, Box::new(TestField::None));
+		assert_eq!(FieldType::try_from(variant.clone()), Err(variant.clone()));
+		let list = TestField::List(bounded_vec![variant.clone()]);
+		assert_eq!(FieldType::try_from(list), Err(variant));
+	}
+
+	#[test]
+	fn wide_field_types_ethabi() {
+		assert_eq!(
+			TestField::Int128(-2).into_ethabi_token(),
+			ethabi::Token::Int(ethabi::Int::MAX - ethabi::Int::one())
+		);
+		assert_eq!(
+			TestField::Uint256(U256::MAX).into_ethabi_token(),
+			ethabi::Token::Uint(ethabi::Uint::MAX)
+		);
+		assert_eq!(
+			TestField::Enum(1, Box::new(TestField::Bool(true))).into_ethabi_token(),
+			ethabi::Token::Tuple(vec![
+				ethabi::Token::Uint(1.into()),
+				ethabi::Token::Bytes(ethabi::encode(&[ethabi::Token::Bool(true)]))
+			])
+		);
+		assert_eq!(
+			TestField::Map(bounded_vec![(TestField::Uint8(1), TestField::Int8(2))])
+				.into_ethabi_token(),
+			ethabi::Token::Array(vec![ethabi::Token::Tuple(vec![
+				ethabi::Token::Uint(1.into()),
+				ethabi::Token::Int(2.into())
+			])])
+		);
+	}
+
+	#[test]
+	fn fields_ethabi_roundtrip() {
+		let types = vec![
+			FieldType::Int64,
+			FieldType::Bytes,
+			FieldType::Array(2, Box::new(FieldType::Uint16)),
+			FieldType::List(Box::new(FieldType::String)),
+			FieldType::Struct(
+				Box::new(FieldType::String),
+				bounded_vec![(Box::new(FieldType::String), Box::new(FieldType::Bool))],
+			),
+			FieldType::FixedBytes(20),
+			FieldType::Enum(bounded_vec![Box::new(FieldType::Void), Box::new(FieldType::Int128)]),
+			FieldType::Map(Box::new(FieldType::Uint8), Box::new(FieldType::AccountId)),
+		];
+		let fields = vec![
+			TestField::Int64(-42),
+			TestField::Bytes(vec![7; 40].try_into().unwrap()),
+			TestField::Array(bounded_vec![TestField::Uint16(1), TestField::Uint16(2)]),
+			TestField::List(bounded_vec![TestField::String("tangle".try_into().unwrap())]),
+			TestField::Struct(
+				Default::default(),
+				bounded_vec![("ok".try_into().unwrap(), Box::new(TestField::Bool(true)))],
+			),
+			TestField::FixedBytes(vec![1; 20].try_into().unwrap()),
+			TestField::Enum(1, Box::new(TestField::Int128(i128::MIN))),
+			TestField::Map(bounded_vec![(
+				TestField::Uint8(1),
+				TestField::AccountId(sp_runtime::AccountId32::new([1; 32]))
+			)]),
+		];
+		assert_eq!(crate::services::type_checker(&types, &fields), Ok(()));
+
+		let data = TestField::encode_to_ethabi(&fields);
+		assert_eq!(TestField::decode_from_ethabi(&types, &data).unwrap(), fields);
+		assert_eq!(
+			TestField::decode_from_ethabi(
+				&[FieldType::Enum(bounded_vec![Box::new(FieldType::Void)])],
+				&TestField::encode_to_ethabi(&[TestField::Enum(0, Box::new(TestField::None))])
+			)
+			.unwrap(),
+			vec![TestField::Enum(0, Box::new(TestField::None))]
+		);
+
+		// the values must fit their types.
+		let data = TestField::encode_to_ethabi(&[TestField::Uint16(256)]);
+		assert!(TestField::decode_from_ethabi(&[FieldType::Uint8], &data).is_err());
+		let data = TestField::encode_to_ethabi(&[TestField::Int16(-129)]);
+		assert!(TestField::decode_from_ethabi(&[FieldType::Int8], &data).is_err());
+		let data = TestField::encode_to_ethabi(&[TestField::Int16(-128)]);
+		assert_eq!(
+			TestField::decode_from_ethabi(&[FieldType::Int8], &data).unwrap(),
+			vec![TestField::Int8(-128)]
+		);
+		assert!(TestField::decode_from_ethabi(&[FieldType::Uint8], &[]).is_err());
+	}
+}
@@ -0,0 +1,710 @@
+// This file is part of Tangle.
+// Copyright (C) 2022-2024 Webb Technologies Inc.
+//
+// Tangle is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Tangle is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Tangle.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A human-readable JSON codec for the fields of the jobs, and the JSON schema of their types.
+//!
+//! Unlike the serde derive of [`Field`], which mirrors the Rust enum, the fields are written as
+//! natural JSON and read back given their [`FieldType`]:
+//!
+//! - `Void` is `null`, and an `Optional` is `null` or its value.
+//! - Integers up to 32 bits are numbers, wider ones are decimal strings, since JavaScript
+//!   numbers lose precision above 2^53. Integers are also read from numbers and decimal strings,
+//!   and a `Uint256` from a `0x` prefixed hex string.
+//! - `String` is a string, `Bytes` and `FixedBytes` are `0x` prefixed hex strings.
+//! - `Array` and `List` are arrays.
+//! - `Struct` is an array of `[name, value]` pairs, in the order of the type. The type does not
+//!   name the fields, so their order must not depend on a JSON object keeping its keys ordered.
+//! - `Enum` is an object with a single entry, keyed by the index of the variant.
+//! - `Map` is an object when its keys are strings, and an array of `[key, value]` pairs
+//!   otherwise. Both forms are read for any key type.
+//! - `AccountId` is a SS58 or a `0x` prefixed hex string.
+
+use super::{BoundedString, Constraints, Field, FieldType, JobDefinition};
+use core::{fmt, marker::PhantomData};
+use frame_support::pallet_prelude::*;
+use parity_scale_codec::DecodeAll;
+use serde::{
+	de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Unexpected, Visitor},
+	ser::{SerializeMap, SerializeSeq},
+	Serialize, Serializer,
+};
+use serde_json::{json, Value};
+use sp_core::{crypto::Ss58Codec, U256};
+
+impl<C: Constraints, AccountId: Ss58Codec + Decode> Field<C, AccountId> {
+	/// Parses a field of the given type from natural JSON.
+	pub fn from_json(ty: &FieldType, json: &str) -> serde_json::Result<Self> {
+		let mut deserializer = serde_json::Deserializer::from_str(json);
+		let field = FieldSeed::new(ty).deserialize(&mut deserializer)?;
+		deserializer.end()?;
+		Ok(field)
+	}
+
+	/// Parses the arguments of a job, given as a JSON array, from natural JSON.
+	pub fn args_from_json(types: &[FieldType], json: &str) -> serde_json::Result<Vec<Self>> {
+		let mut deserializer = serde_json::Deserializer::from_str(json);
+		let args = ArgsSeed(types, PhantomData).deserialize(&mut deserializer)?;
+		deserializer.end()?;
+		Ok(args)
+	}
+
+	/// Renders the field as natural JSON.
+	pub fn to_json(&self) -> String {
+		serde_json::to_string(&JsonField(self)).expect("fields always serialize to JSON; qed")
+	}
+
+	/// Renders the arguments of a job as a JSON array of natural JSON.
+	pub fn args_to_json(args: &[Self]) -> String {
+		let args: Vec<_> = args.iter().map(JsonField).collect();
+		serde_json::to_string(&args).expect("fields always serialize to JSON; qed")
+	}
+}
+
+/// A [`Field`] rendered as natural JSON.
+pub struct JsonField<'a, C: Constraints, AccountId>(pub &'a Field<C, AccountId>);
+
+impl<'a, C: Constraints, AccountId: Ss58Codec> Serialize for JsonField<'a, C, AccountId> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.0 {
+			Field::None => serializer.serialize_unit(),
+			Field::Bool(val) => serializer.serialize_bool(*val),
+			Field::Uint8(val) => serializer.serialize_u8(*val),
+			Field::Int8(val) => serializer.serialize_i8(*val),
+			Field::Uint16(val) => serializer.serialize_u16(*val),
+			Field::Int16(val) => serializer.serialize_i16(*val),
+			Field::Uint32(val) => serializer.serialize_u32(*val),
+			Field::Int32(val) => serializer.serialize_i32(*val),
+			// wider integers do not fit in the numbers of JavaScript, nor of most JSON parsers.
+			Field::Uint64(val) => serializer.collect_str(val),
+			Field::Int64(val) => serializer.collect_str(val),
+			Field::Uint128(val) => serializer.collect_str(val),
+			Field::Int128(val) => serializer.collect_str(val),
+			Field::Uint256(val) => serializer.collect_str(val),
+			Field::String(val) => serializer.serialize_str(val.as_str()),
+			Field::Bytes(val) => serializer.serialize_str(&sp_core::bytes::to_hex(val, false)),
+			Field::FixedBytes(val) => serializer.serialize_str(&sp_core::bytes::to_hex(val, false)),
+			Field::Array(values) | Field::List(values) => {
+				serializer.collect_seq(values.iter().map(JsonField))
+			},
+			Field::Struct(_, fields) => {
+				let mut seq = serializer.serialize_seq(Some(fields.len()))?;
+				for (name, value) in fields.iter() {
+					seq.serialize_element(&(name.as_str(), JsonField(value.as_ref())))?;
+				}
+				seq.end()
+			},
+			Field::Enum(index, value) => {
+				let mut map = serializer.serialize_map(Some(1))?;
+				map.serialize_entry(&index.to_string(), &JsonField(value.as_ref()))?;
+				map.end()
+			},
+			Field::Map(entries) if entries.iter().all(|(k, _)| matches!(k, Field::String(_))) => {
+				let mut map = serializer.serialize_map(Some(entries.len()))?;
+				for (key, value) in entries.iter() {
+					map.serialize_entry(&JsonField(key), &JsonField(value))?;
+				}
+				map.end()
+			},
+			Field::Map(entries) => {
+				let mut seq = serializer.serialize_seq(Some(entries.len()))?;
+				for (key, value) in entries.iter() {
+					seq.serialize_element(&(JsonField(key), JsonField(value)))?;
+				}
+				seq.end()
+			},
+			Field::AccountId(val) => serializer.serialize_str(&val.to_ss58check()),
+		}
+	}
+}
+
+/// Parses a [`Field`] of a [`FieldType`] from natural JSON.
+pub struct FieldSeed<'a, C, AccountId> {
+	ty: &'a FieldType,
+	_marker: PhantomData<(C, AccountId)>,
+}
+
+impl<'a, C, AccountId> FieldSeed<'a, C, AccountId> {
+	/// Creates a seed parsing a field of the given type.
+	pub fn new(ty: &'a FieldType) -> Self {
+		Self { ty, _marker: PhantomData }
+	}
+}
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> DeserializeSeed<'de>
+	for FieldSeed<'a, C, AccountId>
+{
+	type Value = Field<C, AccountId>;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		match self.ty {
+			FieldType::Optional(_) => deserializer.deserialize_option(self),
+			_ => deserializer.deserialize_any(self),
+		}
+	}
+}
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> Visitor<'de>
+	for FieldSeed<'a, C, AccountId>
+{
+	type Value = Field<C, AccountId>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "a value of type {:?}", self.ty)
+	}
+
+	fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+		match self.ty {
+			FieldType::Void | FieldType::Optional(_) => Ok(Field::None),
+			_ => Err(E::invalid_type(Unexpected::Option, &self)),
+		}
+	}
+
+	fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		match self.ty {
+			FieldType::Optional(ty) => FieldSeed::new(ty).deserialize(deserializer),
+			_ => Err(de::Error::invalid_type(Unexpected::Option, &self)),
+		}
+	}
+
+	fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+		self.visit_none()
+	}
+
+	fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+		match self.ty {
+			FieldType::Bool => Ok(Field::Bool(v)),
+			_ => Err(E::invalid_type(Unexpected::Bool(v), &self)),
+		}
+	}
+
+	fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+		integer_field(self.ty, v.into())
+			.ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+	}
+
+	fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+		integer_field(self.ty, v.into())
+			.ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		let invalid = || E::invalid_value(Unexpected::Str(v), &self);
+		match self.ty {
+			FieldType::String => Ok(Field::String(v.try_into().map_err(|_| invalid())?)),
+			FieldType::Bytes => {
+				let bytes = sp_core::bytes::from_hex(v).map_err(|_| invalid())?;
+				Ok(Field::Bytes(bytes.try_into().map_err(|_| invalid())?))
+			},
+			FieldType::FixedBytes(len) => {
+				let bytes = sp_core::bytes::from_hex(v).map_err(|_| invalid())?;
+				if bytes.len() != usize::from(*len) {
+					return Err(E::invalid_length(bytes.len(), &self));
+				}
+				Ok(Field::FixedBytes(bytes.try_into().map_err(|_| invalid())?))
+			},
+			FieldType::Uint128 => v.parse().map(Field::Uint128).map_err(|_| invalid()),
+			FieldType::Uint256 => match v.strip_prefix("0x") {
+				Some(hex) => U256::from_str_radix(hex, 16).ok(),
+				None => U256::from_dec_str(v).ok(),
+			}
+			.map(Field::Uint256)
+			.ok_or_else(invalid),
+			FieldType::AccountId => match v.strip_prefix("0x") {
+				Some(_) => sp_core::bytes::from_hex(v)
+					.ok()
+					.and_then(|bytes| AccountId::decode_all(&mut &bytes[..]).ok()),
+				None => AccountId::from_ss58check(v).ok(),
+			}
+			.map(Field::AccountId)
+			.ok_or_else(invalid),
+			ty => v.parse::<i128>().ok().and_then(|v| integer_field(ty, v)).ok_or_else(invalid),
+		}
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		match self.ty {
+			FieldType::Array(len, ty) => {
+				let values = bounded_elements(&mut seq, ty, &self)?;
+				if values.len() as u64 != *len {
+					return Err(de::Error::invalid_length(values.len(), &self));
+				}
+				Ok(Field::Array(values))
+			},
+			FieldType::List(ty) => Ok(Field::List(bounded_elements(&mut seq, ty, &self)?)),
+			FieldType::Struct(_, types) => {
+				let mut fields = Vec::with_capacity(types.len());
+				for (_, ty) in types.iter() {
+					let Some((name, value)) =
+						seq.next_element_seed(NamedFieldSeed(ty, PhantomData))?
+					else {
+						return Err(de::Error::invalid_length(fields.len(), &self));
+					};
+					if fields.iter().any(|(other, _)| other == &name) {
+						return Err(de::Error::custom(format!("duplicate field `{name}`")));
+					}
+					fields.push((name, Box::new(value)));
+				}
+				if seq.next_element::<de::IgnoredAny>()?.is_some() {
+					return Err(de::Error::invalid_length(types.len() + 1, &self));
+				}
+				let len = fields.len();
+				let fields =
+					fields.try_into().map_err(|_| de::Error::invalid_length(len, &self))?;
+				Ok(Field::Struct(Default::default(), fields))
+			},
+			FieldType::Map(key, value) => {
+				let mut entries = Vec::new();
+				while let Some(entry) = seq.next_element_seed(PairSeed(key, value, PhantomData))? {
+					entries.push(entry);
+				}
+				let len = entries.len();
+				entries
+					.try_into()
+					.map(Field::Map)
+					.map_err(|_| de::Error::invalid_length(len, &self))
+			},
+			_ => Err(de::Error::invalid_type(Unexpected::Seq, &self)),
+		}
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+		match self.ty {
+			FieldType::Enum(variants) => {
+				let Some(index) = map.next_key::<String>()? else {
+					return Err(de::Error::invalid_length(0, &self));
+				};
+				let ty = index
+					.parse::<u8>()
+					.ok()
+					.and_then(|i| variants.get(usize::from(i)).map(|ty| (i, ty)));
+				let Some((index, ty)) = ty else {
+					return Err(de::Error::invalid_value(Unexpected::Str(&index), &self));
+				};
+				let value = map.next_value_seed(FieldSeed::new(ty))?;
+				if map.next_key::<de::IgnoredAny>()?.is_some() {
+					return Err(de::Error::invalid_length(2, &self));
+				}
+				Ok(Field::Enum(index, Box::new(value)))
+			},
+			FieldType::Map(key, value) => {
+				let mut entries = Vec::new();
+				while let Some(k) = map.next_key_seed(FieldSeed::new(key))? {
+					entries.push((k, map.next_value_seed(FieldSeed::new(value))?));
+				}
+				let len = entries.len();
+				entries
+					.try_into()
+					.map(Field::Map)
+					.map_err(|_| de::Error::invalid_length(len, &self))
+			},
+			_ => Err(de::Error::invalid_type(Unexpected::Map, &self)),
+		}
+	}
+}
+
+/// Returns an integer field of the given type, if the value fits in it.
+fn integer_field<C: Constraints, AccountId>(
+	ty: &FieldType,
+	v: i128,
+) -> Option<Field<C, AccountId>> {
+	let field = match ty {
+		FieldType::Uint8 => Field::Uint8(v.try_into().ok()?),
+		FieldType::Int8 => Field::Int8(v.try_into().ok()?),
+		FieldType::Uint16 => Field::Uint16(v.try_into().ok()?),
+		FieldType::Int16 => Field::Int16(v.try_into().ok()?),
+		FieldType::Uint32 => Field::Uint32(v.try_into().ok()?),
+		FieldType::Int32 => Field::Int32(v.try_into().ok()?),
+		FieldType::Uint64 => Field::Uint64(v.try_into().ok()?),
+		FieldType::Int64 => Field::Int64(v.try_into().ok()?),
+		FieldType::Uint128 => Field::Uint128(v.try_into().ok()?),
+		FieldType::Int128 => Field::Int128(v),
+		FieldType::Uint256 => Field::Uint256(u128::try_from(v).ok()?.into()),
+		_ => return None,
+	};
+	Some(field)
+}
+
+/// Parses the elements of a JSON array as fields of the given type.
+fn bounded_elements<'de, A, C, AccountId>(
+	seq: &mut A,
+	ty: &FieldType,
+	expected: &dyn de::Expected,
+) -> Result<BoundedVec<Field<C, AccountId>, C::MaxFieldsSize>, A::Error>
+where
+	A: SeqAccess<'de>,
+	C: Constraints,
+	AccountId: Ss58Codec + Decode,
+{
+	let mut values = Vec::new();
+	while let Some(value) = seq.next_element_seed(FieldSeed::new(ty))? {
+		values.push(value);
+	}
+	let len = values.len();
+	values.try_into().map_err(|_| de::Error::invalid_length(len, expected))
+}
+
+/// Parses a `[key, value]` pair of a map.
+struct PairSeed<'a, C, AccountId>(&'a FieldType, &'a FieldType, PhantomData<(C, AccountId)>);
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> DeserializeSeed<'de>
+	for PairSeed<'a, C, AccountId>
+{
+	type Value = (Field<C, AccountId>, Field<C, AccountId>);
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> Visitor<'de>
+	for PairSeed<'a, C, AccountId>
+{
+	type Value = (Field<C, AccountId>, Field<C, AccountId>);
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "a [key, value] pair of types {:?} and {:?}", self.0, self.1)
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let key = seq
+			.next_element_seed(FieldSeed::new(self.0))?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let value = seq
+			.next_element_seed(FieldSeed::new(self.1))?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		if seq.next_element::<de::IgnoredAny>()?.is_some() {
+			return Err(de::Error::invalid_length(3, &self));
+		}
+		Ok((key, value))
+	}
+}
+
+/// Parses a `[name, value]` pair of a struct.
+struct NamedFieldSeed<'a, C, AccountId>(&'a FieldType, PhantomData<(C, AccountId)>);
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> DeserializeSeed<'de>
+	for NamedFieldSeed<'a, C, AccountId>
+{
+	type Value = (BoundedString<C::MaxFieldsSize>, Field<C, AccountId>);
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> Visitor<'de>
+	for NamedFieldSeed<'a, C, AccountId>
+{
+	type Value = (BoundedString<C::MaxFieldsSize>, Field<C, AccountId>);
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "a [name, value] pair of a field of type {:?}", self.0)
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let name = seq
+			.next_element::<String>()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let name = BoundedString::try_from(name.as_str())
+			.map_err(|_| de::Error::invalid_value(Unexpected::Str(&name), &self))?;
+		let value = seq
+			.next_element_seed(FieldSeed::new(self.0))?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		if seq.next_element::<de::IgnoredAny>()?.is_some() {
+			return Err(de::Error::invalid_length(3, &self));
+		}
+		Ok((name, value))
+	}
+}
+
+/// Parses the arguments of a job from a JSON array.
+struct ArgsSeed<'a, C, AccountId>(&'a [FieldType], PhantomData<(C, AccountId)>);
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> DeserializeSeed<'de>
+	for ArgsSeed<'a, C, AccountId>
+{
+	type Value = Vec<Field<C, AccountId>>;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+impl<'a, 'de, C: Constraints, AccountId: Ss58Codec + Decode> Visitor<'de>
+	for ArgsSeed<'a, C, AccountId>
+{
+	type Value = Vec<Field<C, AccountId>>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "an array of {} arguments", self.0.len())
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut args = Vec::with_capacity(self.0.len());
+		for ty in self.0 {
+			match seq.next_element_seed(FieldSeed::new(ty))? {
+				Some(arg) => args.push(arg),
+				None => return Err(de::Error::invalid_length(args.len(), &self)),
+			}
+		}
+		if seq.next_element::<de::IgnoredAny>()?.is_some() {
+			return Err(de::Error::invalid_length(self.0.len() + 1, &self));
+		}
+		Ok(args)
+	}
+}
+
+impl FieldType {
+	/// Returns the JSON schema of the natural JSON of the fields of this type.
+	pub fn json_schema(&self) -> Value {
+		match self {
+			FieldType::Void => json!({ "type": "null" }),
+			FieldType::Bool => json!({ "type": "boolean" }),
+			FieldType::Uint8 => integer_schema(u8::MIN.into(), u8::MAX.into()),
+			FieldType::Int8 => integer_schema(i8::MIN.into(), i8::MAX.into()),
+			FieldType::Uint16 => integer_schema(u16::MIN.into(), u16::MAX.into()),
+			FieldType::Int16 => integer_schema(i16::MIN.into(), i16::MAX.into()),
+			FieldType::Uint32 => integer_schema(u32::MIN.into(), u32::MAX.into()),
+			FieldType::Int32 => integer_schema(i32::MIN.into(), i32::MAX.into()),
+			FieldType::Uint64 | FieldType::Uint128 => {
+				json!({ "type": "string", "pattern": "^[0-9]+$" })
+			},
+			FieldType::Int64 | FieldType::Int128 => {
+				json!({ "type": "string", "pattern": "^-?[0-9]+$" })
+			},
+			FieldType::Uint256 => {
+				json!({ "type": "string", "pattern": "^([0-9]+|0x[0-9a-fA-F]{1,64})$" })
+			},
+			FieldType::String => json!({ "type": "string" }),
+			FieldType::Bytes => json!({ "type": "string", "pattern": "^0x([0-9a-fA-F]{2})*$" }),
+			FieldType::FixedBytes(len) => json!({
+				"type": "string",
+				"pattern": format!("^0x[0-9a-fA-F]{{{}}}$", usize::from(*len) * 2),
+			}),
+			FieldType::Optional(ty) => json!({ "anyOf": [{ "type": "null" }, ty.json_schema()] }),
+			FieldType::Array(len, ty) => json!({
+				"type": "array",
+				"items": ty.json_schema(),
+				"minItems": len,
+				"maxItems": len,
+			}),
+			FieldType::List(ty) => json!({ "type": "array", "items": ty.json_schema() }),
+			// the names of the fields are not part of the type, only their order is.
+			FieldType::Struct(_, fields) => json!({
+				"type": "array",
+				"prefixItems": fields
+					.iter()
+					.map(|(_, ty)| json!({
+						"type": "array",
+						"prefixItems": [{ "type": "string" }, ty.json_schema()],
+						"items": false,
+						"minItems": 2,
+						"maxItems": 2,
+					}))
+					.collect::<Vec<_>>(),
+				"items": false,
+				"minItems": fields.len(),
+				"maxItems": fields.len(),
+			}),
+			FieldType::Enum(variants) => json!({
+				"oneOf": variants
+					.iter()
+					.enumerate()
+					.map(|(index, ty)| json!({
+						"type": "object",
+						"properties": { index.to_string(): ty.json_schema() },
+						"required": [index.to_string()],
+						"additionalProperties": false,
+					}))
+					.collect::<Vec<_>>(),
+			}),
+			FieldType::Map(key, value) if **key == FieldType::String => json!({
+				"type": "object",
+				"additionalProperties": value.json_schema(),
+			}),
+			FieldType::Map(key, value) => json!({
+				"type": "array",
+				"items": {
+					"type": "array",
+					"prefixItems": [key.json_schema(), value.json_schema()],
+					"items": false,
+					"minItems": 2,
+					"maxItems": 2,
+				},
+			}),
+			FieldType::AccountId => json!({
+				"type": "string",
+				"description": "A SS58 or a 0x prefixed hex encoded account id",
+			}),
+		}
+	}
+}
+
+/// Returns the JSON schema of an integer within the given bounds.
+fn integer_schema(min: Value, max: Value) -> Value {
+	json!({ "type": "integer", "minimum": min, "maximum": max })
+}
+
+/// Returns the JSON schema of a JSON array of fields of the given types.
+fn fields_schema(title: String, description: Option<String>, types: &[FieldType]) -> Value {
+	let mut schema = json!({
+		"$schema": "https://json-schema.org/draft/2020-12/schema",
+		"title": title,
+		"type": "array",
+		"prefixItems": types.iter().map(FieldType::json_schema).collect::<Vec<_>>(),
+		"items": false,
+		"minItems": types.len(),
+		"maxItems": types.len(),
+	});
+	if let Some(description) = description {
+		schema["description"] = description.into();
+	}
+	schema
+}
+
+impl<C: Constraints> JobDefinition<C> {
+	/// Returns the JSON schema of the arguments of the job, as parsed by
+	/// [`Field::args_from_json`].
+	pub fn params_json_schema(&self) -> Value {
+		fields_schema(
+			format!("{} params", self.metadata.name),
+			self.metadata.description.as_ref().map(ToString::to_string),
+			&self.params,
+		)
+	}
+
+	/// Returns the JSON schema of the result of the job, as rendered by [`Field::args_to_json`].
+	pub fn result_json_schema(&self) -> Value {
+		fields_schema(
+			format!("{} result", self.metadata.name),
+			self.metadata.description.as_ref().map(ToString::to_string),
+			&self.result,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::{
+		tests::{TestConstraints, TestField},
+		JobMetadata,
+	};
+	use frame_support::bounded_vec;
+	use sp_runtime::AccountId32;
+
+	#[test]
+	fn fields_json_codec() {
+		let alice = AccountId32::new([1; 32]);
+		let types = vec![
+			FieldType::Uint8,
+			FieldType::Optional(Box::new(FieldType::Int32)),
+			FieldType::Uint256,
+			FieldType::Bytes,
+			FieldType::Struct(
+				Box::new(FieldType::String),
+				bounded_vec![
+					(Box::new(FieldType::String), Box::new(FieldType::String)),
+					(Box::new(FieldType::String), Box::new(FieldType::Int128)),
+				],
+			),
+			FieldType::Enum(bounded_vec![Box::new(FieldType::Void), Box::new(FieldType::Bool)]),
+			FieldType::Map(Box::new(FieldType::String), Box::new(FieldType::AccountId)),
+		];
+		let args = vec![
+			TestField::Uint8(7),
+			TestField::None,
+			TestField::Uint256(U256::MAX),
+			TestField::Bytes(vec![0xde, 0xad].try_into().unwrap()),
+			TestField::Struct(
+				Default::default(),
+				bounded_vec![
+					(
+						"name".try_into().unwrap(),
+						Box::new(TestField::String("tangle".try_into().unwrap()))
+					),
+					("amount".try_into().unwrap(), Box::new(TestField::Int128(-5))),
+				],
+			),
+			TestField::Enum(1, Box::new(TestField::Bool(true))),
+			TestField::Map(bounded_vec![(
+				TestField::String("owner".try_into().unwrap()),
+				TestField::AccountId(alice.clone())
+			)]),
+		];
+		let json = format!(
+			r#"[7,null,"{}","0xdead",[["name","tangle"],["amount","-5"]],{{"1":true}},{{"owner":"{}"}}]"#,
+			U256::MAX,
+			alice.to_ss58check(),
+		);
+		assert_eq!(TestField::args_to_json(&args), json);
+		assert_eq!(TestField::args_from_json(&types, &json).unwrap(), args);
+
+		// the fields of a struct are bound by position, so they must be named in order, once.
+		let struct_ty = &types[4];
+		for json in [
+			r#"{"name":"tangle","amount":"-5"}"#,
+			r#"[["amount","-5"],["name","tangle"]]"#,
+			r#"[["name","tangle"],["name","tangle"]]"#,
+			r#"[["name","tangle"]]"#,
+			r#"[["name","tangle"],["amount","-5"],["extra",1]]"#,
+		] {
+			assert!(TestField::from_json(struct_ty, json).is_err(), "{json}");
+		}
+
+		// 64-bit integers are rendered as strings, and read from strings and numbers.
+		assert_eq!(TestField::Uint64(u64::MAX).to_json(), format!(r#""{}""#, u64::MAX));
+		assert_eq!(TestField::Int64(-42).to_json(), r#""-42""#);
+		assert_eq!(
+			TestField::from_json(&FieldType::Uint64, r#""42""#).unwrap(),
+			TestField::Uint64(42)
+		);
+		assert_eq!(TestField::from_json(&FieldType::Uint64, "42").unwrap(), TestField::Uint64(42));
+		assert_eq!(
+			TestField::from_json(&FieldType::AccountId, &format!(r#""0x{}""#, hex::encode(&alice)))
+				.unwrap(),
+			TestField::AccountId(alice)
+		);
+		assert!(TestField::from_json(&FieldType::Uint8, "256").is_err());
+		assert!(TestField::from_json(&FieldType::FixedBytes(2), r#""0xdeadbeef""#).is_err());
+		assert!(TestField::args_from_json(&types[..1], "[1, 2]").is_err());
+	}
+
+	#[test]
+	fn fields_json_schema() {
+		let job = JobDefinition::<TestConstraints> {
+			metadata: JobMetadata { name: "sign".try_into().unwrap(), ..Default::default() },
+			params: bounded_vec![
+				FieldType::Uint64,
+				FieldType::Bytes,
+				FieldType::Struct(
+					Box::new(FieldType::String),
+					bounded_vec![(Box::new(FieldType::String), Box::new(FieldType::Bool))],
+				),
+			],
+			..Default::default()
+		};
+		let schema = job.params_json_schema();
+		assert_eq!(schema["title"], "sign params");
+		assert_eq!(schema["minItems"], 3);
+		assert_eq!(schema["prefixItems"][0]["type"], "string");
+		assert_eq!(schema["prefixItems"][0]["pattern"], "^[0-9]+$");
+		assert_eq!(schema["prefixItems"][1]["pattern"], "^0x([0-9a-fA-F]{2})*$");
+		let field = &schema["prefixItems"][2]["prefixItems"][0];
+		assert_eq!(field["prefixItems"][0]["type"], "string");
+		assert_eq!(field["prefixItems"][1]["type"], "boolean");
+	}
+}
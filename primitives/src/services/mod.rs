@@ -44,6 +44,8 @@ use alloc::{string::String, vec, vec::Vec};
 
 pub mod field;
 pub use field::*;
+#[cfg(feature = "std")]
+pub mod json;
 
 /// A Higher level abstraction of all the constraints.
 pub trait Constraints {
@@ -109,8 +111,20 @@ pub struct JobDefinition<C: Constraints> {
 	/// i.e. the output.
 	pub result: BoundedVec<FieldType, C::MaxFields>,
 	/// The verifier of the job result.
-	#[deprecated(note = "Use `blueprint.manager` instead.")]
+	///
+	/// EVM verifiers are superseded by `blueprint.manager`, while zero-knowledge verifiers are
+	/// checked natively by the runtime.
 	pub verifier: JobResultVerifier,
+	/// The policy used to aggregate the results submitted by the operators of the service into a
+	/// single canonical result.
+	pub result_policy: JobResultPolicy,
+	/// The number of blocks the operators have to submit their results for a call of this job,
+	/// after which the call times out. `None` means that the calls never time out.
+	pub response_window: Option<u32>,
+	/// The fee the caller pays for every call of this job, which is paid out to the operators
+	/// whose results get accepted. `None` means that calling the job is free. A job with a fee
+	/// needs a response window, so that the fee of a call without a result gets refunded.
+	pub fee: Option<JobFee>,
 }
 
 #[derive(Educe, Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -153,38 +167,153 @@ pub struct JobCall<C: Constraints, AccountId> {
 	pub job: u8,
 	/// The supplied arguments for this job call.
 	pub args: BoundedVec<Field<C, AccountId>, C::MaxFields>,
+	/// The account that called the job.
+	pub caller: AccountId,
 }
 
 /// Type checks the supplied arguments against the parameters.
+///
+/// On a mismatch, the error reports the index of the argument, the path to the mismatching
+/// value inside of it, and a summary of the expected and the actual types at that path.
 pub fn type_checker<C: Constraints, AccountId: Encode + Clone>(
 	params: &[FieldType],
 	args: &[Field<C, AccountId>],
 ) -> Result<(), TypeCheckError> {
-	if params.len() != args.len() {
+	if params.len() > args.len() {
 		return Err(TypeCheckError::NotEnoughArguments {
 			expected: params.len() as u8,
 			actual: args.len() as u8,
 		});
 	}
-	for i in 0..args.len() {
-		let arg = &args[i];
-		let expected = &params[i];
-		if arg != expected {
+	if params.len() < args.len() {
+		return Err(TypeCheckError::TooManyArguments {
+			expected: params.len() as u8,
+			actual: args.len() as u8,
+		});
+	}
+	for (i, (arg, expected)) in args.iter().zip(params).enumerate() {
+		let mut path = TypeCheckPath::default();
+		if let Err((expected, actual)) = type_check_field(expected, arg, &mut path) {
 			return Err(TypeCheckError::ArgumentTypeMismatch {
 				index: i as u8,
-				expected: expected.clone(),
-				actual: arg.clone().into(),
+				expected,
+				actual,
+				path,
 			});
 		}
 	}
 	Ok(())
 }
 
+/// Type checks a single value against its type, descending into the nested values.
+///
+/// On a mismatch, `path` is left pointing at the mismatching value, truncated to its maximum
+/// depth.
+fn type_check_field<C: Constraints, AccountId: Encode + Clone>(
+	ty: &FieldType,
+	field: &Field<C, AccountId>,
+	path: &mut TypeCheckPath,
+) -> Result<(), (FieldKind, FieldKind)> {
+	let mismatch = || (FieldKind::from(ty), FieldKind::from(field));
+	match (field, ty) {
+		(Field::None, FieldType::Optional(_)) => Ok(()),
+		(_, FieldType::Optional(ty)) => type_check_field(ty, field, path),
+		(Field::Array(items), FieldType::Array(len, ty)) => {
+			ensure!(items.len() as u64 == *len, mismatch());
+			type_check_items(ty, items, path)
+		},
+		(Field::List(items), FieldType::List(ty)) => type_check_items(ty, items, path),
+		(Field::Struct(_, fields), FieldType::Struct(_, types)) => {
+			ensure!(fields.len() == types.len(), mismatch());
+			for ((name, field), (_, ty)) in fields.iter().zip(types) {
+				let segment = TypeCheckPathSegment::Field(truncated_field_name(name));
+				with_segment(path, segment, |path| type_check_field(ty, &**field, path))?;
+			}
+			Ok(())
+		},
+		(Field::Enum(index, value), FieldType::Enum(variants)) => {
+			let ty = variants.get(usize::from(*index)).ok_or_else(mismatch)?;
+			if matches!((value.as_ref(), ty.as_ref()), (Field::None, FieldType::Void)) {
+				return Ok(());
+			}
+			with_segment(path, TypeCheckPathSegment::Variant(*index), |path| {
+				type_check_field(ty, &**value, path)
+			})
+		},
+		(Field::Map(entries), FieldType::Map(key_ty, value_ty)) => {
+			for (i, (key, value)) in entries.iter().enumerate() {
+				with_segment(path, TypeCheckPathSegment::MapKey(i as u32), |path| {
+					type_check_field(key_ty, key, path)
+				})?;
+				with_segment(path, TypeCheckPathSegment::MapValue(i as u32), |path| {
+					type_check_field(value_ty, value, path)
+				})?;
+			}
+			Ok(())
+		},
+		_ if field == ty => Ok(()),
+		_ => Err(mismatch()),
+	}
+}
+
+/// Type checks the items of an array or a list against their type.
+fn type_check_items<C: Constraints, AccountId: Encode + Clone>(
+	ty: &FieldType,
+	items: &[Field<C, AccountId>],
+	path: &mut TypeCheckPath,
+) -> Result<(), (FieldKind, FieldKind)> {
+	for (i, item) in items.iter().enumerate() {
+		with_segment(path, TypeCheckPathSegment::Index(i as u32), |path| {
+			type_check_field(ty, item, path)
+		})?;
+	}
+	Ok(())
+}
+
+/// Runs `f` with `segment` pushed to the path, and pops it again once `f` succeeds.
+///
+/// Segments deeper than the maximum depth of the path are dropped.
+fn with_segment<E>(
+	path: &mut TypeCheckPath,
+	segment: TypeCheckPathSegment,
+	f: impl FnOnce(&mut TypeCheckPath) -> Result<(), E>,
+) -> Result<(), E> {
+	let pushed = path.try_push(segment).is_ok();
+	f(path)?;
+	if pushed {
+		path.pop();
+	}
+	Ok(())
+}
+
+/// Truncates the name of a struct field to fit in a [`TypeCheckPathSegment::Field`], on a
+/// character boundary.
+fn truncated_field_name<S: Get<u32>>(name: &BoundedString<S>) -> BoundedString<ConstU32<32>> {
+	let name = name.as_str();
+	let mut end = name.len().min(32);
+	while !name.is_char_boundary(end) {
+		end -= 1;
+	}
+	BoundedString(BoundedVec::truncate_from(name.as_bytes()[..end].to_vec()))
+}
+
 impl<C: Constraints, AccountId: Encode + Clone> JobCall<C, AccountId> {
 	/// Check if the supplied arguments match the job definition types.
 	pub fn type_check(&self, job_def: &JobDefinition<C>) -> Result<(), TypeCheckError> {
 		type_checker(&job_def.params, &self.args)
 	}
+
+	/// The domain separator of the job results signed by the operators of a service.
+	pub const SIGNING_CONTEXT: &'static [u8] = b"tangle/services/job-result";
+
+	/// Returns the hash signed by the operators of the service when the result of the job call
+	/// `call_id` is verified by [`JobResultVerifier::GroupSignature`],
+	/// `keccak256(SIGNING_CONTEXT ++ SCALE((service_id, call_id, job, args)))`.
+	pub fn signing_hash(&self, call_id: u64) -> [u8; 32] {
+		let mut message = Self::SIGNING_CONTEXT.to_vec();
+		(self.service_id, call_id, self.job, &self.args).encode_to(&mut message);
+		sp_core::hashing::keccak_256(&message)
+	}
 }
 
 /// A Job Call Result is the result of a job call.
@@ -218,7 +347,12 @@ pub struct JobCallResult<C: Constraints, AccountId> {
 impl<C: Constraints, AccountId: Encode + Clone> JobCallResult<C, AccountId> {
 	/// Check if the supplied result match the job definition types.
 	pub fn type_check(&self, job_def: &JobDefinition<C>) -> Result<(), TypeCheckError> {
-		type_checker(&job_def.result, &self.result)
+		type_checker(&job_def.result, &self.result).map_err(|e| match e {
+			TypeCheckError::ArgumentTypeMismatch { index, expected, actual, path } => {
+				TypeCheckError::ResultTypeMismatch { index, expected, actual, path }
+			},
+			e => e,
+		})
 	}
 }
 
@@ -232,23 +366,386 @@ pub enum JobResultVerifier {
 	None,
 	/// An EVM Contract Address that will verify the result.
 	Evm(sp_core::H160),
-	// NOTE(@shekohex): Add more verification methods here.
+	/// A Groth16 proof over BN254 carried by the result, verified against the verifying key the
+	/// blueprint stored for the job.
+	Groth16 {
+		/// The encoding of the proof and of the verifying key.
+		verifier: Groth16Verifier,
+		/// The index of the result field holding the public inputs, as concatenated 32 bytes
+		/// big-endian field elements.
+		public_inputs: u8,
+		/// The index of the result field holding the proof.
+		proof: u8,
+	},
+	/// A signature carried by the result, made by the operators of the service under the group
+	/// key registered for the service, over the signing hash of the call, see
+	/// [`JobCall::signing_hash`].
+	GroupSignature {
+		/// The index of the result field holding the signature.
+		signature: u8,
+	},
+}
+
+/// The signature scheme of the group key of a service.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SignatureScheme {
+	/// ECDSA over secp256k1, with a 33 bytes compressed key and a 65 bytes recoverable
+	/// signature.
+	EcdsaSecp256k1,
+	/// Schnorr over ristretto255 (sr25519), with a 32 bytes key and a 64 bytes signature.
+	SchnorrSr25519,
+	/// Schnorr over ed25519, with a 32 bytes key and a 64 bytes signature. FROST signatures
+	/// are valid ed25519 signatures.
+	SchnorrEd25519,
+}
+
+impl SignatureScheme {
+	/// Parses a public key of this scheme.
+	pub fn group_key(self, bytes: &[u8]) -> Option<GroupKey> {
+		match self {
+			Self::EcdsaSecp256k1 => bytes.try_into().ok().map(GroupKey::EcdsaSecp256k1),
+			Self::SchnorrSr25519 => bytes.try_into().ok().map(GroupKey::SchnorrSr25519),
+			Self::SchnorrEd25519 => bytes.try_into().ok().map(GroupKey::SchnorrEd25519),
+		}
+	}
+}
+
+/// The public key of the operators of a service as a group, produced by a distributed key
+/// generation, under which they sign the results of the jobs.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum GroupKey {
+	/// An ECDSA secp256k1 public key.
+	EcdsaSecp256k1(ecdsa::Public),
+	/// A Schnorr sr25519 public key.
+	SchnorrSr25519(sp_core::sr25519::Public),
+	/// A Schnorr ed25519 public key.
+	SchnorrEd25519(sp_core::ed25519::Public),
+}
+
+/// The encoding of a Groth16 proof and of its verifying key.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Groth16Verifier {
+	/// Both the proof and the verifying key are compressed with `ark-serialize`.
+	#[default]
+	Arkworks,
+	/// The proof is ABI encoded as generated by Circom (snarkjs) for Solidity verifiers, and
+	/// the verifying key is compressed with `ark-serialize`.
+	Circom,
+}
+
+/// The asset a job fee is paid in.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FeeAsset {
+	/// The native currency of the chain.
+	#[codec(index = 0)]
+	#[default]
+	Native,
+	/// A custom asset, identified by its asset ID.
+	#[codec(index = 1)]
+	Custom(u128),
+}
+
+/// The fee the caller of a job pays to the operators of the service.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum JobFee {
+	/// A fixed amount for every call.
+	#[codec(index = 0)]
+	Fixed {
+		/// The asset the fee is paid in.
+		asset: FeeAsset,
+		/// The amount paid for every call.
+		amount: u128,
+	},
+	/// A base amount, plus an amount for every byte of the encoded call arguments.
+	#[codec(index = 1)]
+	PerByte {
+		/// The asset the fee is paid in.
+		asset: FeeAsset,
+		/// The amount paid for every call.
+		base: u128,
+		/// The amount paid for every byte of the encoded call arguments.
+		per_byte: u128,
+	},
+}
+
+impl JobFee {
+	/// The asset the fee is paid in.
+	pub fn asset(&self) -> FeeAsset {
+		match self {
+			Self::Fixed { asset, .. } | Self::PerByte { asset, .. } => *asset,
+		}
+	}
+
+	/// The fee of a call, given the size of its encoded arguments.
+	pub fn amount(&self, args_size: usize) -> u128 {
+		match self {
+			Self::Fixed { amount, .. } => *amount,
+			Self::PerByte { base, per_byte, .. } => {
+				base.saturating_add(per_byte.saturating_mul(args_size as u128))
+			},
+		}
+	}
+}
+
+/// A Job Result Policy defines when the results submitted by the operators of a service for a
+/// job call are considered final, and which of them is the canonical result.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum JobResultPolicy {
+	/// The first submitted result is the canonical result.
+	#[codec(index = 0)]
+	#[default]
+	First,
+	/// All the operators of the service must submit the same result.
+	#[codec(index = 1)]
+	All,
+	/// At least `count` operators must submit the same result.
+	#[codec(index = 2)]
+	Threshold {
+		/// The number of identical results required.
+		count: u32,
+	},
+	/// Once at least `threshold` operators submitted a result, the result submitted by the most
+	/// operators is the canonical result. Ties are resolved in favor of the earliest submission.
+	#[codec(index = 3)]
+	MajorityVote {
+		/// The number of submissions required before the vote is settled.
+		threshold: u32,
+	},
+}
+
+impl JobResultPolicy {
+	/// Returns the index of the canonical result among the `submissions`, ordered from the
+	/// earliest to the latest, or `None` if the policy is not met yet.
+	///
+	/// `operators` is the number of operators of the service.
+	pub fn canonical_result<R: PartialEq>(
+		&self,
+		operators: usize,
+		submissions: &[R],
+	) -> Option<usize> {
+		let votes = |result: &R| submissions.iter().filter(|other| *other == result).count();
+		match self {
+			Self::First => (!submissions.is_empty()).then_some(0),
+			Self::All => (operators > 0 && votes(submissions.first()?) >= operators).then_some(0),
+			Self::Threshold { count } => {
+				let count = (*count as usize).max(1);
+				submissions.iter().position(|result| votes(result) >= count)
+			},
+			Self::MajorityVote { threshold } => {
+				if submissions.len() < (*threshold as usize).max(1) {
+					return None;
+				}
+				// `max_by_key` returns the last maximum, so walk the submissions in reverse.
+				submissions
+					.iter()
+					.enumerate()
+					.rev()
+					.max_by_key(|(_, result)| votes(result))
+					.map(|(index, _)| index)
+			},
+		}
+	}
+}
+
+/// A step into a nested value, on the path to the value that failed to type check.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TypeCheckPathSegment {
+	/// A field of a struct, by name, truncated to 32 bytes.
+	#[codec(index = 0)]
+	Field(BoundedString<ConstU32<32>>),
+	/// An item of an array or a list, by index.
+	#[codec(index = 1)]
+	Index(u32),
+	/// The value of an enum variant, by index.
+	#[codec(index = 2)]
+	Variant(u8),
+	/// The key of a map entry, by the index of the entry.
+	#[codec(index = 3)]
+	MapKey(u32),
+	/// The value of a map entry, by the index of the entry.
+	#[codec(index = 4)]
+	MapValue(u32),
+}
+
+/// The path from an argument to the nested value that failed to type check.
+///
+/// Only the first 8 segments of the path are kept.
+pub type TypeCheckPath = BoundedVec<TypeCheckPathSegment, ConstU32<8>>;
+
+/// A summary of a [`FieldType`], without its inner types.
+///
+/// The variants share the codec indices of the [`FieldType`] they summarize.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, Copy, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FieldKind {
+	/// A `void` type, or no value at all.
+	#[codec(index = 0)]
+	Void,
+	/// A `bool` type.
+	#[codec(index = 1)]
+	Bool,
+	/// A `u8` type.
+	#[codec(index = 2)]
+	Uint8,
+	/// An `i8` type.
+	#[codec(index = 3)]
+	Int8,
+	/// A `u16` type.
+	#[codec(index = 4)]
+	Uint16,
+	/// An `i16` type.
+	#[codec(index = 5)]
+	Int16,
+	/// A `u32` type.
+	#[codec(index = 6)]
+	Uint32,
+	/// An `i32` type.
+	#[codec(index = 7)]
+	Int32,
+	/// A `u64` type.
+	#[codec(index = 8)]
+	Uint64,
+	/// An `i64` type.
+	#[codec(index = 9)]
+	Int64,
+	/// A `String` type.
+	#[codec(index = 10)]
+	String,
+	/// A `Vec<u8>` type.
+	#[codec(index = 11)]
+	Bytes,
+	/// An `Option<T>` type.
+	#[codec(index = 12)]
+	Optional,
+	/// An array with the given number of items.
+	#[codec(index = 13)]
+	Array(u64),
+	/// A list.
+	#[codec(index = 14)]
+	List,
+	/// A struct with the given number of fields.
+	#[codec(index = 15)]
+	Struct(u8),
+	/// A `u128` type.
+	#[codec(index = 16)]
+	Uint128,
+	/// An `i128` type.
+	#[codec(index = 17)]
+	Int128,
+	/// A `U256` type.
+	#[codec(index = 18)]
+	Uint256,
+	/// A `[u8; N]` type of the given length.
+	#[codec(index = 19)]
+	FixedBytes(u8),
+	/// An enum with the given number of variants.
+	///
+	/// The actual value of an enum reports the index of its variant plus one.
+	#[codec(index = 20)]
+	Enum(u8),
+	/// A map.
+	#[codec(index = 21)]
+	Map,
+	/// An `AccountId` type.
+	#[codec(index = 100)]
+	AccountId,
+}
+
+impl From<&FieldType> for FieldKind {
+	fn from(ty: &FieldType) -> Self {
+		match ty {
+			FieldType::Void => FieldKind::Void,
+			FieldType::Bool => FieldKind::Bool,
+			FieldType::Uint8 => FieldKind::Uint8,
+			FieldType::Int8 => FieldKind::Int8,
+			FieldType::Uint16 => FieldKind::Uint16,
+			FieldType::Int16 => FieldKind::Int16,
+			FieldType::Uint32 => FieldKind::Uint32,
+			FieldType::Int32 => FieldKind::Int32,
+			FieldType::Uint64 => FieldKind::Uint64,
+			FieldType::Int64 => FieldKind::Int64,
+			FieldType::String => FieldKind::String,
+			FieldType::Bytes => FieldKind::Bytes,
+			FieldType::Optional(_) => FieldKind::Optional,
+			FieldType::Array(len, _) => FieldKind::Array(*len),
+			FieldType::List(_) => FieldKind::List,
+			FieldType::Struct(_, fields) => FieldKind::Struct(fields.len() as u8),
+			FieldType::Uint128 => FieldKind::Uint128,
+			FieldType::Int128 => FieldKind::Int128,
+			FieldType::Uint256 => FieldKind::Uint256,
+			FieldType::FixedBytes(len) => FieldKind::FixedBytes(*len),
+			FieldType::Enum(variants) => FieldKind::Enum(variants.len() as u8),
+			FieldType::Map(..) => FieldKind::Map,
+			FieldType::AccountId => FieldKind::AccountId,
+		}
+	}
+}
+
+impl<C: Constraints, AccountId> From<&Field<C, AccountId>> for FieldKind {
+	fn from(field: &Field<C, AccountId>) -> Self {
+		match field {
+			Field::None => FieldKind::Void,
+			Field::Bool(_) => FieldKind::Bool,
+			Field::Uint8(_) => FieldKind::Uint8,
+			Field::Int8(_) => FieldKind::Int8,
+			Field::Uint16(_) => FieldKind::Uint16,
+			Field::Int16(_) => FieldKind::Int16,
+			Field::Uint32(_) => FieldKind::Uint32,
+			Field::Int32(_) => FieldKind::Int32,
+			Field::Uint64(_) => FieldKind::Uint64,
+			Field::Int64(_) => FieldKind::Int64,
+			Field::String(_) => FieldKind::String,
+			Field::Bytes(_) => FieldKind::Bytes,
+			Field::Array(items) => FieldKind::Array(items.len() as u64),
+			Field::List(_) => FieldKind::List,
+			Field::Struct(_, fields) => FieldKind::Struct(fields.len() as u8),
+			Field::Uint128(_) => FieldKind::Uint128,
+			Field::Int128(_) => FieldKind::Int128,
+			Field::Uint256(_) => FieldKind::Uint256,
+			Field::FixedBytes(bytes) => FieldKind::FixedBytes(bytes.len() as u8),
+			Field::Enum(index, _) => FieldKind::Enum(index.saturating_add(1)),
+			Field::Map(_) => FieldKind::Map,
+			Field::AccountId(_) => FieldKind::AccountId,
+		}
+	}
 }
 
 /// An error that can occur during type checking.
+///
+/// The error does not fit in a dispatch error, which only carries its
+/// [`TypeCheckErrorSummary`]. The full error is returned by the type check runtime API of the
+/// services pallet.
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum TypeCheckError {
 	/// The argument type does not match the expected type.
+	#[codec(index = 0)]
 	ArgumentTypeMismatch {
 		/// The index of the argument.
 		index: u8,
-		/// The expected type.
-		expected: FieldType,
-		/// The actual type.
-		actual: FieldType,
+		/// The expected type, at the end of the path.
+		expected: FieldKind,
+		/// The actual type, at the end of the path.
+		actual: FieldKind,
+		/// The path from the argument to the mismatching value, empty if it is the argument
+		/// itself.
+		path: TypeCheckPath,
 	},
 	/// Not enough arguments were supplied.
+	#[codec(index = 1)]
 	NotEnoughArguments {
 		/// The number of arguments that were expected.
 		expected: u8,
@@ -256,18 +753,90 @@ pub enum TypeCheckError {
 		actual: u8,
 	},
 	/// The result type does not match the expected type.
+	#[codec(index = 2)]
 	ResultTypeMismatch {
+		/// The index of the result field.
+		index: u8,
+		/// The expected type, at the end of the path.
+		expected: FieldKind,
+		/// The actual type, at the end of the path.
+		actual: FieldKind,
+		/// The path from the result field to the mismatching value, empty if it is the result
+		/// field itself.
+		path: TypeCheckPath,
+	},
+	/// Too many arguments were supplied.
+	#[codec(index = 3)]
+	TooManyArguments {
+		/// The number of arguments that were expected.
+		expected: u8,
+		/// The number of arguments that were supplied.
+		actual: u8,
+	},
+}
+
+/// The summary of a [`TypeCheckError`], small enough to be returned in a dispatch error.
+#[derive(
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	Clone,
+	Copy,
+	MaxEncodedLen,
+	frame_support::PalletError,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TypeCheckErrorSummary {
+	/// The argument type does not match the expected type.
+	#[codec(index = 0)]
+	ArgumentTypeMismatch {
 		/// The index of the argument.
 		index: u8,
-		/// The expected type.
-		expected: FieldType,
-		/// The actual type.
-		actual: FieldType,
+	},
+	/// Not enough arguments were supplied.
+	#[codec(index = 1)]
+	NotEnoughArguments {
+		/// The number of arguments that were expected.
+		expected: u8,
+		/// The number of arguments that were supplied.
+		actual: u8,
+	},
+	/// The result type does not match the expected type.
+	#[codec(index = 2)]
+	ResultTypeMismatch {
+		/// The index of the result field.
+		index: u8,
+	},
+	/// Too many arguments were supplied.
+	#[codec(index = 3)]
+	TooManyArguments {
+		/// The number of arguments that were expected.
+		expected: u8,
+		/// The number of arguments that were supplied.
+		actual: u8,
 	},
 }
 
-impl frame_support::traits::PalletError for TypeCheckError {
-	const MAX_ENCODED_SIZE: usize = 2;
+impl From<TypeCheckError> for TypeCheckErrorSummary {
+	fn from(error: TypeCheckError) -> Self {
+		match error {
+			TypeCheckError::ArgumentTypeMismatch { index, .. } => {
+				TypeCheckErrorSummary::ArgumentTypeMismatch { index }
+			},
+			TypeCheckError::NotEnoughArguments { expected, actual } => {
+				TypeCheckErrorSummary::NotEnoughArguments { expected, actual }
+			},
+			TypeCheckError::ResultTypeMismatch { index, .. } => {
+				TypeCheckErrorSummary::ResultTypeMismatch { index }
+			},
+			TypeCheckError::TooManyArguments { expected, actual } => {
+				TypeCheckErrorSummary::TooManyArguments { expected, actual }
+			},
+		}
+	}
 }
 
 // -*** Service ***-
@@ -308,12 +877,15 @@ pub enum ServiceRequestHook {
 pub enum BlueprintManager {
 	/// A Smart contract that will manage the service lifecycle.
 	Evm(sp_core::H160),
+	/// A manager implemented natively by the runtime, identified by its ID.
+	Native(u32),
 }
 
 impl BlueprintManager {
 	pub fn try_into_evm(self) -> Result<sp_core::H160, Self> {
 		match self {
 			Self::Evm(addr) => Ok(addr),
+			other => Err(other),
 		}
 	}
 }
@@ -381,6 +953,15 @@ pub struct ServiceBlueprint<C: Constraints> {
 	pub manager: BlueprintManager,
 	/// The gadget that will be executed for the service.
 	pub gadget: Gadget<C>,
+	/// The number of blocks an operator leaving a service has to keep serving it before it
+	/// gets removed from the service.
+	pub exit_notice_period: u32,
+	/// The number of blocks an operator of a service can go without sending a heartbeat before
+	/// it gets flagged as unresponsive. Zero disables the heartbeats.
+	pub heartbeat_interval: u32,
+	/// The gas limit of each call to the hooks of the blueprint manager, up to the maximum
+	/// allowed by the runtime. Zero uses the default gas limit of the pallet.
+	pub hook_gas_limit: u64,
 }
 
 impl<C: Constraints> ServiceBlueprint<C> {
@@ -426,6 +1007,8 @@ impl<C: Constraints> ServiceBlueprint<C> {
 pub struct ServiceRequest<C: Constraints, AccountId, BlockNumber, AssetId> {
 	/// The service blueprint ID.
 	pub blueprint: u64,
+	/// The revision of the service blueprint this request is made against.
+	pub blueprint_revision: u32,
 	/// The owner of the service.
 	pub owner: AccountId,
 	/// The permitted caller(s) of the service.
@@ -434,11 +1017,15 @@ pub struct ServiceRequest<C: Constraints, AccountId, BlockNumber, AssetId> {
 	pub assets: BoundedVec<AssetId, C::MaxAssetsPerService>,
 	/// The Lifetime of the service.
 	pub ttl: BlockNumber,
+	/// The block number at which this request expires if it is still pending.
+	pub expiry: BlockNumber,
 	/// The supplied arguments for the service request.
 	pub args: BoundedVec<Field<C, AccountId>, C::MaxFields>,
 	/// The Selected Operator(s) with their approval state.
 	pub operators_with_approval_state:
 		BoundedVec<(AccountId, ApprovalState), C::MaxOperatorsPerService>,
+	/// The caller policies of the jobs of the service, sorted by job index.
+	pub caller_policies: BoundedVec<(u8, JobCallerPolicy<C, AccountId>), C::MaxJobsPerService>,
 }
 
 impl<C: Constraints, AccountId, BlockNumber, AssetId>
@@ -466,6 +1053,47 @@ impl<C: Constraints, AccountId, BlockNumber, AssetId>
 	}
 }
 
+/// A request to change the operators of a running service, which is applied once all the
+/// joining operators approved it.
+#[derive(Educe, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[educe(
+    Default(bound(AccountId: Default)),
+    Clone(bound(AccountId: Clone)),
+    PartialEq(bound(AccountId: PartialEq)),
+    Eq
+)]
+#[scale_info(skip_type_params(C))]
+#[codec(encode_bound(skip_type_params(C)))]
+#[codec(decode_bound(skip_type_params(C)))]
+#[codec(mel_bound(skip_type_params(C)))]
+#[cfg_attr(not(feature = "std"), derive(RuntimeDebugNoBound))]
+#[cfg_attr(
+	feature = "std",
+	derive(Serialize, Deserialize),
+	serde(bound(
+        serialize = "AccountId: Serialize",
+        deserialize = "AccountId: Deserialize<'de>",
+    )),
+    educe(Debug(bound(AccountId: core::fmt::Debug)))
+)]
+pub struct OperatorChangeRequest<C: Constraints, AccountId> {
+	/// The account that proposed the change.
+	pub proposer: AccountId,
+	/// The operators joining the service with their approval state.
+	pub joining: BoundedVec<(AccountId, ApprovalState), C::MaxOperatorsPerService>,
+	/// The operators leaving the service.
+	pub leaving: BoundedVec<AccountId, C::MaxOperatorsPerService>,
+}
+
+impl<C: Constraints, AccountId> OperatorChangeRequest<C, AccountId> {
+	/// Returns true if all the joining operators are [ApprovalState::Approved].
+	pub fn is_approved(&self) -> bool {
+		self.joining
+			.iter()
+			.all(|(_, state)| matches!(state, ApprovalState::Approved { .. }))
+	}
+}
+
 /// A Service is an instance of a service blueprint.
 #[derive(Educe, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[educe(
@@ -493,6 +1121,8 @@ pub struct Service<C: Constraints, AccountId, BlockNumber, AssetId> {
 	pub id: u64,
 	/// The Blueprint ID of the service.
 	pub blueprint: u64,
+	/// The revision of the blueprint the service is pinned to.
+	pub blueprint_revision: u32,
 	/// The owner of the service.
 	pub owner: AccountId,
 	/// The Permitted caller(s) of the service.
@@ -505,6 +1135,66 @@ pub struct Service<C: Constraints, AccountId, BlockNumber, AssetId> {
 	pub assets: BoundedVec<AssetId, C::MaxAssetsPerService>,
 	/// The Lifetime of the service.
 	pub ttl: BlockNumber,
+	/// The block number at which the service got initiated.
+	pub start_block: BlockNumber,
+	/// The block number at which the lifetime of the service ends.
+	pub end_block: BlockNumber,
+	/// The caller policies of the jobs of the service, sorted by job index.
+	/// Jobs without a policy use [JobCallerPolicy::PermittedCallers].
+	pub caller_policies: BoundedVec<(u8, JobCallerPolicy<C, AccountId>), C::MaxJobsPerService>,
+}
+
+impl<C: Constraints, AccountId: Clone, BlockNumber, AssetId>
+	Service<C, AccountId, BlockNumber, AssetId>
+{
+	/// Returns the caller policy of the given job.
+	pub fn caller_policy(&self, job: u8) -> JobCallerPolicy<C, AccountId> {
+		self.caller_policies
+			.binary_search_by_key(&job, |(j, _)| *j)
+			.map(|i| self.caller_policies[i].1.clone())
+			.unwrap_or_default()
+	}
+}
+
+/// A Job Caller Policy defines who is allowed to call a job of a service.
+#[derive(Educe, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[educe(
+    Default(bound()),
+    Clone(bound(AccountId: Clone)),
+    PartialEq(bound(AccountId: PartialEq)),
+    Eq
+)]
+#[scale_info(skip_type_params(C))]
+#[codec(encode_bound(skip_type_params(C)))]
+#[codec(decode_bound(skip_type_params(C)))]
+#[codec(mel_bound(skip_type_params(C)))]
+#[cfg_attr(not(feature = "std"), derive(RuntimeDebugNoBound))]
+#[cfg_attr(
+	feature = "std",
+	derive(Serialize, Deserialize),
+	serde(bound(
+        serialize = "AccountId: Serialize",
+        deserialize = "AccountId: Deserialize<'de>",
+    )),
+    educe(Debug(bound(AccountId: core::fmt::Debug)))
+)]
+pub enum JobCallerPolicy<C: Constraints, AccountId> {
+	/// The owner and the permitted callers of the service.
+	#[codec(index = 0)]
+	#[educe(Default)]
+	PermittedCallers,
+	/// Only the owner of the service.
+	#[codec(index = 1)]
+	OwnerOnly,
+	/// Only the listed accounts.
+	#[codec(index = 2)]
+	Accounts(BoundedVec<AccountId, C::MaxPermittedCallers>),
+	/// Any account.
+	#[codec(index = 3)]
+	Anyone,
+	/// The manager contract of the service blueprint decides.
+	#[codec(index = 4)]
+	Manager,
 }
 
 #[derive(
@@ -527,6 +1217,94 @@ pub enum ApprovalState {
 	Rejected,
 }
 
+/// The value escrowed for a service instance, which is paid out to its operators across the
+/// lifetime of the service.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ServiceEscrow<Balance> {
+	/// The total value escrowed by the owner of the service.
+	pub value: Balance,
+	/// The part of the value that got already paid out to the operators.
+	pub paid_out: Balance,
+}
+
+/// A pending slash record. The value of the slash has been computed but not applied yet,
+/// rather deferred for several eras.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct UnappliedSlash<AccountId, Balance, AssetId> {
+	/// The Service Instance Id on which the slash is applied.
+	pub service_id: u64,
+	/// The account ID of the offending operator.
+	pub operator: AccountId,
+	/// The operator's own slash.
+	pub own: Balance,
+	/// All other slashed restakers, with the asset of their stake and the amount slashed from
+	/// it.
+	pub others: Vec<(AccountId, AssetId, Balance)>,
+	/// Reporters of the offence; bounty payout recipients.
+	pub reporters: Vec<AccountId>,
+	/// The amount of payout.
+	pub payout: Balance,
+}
+
+/// A heartbeat of an operator for a service, proving that its gadget is running.
+///
+/// It gets signed with the operator [OperatorPreferences::key] when submitted without a signed
+/// origin, e.g. by an offchain worker.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OperatorHeartbeat<AccountId, BlockNumber> {
+	/// The Service Instance Id the heartbeat is sent for.
+	pub service_id: u64,
+	/// The account ID of the operator.
+	pub operator: AccountId,
+	/// The block number at which the heartbeat got created.
+	pub block_number: BlockNumber,
+}
+
+impl<AccountId: Encode, BlockNumber: Encode> OperatorHeartbeat<AccountId, BlockNumber> {
+	/// The domain separator of the signed heartbeats.
+	pub const SIGNING_CONTEXT: &'static [u8] = b"tangle/services/heartbeat";
+
+	/// Returns the hash signed by the operator, `keccak256(SIGNING_CONTEXT ++ SCALE(heartbeat))`.
+	pub fn signing_hash(&self) -> [u8; 32] {
+		let mut message = Self::SIGNING_CONTEXT.to_vec();
+		self.encode_to(&mut message);
+		sp_core::hashing::keccak_256(&message)
+	}
+}
+
+/// The reason a service got terminated.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ServiceTerminationReason {
+	/// The owner of the service terminated it.
+	#[codec(index = 0)]
+	Owner,
+	/// The lifetime of the service has elapsed.
+	#[codec(index = 1)]
+	Expired,
+}
+
+/// The lifecycle state of a service blueprint.
+#[derive(
+	Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Copy, Clone, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BlueprintState {
+	/// The blueprint accepts new operators and service requests.
+	#[codec(index = 0)]
+	#[default]
+	Active,
+	/// The blueprint got retired by its owner, it does not accept new operators nor service
+	/// requests, but the running services keep running until they are terminated.
+	#[codec(index = 1)]
+	Deprecated,
+}
+
 /// Represents the pricing structure for various hardware resources.
 /// All prices are specified in USD/hr, calculated based on the average block time.
 #[derive(
@@ -947,6 +1725,115 @@ pub struct RpcServicesWithBlueprint<C: Constraints, AccountId, BlockNumber, Asse
 	pub blueprint_id: u64,
 	/// The service blueprint.
 	pub blueprint: ServiceBlueprint<C>,
+	/// The lifecycle state of the service blueprint.
+	pub state: BlueprintState,
 	/// The services instances of that blueprint.
 	pub services: Vec<Service<C, AccountId, BlockNumber, AssetId>>,
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+
+	frame_support::parameter_types! {
+		#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+		#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+		pub const MaxTestLength: u32 = 1024;
+	}
+
+	/// The constraints of the service types used in the tests.
+	#[derive(Default, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct TestConstraints;
+
+	impl Constraints for TestConstraints {
+		type MaxFields = MaxTestLength;
+		type MaxFieldsSize = MaxTestLength;
+		type MaxMetadataLength = MaxTestLength;
+		type MaxJobsPerService = MaxTestLength;
+		type MaxOperatorsPerService = MaxTestLength;
+		type MaxPermittedCallers = MaxTestLength;
+		type MaxServicesPerOperator = MaxTestLength;
+		type MaxBlueprintsPerOperator = MaxTestLength;
+		type MaxServicesPerUser = MaxTestLength;
+		type MaxBinariesPerGadget = MaxTestLength;
+		type MaxSourcesPerGadget = MaxTestLength;
+		type MaxGitOwnerLength = MaxTestLength;
+		type MaxGitRepoLength = MaxTestLength;
+		type MaxGitTagLength = MaxTestLength;
+		type MaxBinaryNameLength = MaxTestLength;
+		type MaxIpfsHashLength = MaxTestLength;
+		type MaxContainerRegistryLength = MaxTestLength;
+		type MaxContainerImageNameLength = MaxTestLength;
+		type MaxContainerImageTagLength = MaxTestLength;
+		type MaxAssetsPerService = MaxTestLength;
+	}
+
+	pub type TestField = Field<TestConstraints, sp_runtime::AccountId32>;
+
+	#[test]
+	fn wide_field_types() {
+		use frame_support::bounded_vec;
+
+		let params = [
+			FieldType::Uint128,
+			FieldType::Int128,
+			FieldType::Uint256,
+			FieldType::FixedBytes(20),
+			FieldType::Enum(bounded_vec![Box::new(FieldType::Void), Box::new(FieldType::Uint8)]),
+			FieldType::Map(Box::new(FieldType::String), Box::new(FieldType::Uint64)),
+		];
+		let args = |address: Vec<u8>, variant: TestField| {
+			vec![
+				TestField::Uint128(u128::MAX),
+				TestField::Int128(-1),
+				TestField::Uint256(sp_core::U256::MAX),
+				TestField::FixedBytes(address.try_into().unwrap()),
+				variant,
+				TestField::Map(bounded_vec![
+					(TestField::String("usdc".try_into().unwrap()), TestField::Uint64(1)),
+					(TestField::String("weth".try_into().unwrap()), TestField::Uint64(2)),
+				]),
+			]
+		};
+		assert_eq!(
+			type_checker(
+				&params,
+				&args(vec![1; 20], TestField::Enum(0, Box::new(TestField::None)))
+			),
+			Ok(())
+		);
+		assert_eq!(
+			type_checker(
+				&params,
+				&args(vec![1; 20], TestField::Enum(1, Box::new(TestField::Uint8(7))))
+			),
+			Ok(())
+		);
+
+		assert_eq!(
+			type_checker(
+				&params,
+				&args(vec![1; 32], TestField::Enum(0, Box::new(TestField::None)))
+			),
+			Err(TypeCheckError::ArgumentTypeMismatch {
+				index: 3,
+				expected: FieldKind::FixedBytes(20),
+				actual: FieldKind::FixedBytes(32),
+				path: Default::default(),
+			})
+		);
+		// the enum has no third variant.
+		assert_eq!(
+			type_checker(
+				&params,
+				&args(vec![1; 20], TestField::Enum(2, Box::new(TestField::Uint8(7))))
+			),
+			Err(TypeCheckError::ArgumentTypeMismatch {
+				index: 4,
+				expected: FieldKind::Enum(2),
+				actual: FieldKind::Enum(3),
+				path: Default::default(),
+			})
+		);
+	}
+}
@@ -99,4 +99,27 @@ pub trait MultiAssetDelegationInfo<AccountId, Balance> {
 	fn get_delegators_for_operator(
 		operator: &AccountId,
 	) -> Vec<(AccountId, Balance, Self::AssetId)>;
+
+	/// Slash the given operator and its delegators.
+	///
+	/// This method reduces the self stake of the operator by `own`, and the stake
+	/// each delegator in `others` bonded to the operator in the paired asset by the
+	/// paired amount. The slashed funds are burned.
+	///
+	/// # Parameters
+	///
+	/// * `operator`: A reference to the account identifier of the operator.
+	/// * `own`: The amount to slash from the operator's self stake.
+	/// * `others`: The delegators along with the asset and the amount to slash from
+	/// each of them.
+	///
+	/// # Returns
+	///
+	/// The total amount that got slashed, which can be less than requested if
+	/// the stakes are not large enough.
+	fn slash_operator(
+		operator: &AccountId,
+		own: Balance,
+		others: &[(AccountId, Self::AssetId, Balance)],
+	) -> Balance;
 }
@@ -1,8 +1,8 @@
 use ark_crypto_primitives::{snark::SNARK, Error};
-use ark_ec::pairing::Pairing;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
 use ark_groth16::{Groth16, Proof, VerifyingKey};
-use ark_serialize::CanonicalDeserialize;
-use sp_std::marker::PhantomData;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use sp_std::{marker::PhantomData, prelude::*};
 
 pub fn verify_groth16<E: Pairing>(
 	vk: &VerifyingKey<E>,
@@ -16,6 +16,18 @@ pub fn verify_groth16<E: Pairing>(
 #[derive(Default, Clone, Copy)]
 pub struct ArkworksVerifierGroth16<E: Pairing>(PhantomData<E>);
 
+impl<E: Pairing> ArkworksVerifierGroth16<E> {
+	/// Checks that the bytes hold a compressed verifying key, with its points on the curve and
+	/// in the right subgroups, and nothing after it.
+	pub fn validate_verifying_key(vk_bytes: &[u8]) -> Result<(), Error> {
+		let vk = VerifyingKey::<E>::deserialize_compressed(vk_bytes)?;
+		if vk.gamma_abc_g1.is_empty() || vk.compressed_size() != vk_bytes.len() {
+			return Err(SerializationError::InvalidData.into());
+		}
+		Ok(())
+	}
+}
+
 impl<E: Pairing> super::InstanceVerifier for ArkworksVerifierGroth16<E> {
 	fn verify(public_inp_bytes: &[u8], proof_bytes: &[u8], vk_bytes: &[u8]) -> Result<bool, Error> {
 		let public_input_field_elts = super::to_field_elements::<E::ScalarField>(public_inp_bytes)?;
@@ -28,3 +40,59 @@ impl<E: Pairing> super::InstanceVerifier for ArkworksVerifierGroth16<E> {
 
 use ark_bn254::Bn254;
 pub type ArkworksVerifierGroth16Bn254 = ArkworksVerifierGroth16<Bn254>;
+
+/// Builds a compressed verifying key with `n` public inputs, together with the public inputs
+/// and a compressed proof it accepts.
+///
+/// The points of the key and of the proof are multiples of the generators of the curve, which
+/// only serves to measure the cost of the verification, e.g. in benchmarks.
+pub fn groth16_bn254_verification_case(n: u32) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>), Error> {
+	use ark_bn254::{Fr, G1Affine, G2Affine};
+
+	let g1 = G1Affine::generator();
+	let g2 = G2Affine::generator();
+	// full-size scalars, so that the cost of the multi-scalar multiplication is not understated.
+	let inputs = (0..n).map(|i| -Fr::from(u64::from(i) + 1)).collect::<Vec<_>>();
+	let vk = VerifyingKey::<Bn254> {
+		alpha_g1: g1,
+		beta_g2: g2,
+		gamma_g2: g2,
+		delta_g2: g2,
+		gamma_abc_g1: vec![g1; inputs.len() + 1],
+	};
+	// with every point of G2 being its generator, the pairing check of the verifier reduces to
+	// a = alpha + gamma_abc[0] + sum(input_i * gamma_abc[i + 1]) + c.
+	let a = (g1 * (Fr::from(3u64) + inputs.iter().sum::<Fr>())).into_affine();
+	let proof = Proof::<Bn254> { a, b: g2, c: g1 };
+
+	let mut vk_bytes = Vec::with_capacity(vk.compressed_size());
+	vk.serialize_compressed(&mut vk_bytes)?;
+	let mut proof_bytes = Vec::with_capacity(proof.compressed_size());
+	proof.serialize_compressed(&mut proof_bytes)?;
+	let input_bytes = super::from_field_elements(&inputs)?;
+	Ok((vk_bytes, input_bytes, proof_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::verifier::InstanceVerifier;
+
+	#[test]
+	fn accepts_the_verification_case() {
+		for n in [0, 1, 8] {
+			let (vk, inputs, proof) = groth16_bn254_verification_case(n).unwrap();
+			assert!(ArkworksVerifierGroth16Bn254::validate_verifying_key(&vk).is_ok());
+			assert_eq!(ArkworksVerifierGroth16Bn254::verify(&inputs, &proof, &vk).ok(), Some(true));
+		}
+	}
+
+	#[test]
+	fn rejects_malformed_verifying_keys() {
+		let (mut vk, ..) = groth16_bn254_verification_case(1).unwrap();
+		vk.push(0);
+		assert!(ArkworksVerifierGroth16Bn254::validate_verifying_key(&vk).is_err());
+		assert!(ArkworksVerifierGroth16Bn254::validate_verifying_key(&vk[..vk.len() - 2]).is_err());
+		assert!(ArkworksVerifierGroth16Bn254::validate_verifying_key(&[0xff; 296]).is_err());
+	}
+}
@@ -78,7 +78,10 @@ use sp_runtime::{
 	SaturatedConversion,
 };
 use sp_staking::currency_to_vote::U128CurrencyToVote;
-use tangle_primitives::services::RpcServicesWithBlueprint;
+use tangle_primitives::services::{
+	Field, JobCall, JobCallResult, OperatorPreferences, RpcServicesWithBlueprint, Service,
+	ServiceBlueprint, ServiceEscrow, ServiceRequest, TypeCheckError, UnappliedSlash,
+};
 pub use tangle_services::PalletServicesConstraints;
 
 #[cfg(any(feature = "std", test))]
@@ -1404,7 +1407,10 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	migrations::MigrateSessionKeys<Runtime>,
+	(
+		migrations::MigrateSessionKeys<Runtime>,
+		pallet_services::migrations::v1::MigrateV0ToV1<Runtime>,
+	),
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1871,7 +1877,7 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl pallet_services_rpc_runtime_api::ServicesApi<Block, PalletServicesConstraints, AccountId, AssetId> for Runtime {
+	impl pallet_services_rpc_runtime_api::ServicesApi<Block, PalletServicesConstraints, AccountId, AssetId, Balance> for Runtime {
 		fn query_services_with_blueprints_by_operator(
 			operator: AccountId,
 		) -> Result<
@@ -1880,6 +1886,108 @@ impl_runtime_apis! {
 		> {
 			Services::services_with_blueprints_by_operator(operator).map_err(Into::into)
 		}
+
+		fn query_service_request_escrow(
+			request_id: u64,
+		) -> Result<Balance, sp_runtime::DispatchError> {
+			Services::service_request_escrow(request_id).map_err(Into::into)
+		}
+
+		fn query_registration_escrow(
+			blueprint_id: u64,
+			operator: AccountId,
+		) -> Result<Balance, sp_runtime::DispatchError> {
+			Services::operator_registration_escrow(blueprint_id, operator).map_err(Into::into)
+		}
+
+		fn query_service_escrow(
+			service_id: u64,
+		) -> Result<ServiceEscrow<Balance>, sp_runtime::DispatchError> {
+			Services::service_escrow(service_id).map_err(Into::into)
+		}
+
+		fn query_blueprint_revisions(
+			blueprint_id: u64,
+		) -> Result<Vec<(u32, ServiceBlueprint<PalletServicesConstraints>)>, sp_runtime::DispatchError> {
+			Services::blueprint_revisions_of(blueprint_id).map_err(Into::into)
+		}
+
+		fn query_blueprints(
+			owner: Option<AccountId>,
+			offset: u32,
+			limit: u32,
+		) -> Result<Vec<(u64, AccountId, ServiceBlueprint<PalletServicesConstraints>)>, sp_runtime::DispatchError> {
+			Ok(Services::blueprints_page(owner, offset, limit))
+		}
+
+		fn query_blueprint_operators(
+			blueprint_id: u64,
+			offset: u32,
+			limit: u32,
+		) -> Result<Vec<(AccountId, OperatorPreferences)>, sp_runtime::DispatchError> {
+			Services::blueprint_operators_page(blueprint_id, offset, limit).map_err(Into::into)
+		}
+
+		fn query_services_by_owner(
+			owner: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Result<
+			Vec<Service<PalletServicesConstraints, AccountId, BlockNumberOf<Block>, AssetId>>,
+			sp_runtime::DispatchError,
+		> {
+			Ok(Services::services_by_owner_page(owner, offset, limit))
+		}
+
+		fn query_pending_requests_by_operator(
+			operator: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Result<
+			Vec<(u64, ServiceRequest<PalletServicesConstraints, AccountId, BlockNumberOf<Block>, AssetId>)>,
+			sp_runtime::DispatchError,
+		> {
+			Ok(Services::pending_requests_by_operator_page(operator, offset, limit))
+		}
+
+		fn query_job_calls(
+			service_id: u64,
+			offset: u32,
+			limit: u32,
+		) -> Result<
+			Vec<(
+				u64,
+				JobCall<PalletServicesConstraints, AccountId>,
+				Option<JobCallResult<PalletServicesConstraints, AccountId>>,
+			)>,
+			sp_runtime::DispatchError,
+		> {
+			Services::job_calls_page(service_id, offset, limit).map_err(Into::into)
+		}
+
+		fn query_unapplied_slashes(
+			operator: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Result<Vec<(u32, u32, UnappliedSlash<AccountId, Balance, AssetId>)>, sp_runtime::DispatchError> {
+			Ok(Services::unapplied_slashes_by_operator_page(operator, offset, limit))
+		}
+
+		fn type_check_job_call(
+			service_id: u64,
+			job: u8,
+			args: Vec<Field<PalletServicesConstraints, AccountId>>,
+		) -> Result<Option<TypeCheckError>, sp_runtime::DispatchError> {
+			Services::type_check_job_call(service_id, job, args).map_err(Into::into)
+		}
+
+		fn type_check_job_result(
+			service_id: u64,
+			call_id: u64,
+			result: Vec<Field<PalletServicesConstraints, AccountId>>,
+		) -> Result<Option<TypeCheckError>, sp_runtime::DispatchError> {
+			Services::type_check_job_result(service_id, call_id, result).map_err(Into::into)
+		}
 	}
 
 	impl fg_primitives::GrandpaApi<Block> for Runtime {
@@ -142,12 +142,24 @@ parameter_types! {
 	pub const SlashDeferDuration: u32 = 7;
 }
 
+parameter_types! {
+	pub const ServiceRequestTimeout: BlockNumber = 7 * DAYS;
+	pub const MaxJobBatchSize: u32 = 16;
+	pub const JobRetentionPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxHookGasLimit: u64 = 3_000_000;
+	pub const MaxBatchHookGas: u64 = 16_000_000;
+	pub const PermissiveManagerId: u32 = 0;
+	pub const PermissiveManagerFee: Balance = UNIT;
+	pub const MaxVerifyingKeyLength: u32 = 8 * 1024;
+}
+
 pub type PalletServicesConstraints = pallet_services::types::ConstraintsOf<Runtime>;
 
 impl pallet_services::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type Currency = Balances;
+	type Fungibles = Assets;
 	type PalletEVMAddress = ServicesEVMAddress;
 	type EvmRunner = PalletEvmRunner;
 	type EvmGasWeightMapping = PalletEVMGasWeightMapping;
@@ -176,6 +188,17 @@ impl pallet_services::Config for Runtime {
 	type Constraints = PalletServicesConstraints;
 	type SlashDeferDuration = SlashDeferDuration;
 	type SlashOrigin = EnsureRootOrHalfCouncil;
+	type ServiceRequestTimeout = ServiceRequestTimeout;
+	type MaxJobBatchSize = MaxJobBatchSize;
+	type JobRetentionPeriod = JobRetentionPeriod;
+	type MaxHookGasLimit = MaxHookGasLimit;
+	type MaxBatchHookGas = MaxBatchHookGas;
+	type MaxVerifyingKeyLength = MaxVerifyingKeyLength;
+	type NativeManager = pallet_services::PermissiveWithFee<
+		PermissiveManagerId,
+		PermissiveManagerFee,
+		TreasuryAccount,
+	>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type OperatorDelegationManager = MultiAssetDelegation;
 	#[cfg(feature = "runtime-benchmarks")]